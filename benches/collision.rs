@@ -0,0 +1,206 @@
+use bevy::prelude::*;
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use jam::common::prelude::*;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use std::f32::consts::PI;
+
+// roughly one screen of midnight threat seas, with cannonballs and tentacles flying around
+const ARENA_SIZE: f32 = 3000.;
+
+fn random_entries(count: usize, seed: u64) -> Vec<CollisionQueryEntry> {
+    let mut rng = StdRng::seed_from_u64(seed);
+    (0..count)
+        .map(|index| CollisionQueryEntry {
+            entity: Entity::from_raw(index as u32),
+            position: Vec2::new(
+                rng.gen_range(-ARENA_SIZE..ARENA_SIZE),
+                rng.gen_range(-ARENA_SIZE..ARENA_SIZE),
+            ),
+            shape: CollisionShape::Rect {
+                size: Vec2::splat(rng.gen_range(20.0..140.0)),
+            },
+            flags: COLLISION_FLAG,
+        })
+        .collect()
+}
+
+// the double loop the broadphase replaced
+fn brute_force_pairs(hitboxes: &[CollisionQueryEntry], hurtboxes: &[CollisionQueryEntry]) -> usize {
+    let mut pairs = 0;
+    for hurtbox in hurtboxes.iter() {
+        for hitbox in hitboxes.iter() {
+            if hitbox
+                .shape
+                .overlaps(hitbox.position, hurtbox.shape, hurtbox.position)
+            {
+                pairs += 1;
+            }
+        }
+    }
+    pairs
+}
+
+fn broadphase_pairs(
+    broadphase: &mut Broadphase,
+    candidates: &mut Vec<usize>,
+    hitboxes: &[CollisionQueryEntry],
+    hurtboxes: &[CollisionQueryEntry],
+) -> usize {
+    broadphase.clear();
+    for (index, hitbox) in hitboxes.iter().enumerate() {
+        if let Some((min, max)) = hitbox.shape.bounds(hitbox.position) {
+            broadphase.insert(index, min, max);
+        }
+    }
+    let mut pairs = 0;
+    for hurtbox in hurtboxes.iter() {
+        let Some((min, max)) = hurtbox.shape.bounds(hurtbox.position) else { continue };
+        broadphase.query(min, max, candidates);
+        for index in candidates.iter() {
+            let hitbox = &hitboxes[*index];
+            if hitbox
+                .shape
+                .overlaps(hitbox.position, hurtbox.shape, hurtbox.position)
+            {
+                pairs += 1;
+            }
+        }
+    }
+    pairs
+}
+
+fn bench_damage_pairs(c: &mut Criterion) {
+    let mut group = c.benchmark_group("damage_pairs");
+    for count in [50, 200, 800] {
+        let hitboxes = random_entries(count, 1);
+        let hurtboxes = random_entries(count, 2);
+        let mut broadphase = Broadphase::default();
+        let mut candidates = vec![];
+        assert_eq!(
+            brute_force_pairs(&hitboxes, &hurtboxes),
+            broadphase_pairs(&mut broadphase, &mut candidates, &hitboxes, &hurtboxes)
+        );
+        group.bench_with_input(BenchmarkId::new("brute_force", count), &count, |b, _| {
+            b.iter(|| brute_force_pairs(black_box(&hitboxes), black_box(&hurtboxes)))
+        });
+        group.bench_with_input(BenchmarkId::new("broadphase", count), &count, |b, _| {
+            b.iter(|| {
+                broadphase_pairs(
+                    &mut broadphase,
+                    &mut candidates,
+                    black_box(&hitboxes),
+                    black_box(&hurtboxes),
+                )
+            })
+        });
+    }
+    group.finish();
+}
+
+// one collision query rebuild per character controller, as it was, against one rebuild per frame
+fn bench_character_controllers(c: &mut Criterion) {
+    let mut group = c.benchmark_group("character_controllers");
+    for count in [50, 200, 800] {
+        let entries = random_entries(count, 3);
+        let build = |collision_query: &mut CollisionQuery| {
+            collision_query.clear();
+            for entry in entries.iter() {
+                collision_query.insert(*entry);
+            }
+        };
+        let check = |collision_query: &CollisionQuery, entry: &CollisionQueryEntry| {
+            let filter = CollisionFilter {
+                exclude_entity: entry.entity,
+                flags: COLLISION_FLAG,
+            };
+            black_box(collision_query.check(entry.position, entry.shape, Some(filter)));
+            black_box(collision_query.check_moving(
+                entry.position,
+                Vec2::new(12., -6.),
+                entry.shape,
+                Some(filter),
+            ));
+        };
+        let mut collision_query = CollisionQuery::default();
+        group.bench_with_input(
+            BenchmarkId::new("rebuild_per_entity", count),
+            &count,
+            |b, _| {
+                b.iter(|| {
+                    for entry in entries.iter() {
+                        build(&mut collision_query);
+                        check(&collision_query, entry);
+                    }
+                })
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("rebuild_per_frame", count),
+            &count,
+            |b, _| {
+                b.iter(|| {
+                    build(&mut collision_query);
+                    for entry in entries.iter() {
+                        check(&collision_query, entry);
+                    }
+                })
+            },
+        );
+    }
+    group.finish();
+}
+
+fn random_shape(rng: &mut StdRng) -> CollisionShape {
+    let size = Vec2::new(rng.gen_range(20.0..140.0), rng.gen_range(20.0..140.0));
+    let rotation = rng.gen_range(-PI..PI);
+    match rng.gen_range(0..4) {
+        0 => CollisionShape::Rect { size },
+        1 => CollisionShape::Circle {
+            radius: size.x * 0.5,
+        },
+        2 => CollisionShape::Capsule {
+            length: size.x,
+            radius: size.y * 0.25,
+            rotation,
+        },
+        _ => CollisionShape::OrientedRect { size, rotation },
+    }
+}
+
+// one swept check per candidate pair, as check_moving runs them
+fn bench_moving_shapes(c: &mut Criterion) {
+    let mut rng = StdRng::seed_from_u64(4);
+    let pairs = (0..256)
+        .map(|_| {
+            (
+                random_shape(&mut rng),
+                random_shape(&mut rng),
+                Vec2::new(rng.gen_range(-200.0..200.), rng.gen_range(-200.0..200.)),
+                Vec2::new(rng.gen_range(-80.0..80.), rng.gen_range(-80.0..80.)),
+            )
+        })
+        .collect::<Vec<_>>();
+    let mut group = c.benchmark_group("moving_shapes");
+    group.bench_function("sweep_256_pairs", |b| {
+        b.iter(|| {
+            for (a_shape, b_shape, b_position, b_velocity) in pairs.iter() {
+                black_box(a_shape.overlaps_moving(
+                    Vec2::ZERO,
+                    Vec2::ZERO,
+                    *b_shape,
+                    *b_position,
+                    *b_velocity,
+                ));
+            }
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_damage_pairs,
+    bench_character_controllers,
+    bench_moving_shapes
+);
+criterion_main!(benches);
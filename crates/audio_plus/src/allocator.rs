@@ -0,0 +1,85 @@
+use crate::{kira::VOICE_CHANNEL_COUNT, source::AudioPlusSource, voice::AudioPlusVoiceHandle};
+use bevy::prelude::*;
+use std::{cmp::Ordering, collections::VecDeque};
+
+#[derive(Default, Debug, Clone, Copy, Resource)]
+pub struct AudioPlusVoiceStats {
+    pub assigned: usize,
+    pub unassigned: usize,
+    pub stolen: usize,
+}
+
+// voices waiting for a free kira channel, most important first
+#[derive(Default, Resource)]
+pub(crate) struct AudioPlusVoiceQueue {
+    pub(crate) voices: VecDeque<AudioPlusVoiceHandle>,
+}
+
+#[derive(Clone, Copy)]
+struct VoiceCandidate {
+    handle: AudioPlusVoiceHandle,
+    priority: i32,
+    volume: f32,
+}
+
+impl VoiceCandidate {
+    fn rank(&self, other: &Self) -> Ordering {
+        self.priority
+            .cmp(&other.priority)
+            .then(self.volume.total_cmp(&other.volume))
+    }
+}
+
+pub(crate) fn allocate_voices(
+    mut query: Query<(Entity, &mut AudioPlusSource)>,
+    mut queue: ResMut<AudioPlusVoiceQueue>,
+    mut stats: ResMut<AudioPlusVoiceStats>,
+) {
+    let mut assigned = vec![];
+    let mut pending = vec![];
+    for (entity, source) in query.iter() {
+        for (index, voice) in source.voices.iter().enumerate() {
+            if !voice.should_assign || voice.stolen {
+                continue;
+            }
+            let candidate = VoiceCandidate {
+                handle: AudioPlusVoiceHandle { entity, index },
+                priority: voice.priority,
+                volume: voice.volume * voice.volume_multiplier,
+            };
+            if voice.assigned {
+                assigned.push(candidate);
+            } else {
+                pending.push(candidate);
+            }
+        }
+    }
+    pending.sort_by(|a, b| b.rank(a));
+    assigned.sort_by(|a, b| a.rank(b));
+
+    let mut free = VOICE_CHANNEL_COUNT.saturating_sub(assigned.len());
+    let mut victims = assigned.iter().peekable();
+    let mut stolen = 0;
+    queue.voices.clear();
+    for candidate in pending.iter() {
+        if free > 0 {
+            free -= 1;
+            queue.voices.push_back(candidate.handle);
+            continue;
+        }
+        // the channel lets go of the stolen voice this frame and picks up the next best one after
+        let Some(victim) = victims.next_if(|victim| candidate.rank(victim).is_gt()) else { break };
+        if let Ok((_, mut source)) = query.get_mut(victim.handle.entity) {
+            if let Some(voice) = source.voices.get_mut(victim.handle.index) {
+                voice.stolen = true;
+                stolen += 1;
+            }
+        }
+    }
+
+    *stats = AudioPlusVoiceStats {
+        assigned: assigned.len() - stolen + queue.voices.len(),
+        unassigned: pending.len() - queue.voices.len(),
+        stolen,
+    };
+}
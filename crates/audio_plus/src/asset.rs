@@ -0,0 +1,140 @@
+use crate::{
+    effect::AudioPlusSoundEffect, mixer::AudioPlusMixerChannel, spatial::AudioPlusAttenuation,
+};
+use bevy::{
+    asset::{AssetLoader, AssetPath, HandleId, LoadContext, LoadedAsset},
+    prelude::*,
+    reflect::TypeUuid,
+    utils::{BoxedFuture, HashMap},
+};
+use serde::Deserialize;
+
+// a named collection of sound effects loaded from a `.sfx.ron` file
+#[derive(TypeUuid)]
+#[uuid = "8e2d6b4a-91c3-4f07-b5a8-3d1e7c6f0a29"]
+pub struct AudioPlusSoundEffects {
+    effects: HashMap<String, AudioPlusSoundEffect>,
+}
+
+impl AudioPlusSoundEffects {
+    pub fn get(&self, name: &str) -> Option<&AudioPlusSoundEffect> {
+        self.effects.get(name)
+    }
+
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.effects.keys().map(|name| name.as_str())
+    }
+
+    pub fn audio_sources(&self) -> impl Iterator<Item = HandleId> + '_ {
+        self.effects
+            .values()
+            .flat_map(|effect| effect.audio_sources.iter().map(|handle| handle.id()))
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(default)]
+struct AudioPlusSoundEffectDefinition {
+    sources: Vec<String>,
+    voices: usize,
+    positional: bool,
+    volume: f32,
+    volume_variation: f32,
+    pitch: f32,
+    pitch_variation: f32,
+    distance: f32,
+    min_distance: f32,
+    attenuation: AudioPlusAttenuation,
+    doppler: f32,
+    low_pass: f32,
+    chance: f32,
+    fade_in: f32,
+    fade_out: f32,
+    channel: AudioPlusMixerChannel,
+    priority: i32,
+    sidechain: Option<String>,
+}
+
+impl Default for AudioPlusSoundEffectDefinition {
+    fn default() -> Self {
+        let effect = AudioPlusSoundEffect::default();
+        Self {
+            sources: vec![],
+            voices: effect.voices,
+            positional: effect.positional,
+            volume: effect.volume,
+            volume_variation: effect.volume_variation,
+            pitch: effect.pitch,
+            pitch_variation: effect.pitch_variation,
+            distance: effect.distance,
+            min_distance: effect.min_distance,
+            attenuation: effect.attenuation,
+            doppler: effect.doppler,
+            low_pass: effect.low_pass,
+            chance: effect.chance,
+            fade_in: effect.fade_in,
+            fade_out: effect.fade_out,
+            channel: effect.channel,
+            priority: effect.priority,
+            sidechain: effect.sidechain,
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct AudioPlusSoundEffectsLoader;
+
+impl AssetLoader for AudioPlusSoundEffectsLoader {
+    fn load<'a>(
+        &'a self,
+        bytes: &'a [u8],
+        load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<(), bevy::asset::Error>> {
+        Box::pin(async move {
+            let definitions =
+                ron::de::from_bytes::<HashMap<String, AudioPlusSoundEffectDefinition>>(bytes)?;
+            let mut dependencies = vec![];
+            let mut effects = HashMap::default();
+            for (name, definition) in definitions {
+                let audio_sources = definition
+                    .sources
+                    .iter()
+                    .map(|path| {
+                        let asset_path = AssetPath::from(path.as_str()).to_owned();
+                        dependencies.push(asset_path.clone());
+                        load_context.get_handle(asset_path)
+                    })
+                    .collect();
+                let effect = AudioPlusSoundEffect {
+                    audio_sources,
+                    voices: definition.voices,
+                    positional: definition.positional,
+                    volume: definition.volume,
+                    volume_variation: definition.volume_variation,
+                    pitch: definition.pitch,
+                    pitch_variation: definition.pitch_variation,
+                    distance: definition.distance,
+                    min_distance: definition.min_distance,
+                    attenuation: definition.attenuation,
+                    doppler: definition.doppler,
+                    low_pass: definition.low_pass,
+                    chance: definition.chance,
+                    fade_in: definition.fade_in,
+                    fade_out: definition.fade_out,
+                    channel: definition.channel,
+                    priority: definition.priority,
+                    sidechain: definition.sidechain,
+                };
+                effects.insert(name, effect);
+            }
+            load_context.set_default_asset(
+                LoadedAsset::new(AudioPlusSoundEffects { effects }).with_dependencies(dependencies),
+            );
+            Ok(())
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["sfx.ron"]
+    }
+}
@@ -0,0 +1,25 @@
+use bevy::prelude::*;
+use bevy_kira_audio::AudioSource;
+
+// the output the voice channels drive, one slot per channel index below `VOICE_CHANNEL_COUNT`
+pub trait AudioPlusBackend: Resource {
+    // replaces whatever the channel was playing
+    fn play(&mut self, channel: usize, audio_source: Handle<AudioSource>, looped: bool);
+
+    fn stop(&mut self, channel: usize);
+
+    fn set_volume(&mut self, channel: usize, volume: f32);
+
+    fn set_panning(&mut self, channel: usize, panning: f32);
+
+    fn set_playback_rate(&mut self, channel: usize, playback_rate: f32);
+
+    // cutoff in hz of the channel's low pass filter, `LOW_PASS_OPEN` leaves it unfiltered
+    fn set_low_pass(&mut self, channel: usize, cutoff: f32);
+
+    // how much of the channel also goes to the shared reverb, 0 keeps it dry
+    fn set_reverb_send(&mut self, channel: usize, send: f32);
+
+    // true once the last clip started on the channel is audible, until it ends or is stopped
+    fn playing(&self, channel: usize) -> bool;
+}
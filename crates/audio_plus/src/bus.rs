@@ -0,0 +1,256 @@
+use crate::{
+    mixer::{AudioPlusMixerChannel, CHANNEL_COUNT},
+    source::AudioPlusSource,
+};
+use bevy::{
+    prelude::*,
+    utils::{HashMap, HashSet},
+};
+
+// a mix of every bus that the buses blend towards, on top of the player's own channel volumes
+#[derive(Clone, Copy)]
+pub struct AudioPlusMixSnapshot {
+    volumes: [f32; CHANNEL_COUNT],
+    reverb_sends: [f32; CHANNEL_COUNT],
+}
+
+impl Default for AudioPlusMixSnapshot {
+    fn default() -> Self {
+        Self {
+            volumes: [1.; CHANNEL_COUNT],
+            reverb_sends: [0.; CHANNEL_COUNT],
+        }
+    }
+}
+
+impl AudioPlusMixSnapshot {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_volume(mut self, channel: AudioPlusMixerChannel, volume: f32) -> Self {
+        if let Some(index) = channel.index() {
+            self.volumes[index] = volume.clamp(0., 1.);
+        }
+        self
+    }
+
+    // how much of the bus is sent to the shared reverb on top of its dry signal
+    pub fn with_reverb_send(mut self, channel: AudioPlusMixerChannel, send: f32) -> Self {
+        if let Some(index) = channel.index() {
+            self.reverb_sends[index] = send.clamp(0., 1.);
+        }
+        self
+    }
+
+    pub fn volume(&self, channel: AudioPlusMixerChannel) -> f32 {
+        channel.index().map_or(1., |index| self.volumes[index])
+    }
+
+    pub fn reverb_send(&self, channel: AudioPlusMixerChannel) -> f32 {
+        channel.index().map_or(0., |index| self.reverb_sends[index])
+    }
+
+    fn lerp(&self, other: &Self, t: f32) -> Self {
+        let mut snapshot = *self;
+        for (volume, target) in snapshot.volumes.iter_mut().zip(other.volumes) {
+            *volume += (target - *volume) * t;
+        }
+        for (send, target) in snapshot.reverb_sends.iter_mut().zip(other.reverb_sends) {
+            *send += (target - *send) * t;
+        }
+        snapshot
+    }
+}
+
+// lowers some buses while it's active, either from `set_ducking` or while a sound effect with a
+// matching sidechain plays
+#[derive(Clone)]
+pub struct AudioPlusDucker {
+    channels: Vec<AudioPlusMixerChannel>,
+    amount: f32,
+    attack: f32,
+    release: f32,
+    level: f32,
+}
+
+impl AudioPlusDucker {
+    pub fn new(amount: f32) -> Self {
+        Self {
+            channels: vec![],
+            amount: amount.clamp(0., 1.),
+            attack: 0.1,
+            release: 0.5,
+            level: 0.,
+        }
+    }
+
+    pub fn with_channel(mut self, channel: AudioPlusMixerChannel) -> Self {
+        self.channels.push(channel);
+        self
+    }
+
+    pub fn with_attack(self, attack: f32) -> Self {
+        Self { attack, ..self }
+    }
+
+    pub fn with_release(self, release: f32) -> Self {
+        Self { release, ..self }
+    }
+
+    pub fn level(&self) -> f32 {
+        self.level
+    }
+}
+
+#[derive(Resource)]
+pub struct AudioPlusBuses {
+    snapshots: HashMap<String, AudioPlusMixSnapshot>,
+    snapshot: Option<String>,
+    from: AudioPlusMixSnapshot,
+    current: AudioPlusMixSnapshot,
+    transition: f32,
+    progress: f32,
+    duckers: HashMap<String, AudioPlusDucker>,
+    ducking: HashSet<String>,
+    gains: [f32; CHANNEL_COUNT],
+}
+
+impl Default for AudioPlusBuses {
+    fn default() -> Self {
+        Self {
+            snapshots: HashMap::default(),
+            snapshot: None,
+            from: AudioPlusMixSnapshot::default(),
+            current: AudioPlusMixSnapshot::default(),
+            transition: 0.,
+            progress: 1.,
+            duckers: HashMap::default(),
+            ducking: HashSet::default(),
+            gains: [1.; CHANNEL_COUNT],
+        }
+    }
+}
+
+impl AudioPlusBuses {
+    pub fn add_snapshot(&mut self, name: &str, snapshot: AudioPlusMixSnapshot) {
+        self.snapshots.insert(name.to_owned(), snapshot);
+    }
+
+    pub fn set_snapshot(&mut self, name: &str, seconds: f32) {
+        if self.snapshot.as_deref() != Some(name) {
+            self.snapshot = Some(name.to_owned());
+            self.start_transition(seconds);
+        }
+    }
+
+    pub fn clear_snapshot(&mut self, seconds: f32) {
+        if self.snapshot.is_some() {
+            self.snapshot = None;
+            self.start_transition(seconds);
+        }
+    }
+
+    pub fn snapshot(&self) -> Option<&str> {
+        self.snapshot.as_deref()
+    }
+
+    pub fn add_ducker(&mut self, name: &str, ducker: AudioPlusDucker) {
+        self.duckers.insert(name.to_owned(), ducker);
+    }
+
+    pub fn set_ducking(&mut self, name: &str, active: bool) {
+        if active {
+            self.ducking.insert(name.to_owned());
+        } else {
+            self.ducking.remove(name);
+        }
+    }
+
+    pub fn ducker(&self, name: &str) -> Option<&AudioPlusDucker> {
+        self.duckers.get(name)
+    }
+
+    // snapshot volume and ducking combined, the player's channel volume is applied separately
+    pub fn gain(&self, channel: AudioPlusMixerChannel) -> f32 {
+        channel.index().map_or(1., |index| self.gains[index])
+    }
+
+    pub fn reverb_send(&self, channel: AudioPlusMixerChannel) -> f32 {
+        self.current.reverb_send(channel)
+    }
+
+    fn start_transition(&mut self, seconds: f32) {
+        self.from = self.current;
+        self.transition = seconds.max(0.);
+        self.progress = 0.;
+    }
+
+    fn target(&self) -> AudioPlusMixSnapshot {
+        self.snapshot
+            .as_ref()
+            .and_then(|name| self.snapshots.get(name))
+            .copied()
+            .unwrap_or_default()
+    }
+}
+
+pub(crate) fn update_buses(
+    mut buses: ResMut<AudioPlusBuses>,
+    query: Query<&AudioPlusSource>,
+    time: Res<Time>,
+) {
+    // snapshots and ducking keep moving while the game is paused
+    let delta = time.raw_delta_seconds();
+    let target = buses.target();
+    let AudioPlusBuses {
+        from,
+        current,
+        transition,
+        progress,
+        duckers,
+        ducking,
+        gains,
+        ..
+    } = buses.as_mut();
+
+    let mut sidechains = HashSet::default();
+    for source in query.iter() {
+        if let Some(sidechain) = source.effect().sidechain() {
+            if source.playing() {
+                sidechains.insert(sidechain);
+            }
+        }
+    }
+
+    *gains = [1.; CHANNEL_COUNT];
+    for (name, ducker) in duckers.iter_mut() {
+        let active = ducking.contains(name) || sidechains.contains(name.as_str());
+        ducker.level = if active {
+            if ducker.attack > 0. {
+                (ducker.level + delta / ducker.attack).min(1.)
+            } else {
+                1.
+            }
+        } else if ducker.release > 0. {
+            (ducker.level - delta / ducker.release).max(0.)
+        } else {
+            0.
+        };
+        for channel in ducker.channels.iter() {
+            if let Some(index) = channel.index() {
+                gains[index] *= 1. - ducker.amount * ducker.level;
+            }
+        }
+    }
+
+    *progress = if *transition > 0. {
+        (*progress + delta / *transition).min(1.)
+    } else {
+        1.
+    };
+    *current = from.lerp(&target, *progress);
+    for (gain, volume) in gains.iter_mut().zip(current.volumes) {
+        *gain *= volume;
+    }
+}
@@ -1,76 +1,77 @@
 use crate::{
+    allocator::AudioPlusVoiceQueue,
+    backend::AudioPlusBackend,
+    kira::VOICE_CHANNEL_COUNT,
     source::AudioPlusSource,
-    voice::{AudioPlusVoiceHandle, AudioPlusVoiceState},
-    AudioPlusSystem,
+    spatial::{low_pass_cutoff, LOW_PASS_OPEN},
+    voice::{AudioPlusVoiceHandle, AudioPlusVoiceState, AudioPlusVoiceStatus},
 };
-use bevy::ecs::system::Resource;
 use bevy::prelude::*;
-use bevy_kira_audio::{AudioApp, AudioChannel, AudioControl, AudioInstance};
-
-macro_rules! channels {
-    ( $( $x:ident ),* ) => {
-        $(
-            #[derive(Resource)]
-            pub struct $x;
-        )*
-        pub fn add_audio_channels(app: &mut App) {
-            $(
-                app.add_audio_channel::<$x>();
-                app.add_system(update_kira_channel::<$x>.after(AudioPlusSystem::UpdateAudioSources).before(AudioPlusSystem::Debug));
-            )*
-        }
-    };
-}
 
 fn f32_sufficient_difference(to: f32, from: f32) -> bool {
     return (from - to).abs() > 0.02 || (to == 0. && from != 0.);
 }
 
 #[derive(Default)]
-struct ChannelData {
+pub(crate) struct ChannelData {
     initialized: bool,
     voice_handle: Option<AudioPlusVoiceHandle>,
-    instance_handle: Option<Handle<AudioInstance>>,
+    started: bool,
     last_volume: f32,
     last_panning: f32,
     last_playback_rate: f32,
+    last_low_pass: f32,
+    last_reverb_send: f32,
+}
+
+pub(crate) fn update_channels<B: AudioPlusBackend>(
+    mut channels: Local<Vec<ChannelData>>,
+    mut backend: ResMut<B>,
+    mut query: Query<&mut AudioPlusSource>,
+    mut queue: ResMut<AudioPlusVoiceQueue>,
+) {
+    if channels.is_empty() {
+        channels.resize_with(VOICE_CHANNEL_COUNT, ChannelData::default);
+    }
+    for (channel, data) in channels.iter_mut().enumerate() {
+        update_channel(channel, data, backend.as_mut(), &mut query, &mut queue);
+    }
 }
 
-fn update_kira_channel<T: Resource>(
-    mut data: Local<ChannelData>,
-    channel: Res<AudioChannel<T>>,
-    mut query: Query<(Entity, &mut AudioPlusSource)>,
+fn update_channel<B: AudioPlusBackend>(
+    channel: usize,
+    data: &mut ChannelData,
+    backend: &mut B,
+    query: &mut Query<&mut AudioPlusSource>,
+    queue: &mut AudioPlusVoiceQueue,
 ) {
     if !data.initialized {
-        channel.set_volume(0.);
+        backend.set_volume(channel, 0.);
+        backend.set_low_pass(channel, LOW_PASS_OPEN);
+        data.last_low_pass = LOW_PASS_OPEN;
         data.initialized = true;
     }
     if let Some(voice_handle) = data.voice_handle {
         let mut unassign = true;
-        if let Ok((_, mut source)) = query.get_mut(voice_handle.entity) {
+        if let Ok(mut source) = query.get_mut(voice_handle.entity) {
             if let Some(voice) = source.voices.get_mut(voice_handle.index) {
-                if voice.should_assign {
+                if voice.should_assign && !voice.stolen {
                     unassign = false;
                     if voice.state_dirty {
+                        data.started = false;
+                        backend.stop(channel);
                         match voice.state {
-                            AudioPlusVoiceState::Stopped => {
-                                data.instance_handle = None;
-                                channel.stop();
-                            }
+                            AudioPlusVoiceState::Stopped => {}
                             AudioPlusVoiceState::Playing => {
-                                data.instance_handle = None;
-                                channel.stop();
                                 if let Some(audio_source) = &voice.audio_source {
-                                    data.instance_handle =
-                                        Some(channel.play(audio_source.clone()).handle());
+                                    backend.play(channel, audio_source.clone(), false);
+                                    data.started = true;
                                 }
                             }
                             AudioPlusVoiceState::Looping => {
-                                data.instance_handle = None;
-                                channel.stop();
                                 if let Some(audio_source) = &voice.audio_source {
-                                    data.instance_handle =
-                                        Some(channel.play(audio_source.clone()).looped().handle());
+                                    backend.play(channel, audio_source.clone(), true);
+                                    data.started = true;
                                 }
                             }
                         }
@@ -78,70 +79,72 @@ fn update_kira_channel<T: Resource>(
                     }
                     let new_volume = voice.volume * voice.volume_multiplier * voice.volume_fade;
                     if f32_sufficient_difference(new_volume, data.last_volume) {
-                        channel.set_volume(
-                            (voice.volume * voice.volume_multiplier * voice.volume_fade) as f64,
-                        );
+                        backend.set_volume(channel, new_volume);
                         data.last_volume = new_volume;
                     }
                     if f32_sufficient_difference(voice.panning, data.last_panning) {
-                        channel.set_panning(voice.panning as f64);
+                        backend.set_panning(channel, voice.panning);
                         data.last_panning = voice.panning;
                     }
-                    if f32_sufficient_difference(voice.playback_rate, data.last_playback_rate) {
-                        channel.set_playback_rate(voice.playback_rate as f64);
-                        data.last_playback_rate = voice.playback_rate;
+                    let new_playback_rate = voice.playback_rate * voice.playback_rate_multiplier;
+                    if f32_sufficient_difference(new_playback_rate, data.last_playback_rate) {
+                        backend.set_playback_rate(channel, new_playback_rate);
+                        data.last_playback_rate = new_playback_rate;
+                    }
+                    // compared in octaves so the sweep updates as often at the top as the bottom
+                    let new_low_pass = low_pass_cutoff(voice.low_pass);
+                    if f32_sufficient_difference(
+                        new_low_pass.log2(),
+                        data.last_low_pass.max(f32::EPSILON).log2(),
+                    ) {
+                        backend.set_low_pass(channel, new_low_pass);
+                        data.last_low_pass = new_low_pass;
+                    }
+                    if f32_sufficient_difference(voice.reverb_send, data.last_reverb_send) {
+                        backend.set_reverb_send(channel, voice.reverb_send);
+                        data.last_reverb_send = voice.reverb_send;
                     }
-                    if let Some(instance_handle) = &data.instance_handle {
-                        let has_position = channel.state(&instance_handle).position().is_some();
+                    if data.started {
+                        let playing = backend.playing(channel);
                         if voice.status.initialized {
-                            voice.status.playing = has_position;
+                            voice.status.playing = playing;
                         } else {
-                            voice.status.initialized = has_position;
+                            voice.status.initialized = playing;
                             voice.status.playing = true;
                         }
                     }
+                } else if voice.state != AudioPlusVoiceState::Looping {
+                    // a one shot that loses its channel is dropped, replaying it later would be
+                    // out of sync
+                    voice.reset();
+                    voice.state_dirty = true;
+                } else if voice.assigned {
+                    // loops start over once they get a channel again
+                    voice.assigned = false;
+                    voice.stolen = false;
+                    voice.state_dirty = true;
+                    voice.status = AudioPlusVoiceStatus::default();
                 }
             }
         }
         if unassign {
-            channel.stop();
+            backend.stop(channel);
             if f32_sufficient_difference(0., data.last_volume) {
-                channel.set_volume(0.);
+                backend.set_volume(channel, 0.);
                 data.last_volume = 0.;
             }
             data.voice_handle = None;
-            data.instance_handle = None;
+            data.started = false;
         }
     } else {
-        let mut found = false;
-        for (entity, mut source) in query.iter_mut() {
-            for (index, voice) in source.voices.iter_mut().enumerate() {
-                if voice.should_assign && !voice.assigned {
-                    data.voice_handle = Some(AudioPlusVoiceHandle { entity, index });
-                    voice.assigned = true;
-                    found = true;
-                    break;
-                }
-            }
-            if found {
+        while let Some(voice_handle) = queue.voices.pop_front() {
+            let Ok(mut source) = query.get_mut(voice_handle.entity) else { continue };
+            let Some(voice) = source.voices.get_mut(voice_handle.index) else { continue };
+            if voice.should_assign && !voice.assigned {
+                data.voice_handle = Some(voice_handle);
+                voice.assigned = true;
                 break;
             }
         }
     }
 }
-
-channels!(
-    Channel1, Channel2, Channel3, Channel4, Channel5, Channel6, Channel7, Channel8, Channel9,
-    Channel10, Channel11, Channel12, Channel13, Channel14, Channel15, Channel16, Channel17,
-    Channel18, Channel19, Channel20, Channel21, Channel22, Channel23, Channel24, Channel25,
-    Channel26, Channel27, Channel28, Channel29, Channel30, Channel31, Channel32, Channel33,
-    Channel34, Channel35, Channel36, Channel37, Channel38, Channel39, Channel40, Channel41,
-    Channel42, Channel43, Channel44, Channel45, Channel46, Channel47, Channel48, Channel49,
-    Channel50, Channel51, Channel52, Channel53, Channel54, Channel55, Channel56, Channel57,
-    Channel58, Channel59, Channel60, Channel61, Channel62, Channel63, Channel64, Channel65,
-    Channel66, Channel67, Channel68, Channel69, Channel70, Channel71, Channel72, Channel73,
-    Channel74, Channel75, Channel76, Channel77, Channel78, Channel79, Channel80, Channel81,
-    Channel82, Channel83, Channel84, Channel85, Channel86, Channel87, Channel88, Channel89,
-    Channel90, Channel91, Channel92, Channel93, Channel94, Channel95, Channel96, Channel97,
-    Channel98, Channel99
-);
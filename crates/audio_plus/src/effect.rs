@@ -1,6 +1,6 @@
 use bevy::prelude::*;
 
-use crate::mixer::AudioPlusMixerChannel;
+use crate::{mixer::AudioPlusMixerChannel, spatial::AudioPlusAttenuation};
 
 #[derive(Clone)]
 pub struct AudioPlusSoundEffect {
@@ -12,10 +12,16 @@ pub struct AudioPlusSoundEffect {
     pub pitch: f32,
     pub pitch_variation: f32,
     pub distance: f32,
+    pub min_distance: f32,
+    pub attenuation: AudioPlusAttenuation,
+    pub doppler: f32,
+    pub low_pass: f32,
     pub chance: f32,
     pub fade_in: f32,
     pub fade_out: f32,
     pub channel: AudioPlusMixerChannel,
+    pub priority: i32,
+    pub sidechain: Option<String>,
 }
 
 impl Default for AudioPlusSoundEffect {
@@ -29,10 +35,16 @@ impl Default for AudioPlusSoundEffect {
             pitch: 1.,
             pitch_variation: 0.,
             distance: 1000.,
+            min_distance: 0.,
+            attenuation: AudioPlusAttenuation::Linear,
+            doppler: 0.,
+            low_pass: 0.,
             chance: 1.,
             fade_in: 0.,
             fade_out: 0.,
             channel: AudioPlusMixerChannel::None,
+            priority: 0,
+            sidechain: None,
         }
     }
 }
@@ -137,6 +149,60 @@ impl AudioPlusSoundEffect {
         self.distance
     }
 
+    pub fn with_min_distance(self, min_distance: f32) -> Self {
+        Self {
+            min_distance,
+            ..self
+        }
+    }
+
+    pub fn set_min_distance(&mut self, min_distance: f32) {
+        self.min_distance = min_distance;
+    }
+
+    pub fn min_distance(&self) -> f32 {
+        self.min_distance
+    }
+
+    pub fn with_attenuation(self, attenuation: AudioPlusAttenuation) -> Self {
+        Self {
+            attenuation,
+            ..self
+        }
+    }
+
+    pub fn set_attenuation(&mut self, attenuation: AudioPlusAttenuation) {
+        self.attenuation = attenuation;
+    }
+
+    pub fn attenuation(&self) -> AudioPlusAttenuation {
+        self.attenuation
+    }
+
+    pub fn with_doppler(self, doppler: f32) -> Self {
+        Self { doppler, ..self }
+    }
+
+    pub fn set_doppler(&mut self, doppler: f32) {
+        self.doppler = doppler;
+    }
+
+    pub fn doppler(&self) -> f32 {
+        self.doppler
+    }
+
+    pub fn with_low_pass(self, low_pass: f32) -> Self {
+        Self { low_pass, ..self }
+    }
+
+    pub fn set_low_pass(&mut self, low_pass: f32) {
+        self.low_pass = low_pass;
+    }
+
+    pub fn low_pass(&self) -> f32 {
+        self.low_pass
+    }
+
     pub fn with_chance(self, chance: f32) -> Self {
         Self { chance, ..self }
     }
@@ -184,6 +250,33 @@ impl AudioPlusSoundEffect {
     pub fn channel(&self) -> AudioPlusMixerChannel {
         self.channel
     }
+
+    pub fn with_priority(self, priority: i32) -> Self {
+        Self { priority, ..self }
+    }
+
+    pub fn set_priority(&mut self, priority: i32) {
+        self.priority = priority;
+    }
+
+    pub fn priority(&self) -> i32 {
+        self.priority
+    }
+
+    pub fn with_sidechain(self, sidechain: &str) -> Self {
+        Self {
+            sidechain: Some(sidechain.to_owned()),
+            ..self
+        }
+    }
+
+    pub fn set_sidechain(&mut self, sidechain: Option<String>) {
+        self.sidechain = sidechain;
+    }
+
+    pub fn sidechain(&self) -> Option<&str> {
+        self.sidechain.as_deref()
+    }
 }
 
 impl From<Handle<bevy_kira_audio::AudioSource>> for AudioPlusSoundEffect {
@@ -0,0 +1,301 @@
+use crate::{backend::AudioPlusBackend, spatial::LOW_PASS_OPEN, AudioPlusSystem};
+use ::kira::{
+    manager::{backend::DefaultBackend, AudioManager, AudioManagerSettings, Capacities},
+    sound::static_sound::{PlaybackState, StaticSoundData, StaticSoundHandle},
+    track::{
+        effect::{
+            filter::{FilterBuilder, FilterHandle},
+            reverb::ReverbBuilder,
+        },
+        TrackBuilder, TrackHandle, TrackRoutes,
+    },
+    tween::Tween,
+    PlaybackRate, Volume,
+};
+use bevy::ecs::system::Resource;
+use bevy::prelude::*;
+use bevy_kira_audio::AudioSource;
+
+pub(crate) const VOICE_CHANNEL_COUNT: usize = 99;
+
+pub(crate) fn add_kira_output(app: &mut App) {
+    app.init_non_send_resource::<AudioPlusKiraOutput>()
+        .add_system(
+            sync_kira_output
+                .after(AudioPlusSystem::UpdateChannels)
+                .before(AudioPlusSystem::Debug),
+        );
+}
+
+enum KiraCommand {
+    Play(Handle<AudioSource>, bool),
+    Stop,
+    Volume(f32),
+    Panning(f32),
+    PlaybackRate(f32),
+    LowPass(f32),
+    ReverbSend(f32),
+}
+
+#[derive(Default)]
+struct KiraChannel {
+    commands: Vec<KiraCommand>,
+    playing: bool,
+}
+
+// the manager isn't Send on every platform, so commands are queued here and handed over by
+// `sync_kira_output`
+#[derive(Resource)]
+pub struct AudioPlusKiraBackend {
+    channels: Vec<KiraChannel>,
+}
+
+impl Default for AudioPlusKiraBackend {
+    fn default() -> Self {
+        Self {
+            channels: (0..VOICE_CHANNEL_COUNT)
+                .map(|_| KiraChannel::default())
+                .collect(),
+        }
+    }
+}
+
+impl AudioPlusKiraBackend {
+    fn push(&mut self, channel: usize, command: KiraCommand) {
+        if let Some(channel) = self.channels.get_mut(channel) {
+            channel.commands.push(command);
+        }
+    }
+}
+
+impl AudioPlusBackend for AudioPlusKiraBackend {
+    fn play(&mut self, channel: usize, audio_source: Handle<AudioSource>, looped: bool) {
+        // the old sound's state is stale until the output syncs
+        if let Some(data) = self.channels.get_mut(channel) {
+            data.playing = false;
+        }
+        self.push(channel, KiraCommand::Play(audio_source, looped));
+    }
+
+    fn stop(&mut self, channel: usize) {
+        if let Some(data) = self.channels.get_mut(channel) {
+            data.playing = false;
+        }
+        self.push(channel, KiraCommand::Stop);
+    }
+
+    fn set_volume(&mut self, channel: usize, volume: f32) {
+        self.push(channel, KiraCommand::Volume(volume));
+    }
+
+    fn set_panning(&mut self, channel: usize, panning: f32) {
+        self.push(channel, KiraCommand::Panning(panning));
+    }
+
+    fn set_playback_rate(&mut self, channel: usize, playback_rate: f32) {
+        self.push(channel, KiraCommand::PlaybackRate(playback_rate));
+    }
+
+    fn set_low_pass(&mut self, channel: usize, cutoff: f32) {
+        self.push(channel, KiraCommand::LowPass(cutoff));
+    }
+
+    fn set_reverb_send(&mut self, channel: usize, send: f32) {
+        self.push(channel, KiraCommand::ReverbSend(send));
+    }
+
+    fn playing(&self, channel: usize) -> bool {
+        self.channels
+            .get(channel)
+            .map_or(false, |channel| channel.playing)
+    }
+}
+
+// every voice channel plays into its own track so it can be filtered on its own, bevy_kira_audio
+// channels can't hold effects. the track also feeds a send track whose volume sets how much of
+// it reaches the shared reverb
+struct KiraOutputChannel {
+    track: TrackHandle,
+    filter: FilterHandle,
+    send: TrackHandle,
+    sound: Option<StaticSoundHandle>,
+    // waiting on the clip to load
+    pending: Option<(Handle<AudioSource>, bool)>,
+    volume: f64,
+    panning: f64,
+    playback_rate: f64,
+}
+
+impl KiraOutputChannel {
+    fn new(manager: &mut AudioManager, reverb: &TrackHandle) -> Option<Self> {
+        let send = manager
+            .add_sub_track(
+                TrackBuilder::new()
+                    .volume(Volume::Amplitude(0.))
+                    .routes(TrackRoutes::empty().with_route(reverb, Volume::Amplitude(1.))),
+            )
+            .ok()?;
+        let mut builder =
+            TrackBuilder::new().routes(TrackRoutes::new().with_route(&send, Volume::Amplitude(1.)));
+        let filter = builder.add_effect(FilterBuilder::new().cutoff(LOW_PASS_OPEN as f64));
+        let track = manager.add_sub_track(builder).ok()?;
+        Some(Self {
+            track,
+            filter,
+            send,
+            sound: None,
+            pending: None,
+            volume: 1.,
+            panning: 0.5,
+            playback_rate: 1.,
+        })
+    }
+
+    fn play(&mut self, manager: &mut AudioManager, sound: &StaticSoundData, looped: bool) {
+        let sound = sound.with_modified_settings(|settings| {
+            let settings = settings
+                .output_destination(&self.track)
+                .volume(Volume::Amplitude(self.volume))
+                .panning(self.panning)
+                .playback_rate(PlaybackRate::Factor(self.playback_rate));
+            if looped {
+                settings.loop_region(..)
+            } else {
+                settings
+            }
+        });
+        self.sound = manager.play(sound).ok();
+    }
+
+    fn stop(&mut self) {
+        if let Some(mut sound) = self.sound.take() {
+            let _ = sound.stop(Tween::default());
+        }
+        self.pending = None;
+    }
+}
+
+pub(crate) struct AudioPlusKiraOutput {
+    manager: Option<AudioManager>,
+    // only kept so the track lives as long as the channels sending to it
+    _reverb: Option<TrackHandle>,
+    channels: Vec<KiraOutputChannel>,
+}
+
+impl AudioPlusKiraOutput {
+    fn unavailable() -> Self {
+        Self {
+            manager: None,
+            _reverb: None,
+            channels: vec![],
+        }
+    }
+}
+
+impl Default for AudioPlusKiraOutput {
+    fn default() -> Self {
+        // a filtered track and a send track per channel, plus the reverb they share
+        let settings = AudioManagerSettings {
+            capacities: Capacities {
+                sub_track_capacity: VOICE_CHANNEL_COUNT * 2 + 1,
+                ..default()
+            },
+            ..default()
+        };
+        let mut manager = match AudioManager::<DefaultBackend>::new(settings) {
+            Ok(manager) => manager,
+            Err(error) => {
+                warn!("audio output unavailable: {}", error);
+                return Self::unavailable();
+            }
+        };
+        let Ok(reverb) =
+            manager.add_sub_track(TrackBuilder::new().with_effect(ReverbBuilder::new().mix(1.)))
+        else {
+            warn!("audio output unavailable: not enough kira tracks");
+            return Self::unavailable();
+        };
+        let Some(channels) = (0..VOICE_CHANNEL_COUNT)
+            .map(|_| KiraOutputChannel::new(&mut manager, &reverb))
+            .collect::<Option<Vec<_>>>()
+        else {
+            warn!("audio output unavailable: not enough kira tracks");
+            return Self::unavailable();
+        };
+        Self {
+            manager: Some(manager),
+            _reverb: Some(reverb),
+            channels,
+        }
+    }
+}
+
+fn sync_kira_output(
+    mut backend: ResMut<AudioPlusKiraBackend>,
+    mut output: NonSendMut<AudioPlusKiraOutput>,
+    audio_sources: Res<Assets<AudioSource>>,
+) {
+    let AudioPlusKiraOutput {
+        manager, channels, ..
+    } = &mut *output;
+    let Some(manager) = manager else {
+        for data in backend.channels.iter_mut() {
+            data.commands.clear();
+        }
+        return;
+    };
+    for (data, channel) in backend.channels.iter_mut().zip(channels.iter_mut()) {
+        for command in data.commands.drain(..) {
+            match command {
+                KiraCommand::Play(audio_source, looped) => {
+                    channel.stop();
+                    channel.pending = Some((audio_source, looped));
+                }
+                KiraCommand::Stop => {
+                    channel.stop();
+                }
+                KiraCommand::Volume(volume) => {
+                    channel.volume = volume as f64;
+                    if let Some(sound) = &mut channel.sound {
+                        let _ =
+                            sound.set_volume(Volume::Amplitude(channel.volume), Tween::default());
+                    }
+                }
+                KiraCommand::Panning(panning) => {
+                    channel.panning = panning as f64;
+                    if let Some(sound) = &mut channel.sound {
+                        let _ = sound.set_panning(channel.panning, Tween::default());
+                    }
+                }
+                KiraCommand::PlaybackRate(playback_rate) => {
+                    channel.playback_rate = playback_rate as f64;
+                    if let Some(sound) = &mut channel.sound {
+                        let _ = sound.set_playback_rate(
+                            PlaybackRate::Factor(channel.playback_rate),
+                            Tween::default(),
+                        );
+                    }
+                }
+                KiraCommand::LowPass(cutoff) => {
+                    let _ = channel.filter.set_cutoff(cutoff as f64, Tween::default());
+                }
+                KiraCommand::ReverbSend(send) => {
+                    let _ = channel
+                        .send
+                        .set_volume(Volume::Amplitude(send as f64), Tween::default());
+                }
+            }
+        }
+        if let Some((audio_source, looped)) = channel.pending.take() {
+            if let Some(source) = audio_sources.get(&audio_source) {
+                channel.play(manager, &source.sound, looped);
+            } else {
+                channel.pending = Some((audio_source, looped));
+            }
+        }
+        data.playing = channel
+            .sound
+            .as_ref()
+            .map_or(false, |sound| sound.state() == PlaybackState::Playing);
+    }
+}
@@ -1,12 +1,20 @@
+use self::kira::{add_kira_output, AudioPlusKiraBackend};
+use allocator::{AudioPlusVoiceQueue, AudioPlusVoiceStats};
+use asset::{AudioPlusSoundEffects, AudioPlusSoundEffectsLoader};
+use backend::AudioPlusBackend;
 use bevy::prelude::*;
 use bevy_kira_audio::AudioPlugin;
-use channels::add_audio_channels;
+use bus::AudioPlusBuses;
 use mixer::AudioPlusMixer;
-use source::AudioPlusSource;
+use music::AudioPlusMusicDirector;
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, SystemSet)]
 pub enum AudioPlusSystem {
+    UpdateMusic,
+    UpdateBuses,
     UpdateAudioSources,
+    AllocateVoices,
+    UpdateChannels,
     Debug,
 }
 
@@ -15,38 +23,70 @@ pub struct AudioPlusPlugin;
 impl Plugin for AudioPlusPlugin {
     fn build(&self, app: &mut App) {
         app.add_plugin(AudioPlugin)
-            .init_resource::<AudioPlusMixer>()
-            .add_system(source::update_audio_sources.in_set(AudioPlusSystem::UpdateAudioSources))
-            .add_system(debug.in_set(AudioPlusSystem::Debug));
-        add_audio_channels(app);
+            .add_asset::<AudioPlusSoundEffects>()
+            .init_asset_loader::<AudioPlusSoundEffectsLoader>()
+            .init_resource::<AudioPlusKiraBackend>();
+        add_kira_output(app);
+        add_systems::<AudioPlusKiraBackend>(app);
     }
 }
 
-fn debug(mut _query: Query<&mut AudioPlusSource>) {
-    /*let mut unassigned_count = 0;
-    let mut total = 0;
-    for mut source in query.iter_mut() {
-        for voice in source.voices.iter_mut() {
-            total += 1;
-            if voice.should_assign && !voice.assigned {
-                unassigned_count += 1;
-            }
-        }
-    }
-    if unassigned_count > 0 {
-        println!(
-            "unassigned sfx: {} (total: {}) - {}",
-            unassigned_count,
-            total,
-            rand::random::<f32>()
+pub(crate) fn add_systems<B: AudioPlusBackend>(app: &mut App) {
+    app.init_resource::<AudioPlusMixer>()
+        .init_resource::<AudioPlusBuses>()
+        .init_resource::<AudioPlusMusicDirector>()
+        .init_resource::<AudioPlusVoiceQueue>()
+        .init_resource::<AudioPlusVoiceStats>()
+        .add_system(
+            music::update_music_director
+                .in_set(AudioPlusSystem::UpdateMusic)
+                .before(AudioPlusSystem::UpdateAudioSources),
+        )
+        .add_system(
+            bus::update_buses
+                .in_set(AudioPlusSystem::UpdateBuses)
+                .after(AudioPlusSystem::UpdateMusic)
+                .before(AudioPlusSystem::UpdateAudioSources),
+        )
+        .add_system(source::update_audio_sources.in_set(AudioPlusSystem::UpdateAudioSources))
+        .add_system(
+            allocator::allocate_voices
+                .in_set(AudioPlusSystem::AllocateVoices)
+                .after(AudioPlusSystem::UpdateAudioSources),
+        )
+        .add_system(
+            channels::update_channels::<B>
+                .in_set(AudioPlusSystem::UpdateChannels)
+                .after(AudioPlusSystem::AllocateVoices),
+        )
+        .add_system(
+            debug
+                .in_set(AudioPlusSystem::Debug)
+                .after(AudioPlusSystem::UpdateChannels),
         );
-    }*/
 }
 
+fn debug(stats: Res<AudioPlusVoiceStats>) {
+    if stats.unassigned > 0 || stats.stolen > 0 {
+        debug!(
+            "voices assigned: {}, unassigned: {}, stolen: {}",
+            stats.assigned, stats.unassigned, stats.stolen
+        );
+    }
+}
+
+pub mod allocator;
+pub mod asset;
+pub mod backend;
+pub mod bus;
 pub mod channels;
 pub mod effect;
+pub mod kira;
 pub mod listener;
 pub mod mixer;
+pub mod mock;
+pub mod music;
 pub mod prelude;
 pub mod source;
+pub mod spatial;
 pub mod voice;
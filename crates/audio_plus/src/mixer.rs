@@ -1,17 +1,41 @@
 use bevy::prelude::*;
+use serde::Deserialize;
 
-const CHANNEL_COUNT: usize = 2;
-#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub(crate) const CHANNEL_COUNT: usize = 5;
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Deserialize)]
 pub enum AudioPlusMixerChannel {
     None = 0,
     Music = 1,
     Sfx = 2,
+    Ambience = 3,
+    Voice = 4,
+    Ui = 5,
+}
+
+impl AudioPlusMixerChannel {
+    pub const ALL: [AudioPlusMixerChannel; CHANNEL_COUNT] = [
+        AudioPlusMixerChannel::Music,
+        AudioPlusMixerChannel::Sfx,
+        AudioPlusMixerChannel::Ambience,
+        AudioPlusMixerChannel::Voice,
+        AudioPlusMixerChannel::Ui,
+    ];
+
+    pub(crate) fn index(self) -> Option<usize> {
+        let channel_id = self as usize;
+        if channel_id != 0 && channel_id <= CHANNEL_COUNT {
+            Some(channel_id - 1)
+        } else {
+            None
+        }
+    }
 }
 
 #[derive(Resource)]
 pub struct AudioPlusMixer {
     master_volume: f32,
     volumes: [f32; CHANNEL_COUNT],
+    muted: bool,
 }
 
 impl Default for AudioPlusMixer {
@@ -19,6 +43,7 @@ impl Default for AudioPlusMixer {
         Self {
             master_volume: 1.,
             volumes: [1.; CHANNEL_COUNT],
+            muted: false,
         }
     }
 }
@@ -30,18 +55,26 @@ impl AudioPlusMixer {
     pub fn get_master_volume(&self) -> f32 {
         self.master_volume
     }
+    pub fn set_muted(&mut self, muted: bool) {
+        self.muted = muted;
+    }
+    pub fn muted(&self) -> bool {
+        self.muted
+    }
+    // master volume after muting, applied to every source
+    pub fn get_output_volume(&self) -> f32 {
+        if self.muted {
+            0.
+        } else {
+            self.master_volume
+        }
+    }
     pub fn set_volume(&mut self, channel: AudioPlusMixerChannel, volume: f32) {
-        let channel_id = channel as usize;
-        if channel_id != 0 && channel_id <= CHANNEL_COUNT {
-            self.volumes[channel_id - 1] = volume;
+        if let Some(index) = channel.index() {
+            self.volumes[index] = volume.clamp(0., 1.);
         }
     }
     pub fn get_volume(&self, channel: AudioPlusMixerChannel) -> f32 {
-        let channel_id = channel as usize;
-        if channel_id != 0 && channel_id <= CHANNEL_COUNT {
-            self.volumes[channel_id - 1]
-        } else {
-            1.
-        }
+        channel.index().map_or(1., |index| self.volumes[index])
     }
 }
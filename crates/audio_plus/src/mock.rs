@@ -0,0 +1,222 @@
+use crate::{
+    backend::AudioPlusBackend, kira::VOICE_CHANNEL_COUNT, spatial::LOW_PASS_OPEN, AudioPlusSystem,
+};
+use bevy::{prelude::*, utils::HashMap};
+use bevy_kira_audio::AudioSource;
+
+// runs audio_plus without an audio device, every channel call is recorded instead
+pub struct AudioPlusMockPlugin;
+
+impl Plugin for AudioPlusMockPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<AudioPlusMockBackend>().add_system(
+            update_mock_backend
+                .after(AudioPlusSystem::UpdateChannels)
+                .before(AudioPlusSystem::Debug),
+        );
+        crate::add_systems::<AudioPlusMockBackend>(app);
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum AudioPlusMockEventKind {
+    Play {
+        audio_source: Handle<AudioSource>,
+        looped: bool,
+    },
+    Stop,
+    Finish,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AudioPlusMockEvent {
+    pub time: f32,
+    pub channel: usize,
+    pub kind: AudioPlusMockEventKind,
+}
+
+#[derive(Debug, Clone)]
+pub struct AudioPlusMockChannel {
+    pub audio_source: Option<Handle<AudioSource>>,
+    pub looped: bool,
+    pub playing: bool,
+    pub position: f32,
+    pub volume: f32,
+    pub panning: f32,
+    pub playback_rate: f32,
+    pub low_pass: f32,
+    pub reverb_send: f32,
+}
+
+impl Default for AudioPlusMockChannel {
+    fn default() -> Self {
+        Self {
+            audio_source: None,
+            looped: false,
+            playing: false,
+            position: 0.,
+            volume: 1.,
+            panning: 0.5,
+            playback_rate: 1.,
+            low_pass: LOW_PASS_OPEN,
+            reverb_send: 0.,
+        }
+    }
+}
+
+#[derive(Resource)]
+pub struct AudioPlusMockBackend {
+    channels: Vec<AudioPlusMockChannel>,
+    events: Vec<AudioPlusMockEvent>,
+    lengths: HashMap<Handle<AudioSource>, f32>,
+    time: f32,
+}
+
+impl Default for AudioPlusMockBackend {
+    fn default() -> Self {
+        Self {
+            channels: vec![AudioPlusMockChannel::default(); VOICE_CHANNEL_COUNT],
+            events: vec![],
+            lengths: HashMap::default(),
+            time: 0.,
+        }
+    }
+}
+
+impl AudioPlusMockBackend {
+    // clips without a length play forever
+    pub fn set_length(&mut self, audio_source: &Handle<AudioSource>, seconds: f32) {
+        self.lengths.insert(audio_source.clone_weak(), seconds);
+    }
+
+    pub fn channel(&self, channel: usize) -> Option<&AudioPlusMockChannel> {
+        self.channels.get(channel)
+    }
+
+    pub fn playing_channels(&self) -> impl Iterator<Item = &AudioPlusMockChannel> {
+        self.channels.iter().filter(|channel| channel.playing)
+    }
+
+    pub fn playing_source(
+        &self,
+        audio_source: &Handle<AudioSource>,
+    ) -> impl Iterator<Item = &AudioPlusMockChannel> + '_ {
+        let id = audio_source.id();
+        self.playing_channels().filter(move |channel| {
+            channel
+                .audio_source
+                .as_ref()
+                .map_or(false, |audio_source| audio_source.id() == id)
+        })
+    }
+
+    pub fn events(&self) -> &[AudioPlusMockEvent] {
+        &self.events
+    }
+
+    pub fn clear_events(&mut self) {
+        self.events.clear();
+    }
+
+    fn record(&mut self, channel: usize, kind: AudioPlusMockEventKind) {
+        self.events.push(AudioPlusMockEvent {
+            time: self.time,
+            channel,
+            kind,
+        });
+    }
+}
+
+impl AudioPlusBackend for AudioPlusMockBackend {
+    fn play(&mut self, channel: usize, audio_source: Handle<AudioSource>, looped: bool) {
+        let Some(data) = self.channels.get_mut(channel) else { return };
+        data.audio_source = Some(audio_source.clone());
+        data.looped = looped;
+        data.playing = true;
+        data.position = 0.;
+        self.record(
+            channel,
+            AudioPlusMockEventKind::Play {
+                audio_source,
+                looped,
+            },
+        );
+    }
+
+    fn stop(&mut self, channel: usize) {
+        let Some(data) = self.channels.get_mut(channel) else { return };
+        if data.playing {
+            data.playing = false;
+            self.record(channel, AudioPlusMockEventKind::Stop);
+        }
+    }
+
+    fn set_volume(&mut self, channel: usize, volume: f32) {
+        if let Some(data) = self.channels.get_mut(channel) {
+            data.volume = volume;
+        }
+    }
+
+    fn set_panning(&mut self, channel: usize, panning: f32) {
+        if let Some(data) = self.channels.get_mut(channel) {
+            data.panning = panning;
+        }
+    }
+
+    fn set_playback_rate(&mut self, channel: usize, playback_rate: f32) {
+        if let Some(data) = self.channels.get_mut(channel) {
+            data.playback_rate = playback_rate;
+        }
+    }
+
+    fn set_low_pass(&mut self, channel: usize, cutoff: f32) {
+        if let Some(data) = self.channels.get_mut(channel) {
+            data.low_pass = cutoff;
+        }
+    }
+
+    fn set_reverb_send(&mut self, channel: usize, send: f32) {
+        if let Some(data) = self.channels.get_mut(channel) {
+            data.reverb_send = send;
+        }
+    }
+
+    fn playing(&self, channel: usize) -> bool {
+        self.channels
+            .get(channel)
+            .map_or(false, |channel| channel.playing)
+    }
+}
+
+fn update_mock_backend(mut backend: ResMut<AudioPlusMockBackend>, time: Res<Time>) {
+    let delta = time.delta_seconds();
+    backend.time += delta;
+    let AudioPlusMockBackend {
+        channels, lengths, ..
+    } = backend.as_mut();
+    let mut finished = vec![];
+    for (index, channel) in channels.iter_mut().enumerate() {
+        if !channel.playing {
+            continue;
+        }
+        channel.position += delta * channel.playback_rate;
+        let Some(length) = channel
+            .audio_source
+            .as_ref()
+            .and_then(|audio_source| lengths.get(audio_source))
+        else {
+            continue;
+        };
+        if channel.position >= *length {
+            if channel.looped {
+                channel.position %= length.max(f32::EPSILON);
+            } else {
+                channel.playing = false;
+                finished.push(index);
+            }
+        }
+    }
+    for index in finished {
+        backend.record(index, AudioPlusMockEventKind::Finish);
+    }
+}
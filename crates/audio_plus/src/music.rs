@@ -0,0 +1,201 @@
+use crate::{effect::AudioPlusSoundEffect, source::AudioPlusSource};
+use bevy::{prelude::*, utils::HashMap};
+
+// a looping piece of music made of layers that all play in sync, layers above the director's
+// intensity are kept silent instead of stopped
+#[derive(Clone)]
+pub struct AudioPlusMusicTrack {
+    layers: Vec<(AudioPlusSoundEffect, f32)>,
+    bpm: f32,
+}
+
+impl AudioPlusMusicTrack {
+    pub fn new(bpm: f32) -> Self {
+        Self {
+            layers: vec![],
+            bpm,
+        }
+    }
+
+    pub fn with_layer(mut self, sound_effect: AudioPlusSoundEffect, intensity: f32) -> Self {
+        self.layers.push((sound_effect, intensity));
+        self
+    }
+
+    pub fn bpm(&self) -> f32 {
+        self.bpm
+    }
+}
+
+#[derive(Resource)]
+pub struct AudioPlusMusicDirector {
+    tracks: HashMap<String, AudioPlusMusicTrack>,
+    states: HashMap<String, String>,
+    state: Option<String>,
+    crossfade: f32,
+    intensity: f32,
+    stingers: Vec<AudioPlusSoundEffect>,
+    beat_time: f32,
+}
+
+impl Default for AudioPlusMusicDirector {
+    fn default() -> Self {
+        Self {
+            tracks: HashMap::default(),
+            states: HashMap::default(),
+            state: None,
+            crossfade: 1.,
+            intensity: 0.,
+            stingers: vec![],
+            beat_time: 0.,
+        }
+    }
+}
+
+impl AudioPlusMusicDirector {
+    pub fn add_track(&mut self, name: &str, track: AudioPlusMusicTrack) {
+        self.tracks.insert(name.to_owned(), track);
+    }
+
+    pub fn add_state(&mut self, state: &str, track: &str) {
+        self.states.insert(state.to_owned(), track.to_owned());
+    }
+
+    pub fn set_state(&mut self, state: &str) {
+        if self.state.as_deref() != Some(state) {
+            self.state = Some(state.to_owned());
+        }
+    }
+
+    pub fn clear_state(&mut self) {
+        self.state = None;
+    }
+
+    pub fn state(&self) -> Option<&str> {
+        self.state.as_deref()
+    }
+
+    pub fn set_crossfade(&mut self, seconds: f32) {
+        self.crossfade = seconds.max(0.);
+    }
+
+    pub fn set_intensity(&mut self, intensity: f32) {
+        self.intensity = intensity.clamp(0., 1.);
+    }
+
+    pub fn intensity(&self) -> f32 {
+        self.intensity
+    }
+
+    // plays on the next beat of the current track, or right away when nothing is playing
+    pub fn play_stinger(&mut self, sound_effect: AudioPlusSoundEffect) {
+        self.stingers.push(sound_effect);
+    }
+
+    fn track_name(&self) -> Option<&str> {
+        let state = self.state.as_ref()?;
+        self.states.get(state).map(|track| track.as_str())
+    }
+
+    fn track(&self) -> Option<&AudioPlusMusicTrack> {
+        self.tracks.get(self.track_name()?)
+    }
+}
+
+// spawn one of these to give the director somewhere to put its sources
+#[derive(Component)]
+pub struct AudioPlusMusic;
+
+#[derive(Component)]
+pub struct AudioPlusMusicLayer {
+    track: String,
+    intensity: f32,
+    volume: f32,
+}
+
+#[derive(Component)]
+pub struct AudioPlusMusicStinger;
+
+pub(crate) fn update_music_director(
+    mut director: ResMut<AudioPlusMusicDirector>,
+    music_query: Query<Entity, With<AudioPlusMusic>>,
+    mut layer_query: Query<(Entity, &mut AudioPlusMusicLayer, &mut AudioPlusSource)>,
+    stinger_query: Query<(Entity, &AudioPlusSource), With<AudioPlusMusicStinger>>,
+    time: Res<Time>,
+    mut commands: Commands,
+) {
+    let Ok(music_entity) = music_query.get_single() else { return };
+    let track_name = director.track_name().map(|name| name.to_owned());
+    let fade = if director.crossfade > 0. {
+        time.delta_seconds() / director.crossfade
+    } else {
+        1.
+    };
+
+    let mut track_playing = false;
+    for (entity, mut layer, mut source) in layer_query.iter_mut() {
+        let current = Some(&layer.track) == track_name.as_ref();
+        track_playing |= current;
+        let target = if current && director.intensity >= layer.intensity {
+            1.
+        } else {
+            0.
+        };
+        layer.volume = if layer.volume < target {
+            (layer.volume + fade).min(target)
+        } else {
+            (layer.volume - fade).max(target)
+        };
+        source.set_volume(layer.volume);
+        if !current && layer.volume == 0. {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+
+    if let (Some(track_name), false) = (&track_name, track_playing) {
+        if let Some(track) = director.track() {
+            commands.entity(music_entity).with_children(|parent| {
+                for (sound_effect, intensity) in track.layers.iter() {
+                    let mut source = AudioPlusSource::new(sound_effect.clone()).as_looping();
+                    source.set_volume(0.);
+                    parent.spawn((
+                        source,
+                        AudioPlusMusicLayer {
+                            track: track_name.clone(),
+                            intensity: *intensity,
+                            volume: 0.,
+                        },
+                    ));
+                }
+            });
+        }
+        director.beat_time = 0.;
+    }
+
+    let last_beat_time = director.beat_time;
+    director.beat_time += time.delta_seconds();
+    let on_beat = match director.track() {
+        Some(track) if track.bpm > 0. && track_playing => {
+            let beat_length = 60. / track.bpm;
+            (last_beat_time / beat_length).floor() != (director.beat_time / beat_length).floor()
+        }
+        _ => true,
+    };
+    if on_beat && !director.stingers.is_empty() {
+        let stingers = std::mem::take(&mut director.stingers);
+        commands.entity(music_entity).with_children(|parent| {
+            for sound_effect in stingers {
+                parent.spawn((
+                    AudioPlusSource::new(sound_effect).as_playing(),
+                    AudioPlusMusicStinger,
+                ));
+            }
+        });
+    }
+
+    for (entity, source) in stinger_query.iter() {
+        if !source.playing() {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
@@ -1,7 +1,15 @@
 pub use super::{
+    allocator::AudioPlusVoiceStats,
+    asset::AudioPlusSoundEffects,
+    bus::{AudioPlusBuses, AudioPlusDucker, AudioPlusMixSnapshot},
     effect::AudioPlusSoundEffect,
     listener::AudioPlusListener,
     mixer::{AudioPlusMixer, AudioPlusMixerChannel},
+    music::{
+        AudioPlusMusic, AudioPlusMusicDirector, AudioPlusMusicLayer, AudioPlusMusicStinger,
+        AudioPlusMusicTrack,
+    },
     source::AudioPlusSource,
+    spatial::{AudioPlusAttenuation, LOW_PASS_CLOSED, LOW_PASS_OPEN},
     AudioPlusPlugin,
 };
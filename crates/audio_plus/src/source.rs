@@ -1,7 +1,9 @@
 use crate::{
+    bus::AudioPlusBuses,
     effect::AudioPlusSoundEffect,
     listener::AudioPlusListener,
     mixer::{AudioPlusMixer, AudioPlusMixerChannel},
+    spatial::doppler,
     voice::{AudioPlusVoice, AudioPlusVoiceState},
 };
 use bevy::prelude::*;
@@ -11,6 +13,8 @@ pub struct AudioPlusSource {
     pub(crate) sound_effect: AudioPlusSoundEffect,
     pub(crate) voices: Vec<AudioPlusVoice>,
     pub(crate) next_voice: usize,
+    pub(crate) volume: f32,
+    pub(crate) last_position: Option<Vec2>,
 }
 
 impl AudioPlusSource {
@@ -19,6 +23,8 @@ impl AudioPlusSource {
             sound_effect,
             voices: vec![],
             next_voice: 0,
+            volume: 1.,
+            last_position: None,
         }
     }
 
@@ -51,8 +57,13 @@ impl AudioPlusSource {
             let voice = &mut self.voices[id];
             let audio_source = &self.sound_effect.audio_sources
                 [rand::random::<usize>() % self.sound_effect.audio_sources.len()];
+            // a channel may still be holding this voice, it restarts it instead of a second
+            // channel picking it up
+            let assigned = voice.assigned;
             voice.reset();
+            voice.assigned = assigned;
             voice.audio_source = Some(audio_source.clone());
+            voice.priority = self.sound_effect.priority;
             voice.state_dirty = true;
             voice.volume = (self.sound_effect.volume - self.sound_effect.volume_variation * 0.5
                 + rand::random::<f32>() * self.sound_effect.volume_variation)
@@ -92,6 +103,20 @@ impl AudioPlusSource {
         }
     }
 
+    pub fn playing(&self) -> bool {
+        self.voices
+            .iter()
+            .any(|voice| voice.state != AudioPlusVoiceState::Stopped)
+    }
+
+    pub fn set_volume(&mut self, volume: f32) {
+        self.volume = volume.clamp(0., 1.);
+    }
+
+    pub fn volume(&self) -> f32 {
+        self.volume
+    }
+
     pub fn effect(&self) -> &AudioPlusSoundEffect {
         &self.sound_effect
     }
@@ -108,32 +133,66 @@ pub(crate) fn update_audio_sources(
     )>,
     time: Res<Time>,
     mixer: Res<AudioPlusMixer>,
+    buses: Res<AudioPlusBuses>,
+    mut last_listener_position: Local<Option<Vec2>>,
 ) {
-    let listener_transform = if let Ok(transform) = queries.p1().get_single() {
-        Some(*transform)
+    let delta = time.delta_seconds();
+    let listener_position = if let Ok(transform) = queries.p1().get_single() {
+        Some(transform.translation().truncate())
     } else {
         None
     };
+    let listener_velocity = match (listener_position, *last_listener_position) {
+        (Some(position), Some(last_position)) if delta > 0. => (position - last_position) / delta,
+        _ => Vec2::ZERO,
+    };
+    *last_listener_position = listener_position;
     for (mut source, transform) in queries.p0().iter_mut() {
         source.create_voices();
+        let position = transform.map(|transform| transform.translation().truncate());
+        let velocity = match (position, source.last_position) {
+            (Some(position), Some(last_position)) if delta > 0. => {
+                (position - last_position) / delta
+            }
+            _ => Vec2::ZERO,
+        };
+        source.last_position = position;
         let mut volume = 1.;
         let mut panning = 0.5;
-        if source.sound_effect.positional && transform.is_some() && listener_transform.is_some() {
-            let relative_position = transform.unwrap().translation().truncate()
-                - listener_transform.unwrap().translation().truncate();
+        let mut playback_rate = 1.;
+        let mut low_pass = 0.;
+        if let (true, Some(position), Some(listener_position)) =
+            (source.sound_effect.positional, position, listener_position)
+        {
+            let effect = &source.sound_effect;
+            let relative_position = position - listener_position;
             let distance = relative_position.length();
-            volume *= ((source.sound_effect.distance - distance) / source.sound_effect.distance)
-                .clamp(0., 1.);
-            panning =
-                (0.5 + (relative_position.x / source.sound_effect.distance) * 1.2).clamp(0.2, 0.8);
+            volume *= effect
+                .attenuation
+                .volume(distance, effect.min_distance, effect.distance);
+            // sources inside the min distance drift towards the center instead of snapping
+            // between ears
+            panning = (0.5 + relative_position.x / distance.max(effect.min_distance).max(1.) * 0.4)
+                .clamp(0.1, 0.9);
+            playback_rate = doppler(
+                relative_position,
+                velocity - listener_velocity,
+                effect.doppler,
+            );
+            let range = (effect.distance - effect.min_distance).max(f32::EPSILON);
+            low_pass = ((distance - effect.min_distance) / range).clamp(0., 1.)
+                * effect.low_pass.clamp(0., 1.);
         }
-        if source.sound_effect.channel != AudioPlusMixerChannel::None {
-            volume *= mixer.get_volume(source.sound_effect.channel);
+        let channel = source.sound_effect.channel;
+        if channel != AudioPlusMixerChannel::None {
+            volume *= mixer.get_volume(channel) * buses.gain(channel);
         }
-        volume *= mixer.get_master_volume();
+        let reverb_send = buses.reverb_send(channel);
+        volume *= mixer.get_output_volume();
         let AudioPlusSource {
             voices,
             sound_effect,
+            volume: source_volume,
             ..
         } = source.as_mut();
         for voice in voices.iter_mut() {
@@ -158,10 +217,14 @@ pub(crate) fn update_audio_sources(
                 if voice.volume_fade == 0. {
                     voice.reset()
                 } else {
+                    // a source faded to silence keeps its channel so layered loops stay in sync
                     voice.should_assign =
                         voice.state != AudioPlusVoiceState::Stopped && volume > 0.;
-                    voice.volume_multiplier = volume;
+                    voice.volume_multiplier = volume * *source_volume;
                     voice.panning = panning;
+                    voice.playback_rate_multiplier = playback_rate;
+                    voice.low_pass = low_pass;
+                    voice.reverb_send = reverb_send;
                 }
             }
         }
@@ -0,0 +1,67 @@
+use bevy::prelude::*;
+use serde::Deserialize;
+
+// world units per second, low enough that a fast boat is audible
+pub(crate) const SPEED_OF_SOUND: f32 = 2400.;
+
+// low pass cutoffs in hz for an unfiltered and a fully filtered voice
+pub const LOW_PASS_OPEN: f32 = 20000.;
+pub const LOW_PASS_CLOSED: f32 = 400.;
+
+// how volume falls off between a sound effect's min distance and its distance
+#[derive(Default, Debug, Clone, Copy, Deserialize)]
+pub enum AudioPlusAttenuation {
+    #[default]
+    Linear,
+    Inverse {
+        rolloff: f32,
+    },
+    Exponential {
+        rolloff: f32,
+    },
+    // maps 0 at the min distance to 1 at the max distance, e.g. an easing function
+    #[serde(skip)]
+    Custom(fn(f32) -> f32),
+}
+
+impl AudioPlusAttenuation {
+    pub fn volume(&self, distance: f32, min_distance: f32, max_distance: f32) -> f32 {
+        let range = max_distance - min_distance;
+        let x = if range > 0. {
+            ((distance - min_distance) / range).clamp(0., 1.)
+        } else if distance > max_distance {
+            1.
+        } else {
+            0.
+        };
+        // every curve is rescaled so it reaches silence exactly at the max distance
+        let volume = match *self {
+            Self::Linear => 1. - x,
+            Self::Inverse { rolloff } if rolloff > 0. => {
+                let end = 1. / (1. + rolloff);
+                (1. / (1. + rolloff * x) - end) / (1. - end)
+            }
+            Self::Exponential { rolloff } if rolloff > 0. => {
+                let end = (-rolloff).exp();
+                ((-rolloff * x).exp() - end) / (1. - end)
+            }
+            Self::Inverse { .. } | Self::Exponential { .. } => 1. - x,
+            Self::Custom(curve) => 1. - curve(x),
+        };
+        volume.clamp(0., 1.)
+    }
+}
+
+// cutoff for a filter amount between 0 and 1, swept evenly in octaves rather than hz
+pub(crate) fn low_pass_cutoff(amount: f32) -> f32 {
+    LOW_PASS_OPEN * (LOW_PASS_CLOSED / LOW_PASS_OPEN).powf(amount.clamp(0., 1.))
+}
+
+// pitch multiplier from the speed the source and listener move apart
+pub(crate) fn doppler(relative_position: Vec2, relative_velocity: Vec2, scale: f32) -> f32 {
+    if scale <= 0. {
+        return 1.;
+    }
+    let receding = relative_velocity.dot(relative_position.normalize_or_zero()) * scale;
+    (SPEED_OF_SOUND / (SPEED_OF_SOUND + receding).max(f32::EPSILON)).clamp(0.5, 2.)
+}
@@ -1,6 +1,6 @@
 use bevy::prelude::*;
 
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub(crate) struct AudioPlusVoiceHandle {
     pub(crate) entity: Entity,
     pub(crate) index: usize,
@@ -9,12 +9,17 @@ pub(crate) struct AudioPlusVoiceHandle {
 pub(crate) struct AudioPlusVoice {
     pub(crate) should_assign: bool,
     pub(crate) assigned: bool,
+    pub(crate) stolen: bool,
+    pub(crate) priority: i32,
     pub(crate) audio_source: Option<Handle<bevy_kira_audio::AudioSource>>,
     pub(crate) volume: f32,
     pub(crate) volume_multiplier: f32,
     pub(crate) volume_fade: f32,
     pub(crate) panning: f32,
     pub(crate) playback_rate: f32,
+    pub(crate) playback_rate_multiplier: f32,
+    pub(crate) low_pass: f32,
+    pub(crate) reverb_send: f32,
     pub(crate) state: AudioPlusVoiceState,
     pub(crate) state_dirty: bool,
     pub(crate) stopping: bool,
@@ -32,12 +37,17 @@ impl AudioPlusVoice {
         Self {
             should_assign: false,
             assigned: false,
+            stolen: false,
+            priority: 0,
             audio_source: None,
             volume: 0.,
             volume_multiplier: 1.,
             volume_fade: 0.,
             panning: 0.5,
             playback_rate: 1.,
+            playback_rate_multiplier: 1.,
+            low_pass: 0.,
+            reverb_send: 0.,
             state: AudioPlusVoiceState::Stopped,
             state_dirty: false,
             stopping: false,
@@ -48,12 +58,17 @@ impl AudioPlusVoice {
     pub(crate) fn reset(&mut self) {
         self.should_assign = false;
         self.assigned = false;
+        self.stolen = false;
+        self.priority = 0;
         self.audio_source = None;
         self.volume = 0.;
         self.volume_multiplier = 1.;
         self.volume_fade = 0.;
         self.panning = 0.5;
         self.playback_rate = 1.;
+        self.playback_rate_multiplier = 1.;
+        self.low_pass = 0.;
+        self.reverb_send = 0.;
         self.state = AudioPlusVoiceState::Stopped;
         self.stopping = false;
         self.status = AudioPlusVoiceStatus::default();
@@ -0,0 +1,102 @@
+#![allow(dead_code)]
+
+use audio_plus::{mock::*, prelude::*};
+use bevy::{asset::HandleId, prelude::*};
+use bevy_kira_audio::AudioSource;
+use std::time::{Duration, Instant};
+
+pub const FRAME: f32 = 1. / 60.;
+
+// a headless app on the mock backend that only moves time when told to
+pub struct TestApp {
+    pub app: App,
+    now: Instant,
+}
+
+impl TestApp {
+    pub fn new() -> Self {
+        let mut app = App::new();
+        let now = Instant::now();
+        let mut time = Time::default();
+        time.update_with_instant(now);
+        app.insert_resource(time).add_plugin(AudioPlusMockPlugin);
+        Self { app, now }
+    }
+
+    pub fn step(&mut self, seconds: f32) {
+        self.now += Duration::from_secs_f32(seconds);
+        self.app
+            .world
+            .resource_mut::<Time>()
+            .update_with_instant(self.now);
+        self.app.update();
+    }
+
+    pub fn run(&mut self, seconds: f32) {
+        let mut elapsed = 0.;
+        while elapsed < seconds {
+            self.step(FRAME);
+            elapsed += FRAME;
+        }
+    }
+
+    pub fn spawn(&mut self, bundle: impl Bundle) -> Entity {
+        self.app.world.spawn(bundle).id()
+    }
+
+    pub fn source(&mut self, entity: Entity) -> Mut<AudioPlusSource> {
+        self.app.world.get_mut::<AudioPlusSource>(entity).unwrap()
+    }
+
+    pub fn backend(&self) -> &AudioPlusMockBackend {
+        self.app.world.resource::<AudioPlusMockBackend>()
+    }
+
+    pub fn backend_mut(&mut self) -> Mut<AudioPlusMockBackend> {
+        self.app.world.resource_mut::<AudioPlusMockBackend>()
+    }
+
+    pub fn plays(&self) -> usize {
+        self.count(|kind| matches!(kind, AudioPlusMockEventKind::Play { .. }))
+    }
+
+    pub fn stops(&self) -> usize {
+        self.count(|kind| matches!(kind, AudioPlusMockEventKind::Stop))
+    }
+
+    pub fn count(&self, filter: impl Fn(&AudioPlusMockEventKind) -> bool) -> usize {
+        self.backend()
+            .events()
+            .iter()
+            .filter(|event| filter(&event.kind))
+            .count()
+    }
+
+    // the channel currently playing the clip, when exactly one is
+    pub fn playing(&self, clip: &Handle<AudioSource>) -> AudioPlusMockChannel {
+        let channels = self
+            .backend()
+            .playing_source(clip)
+            .cloned()
+            .collect::<Vec<_>>();
+        assert_eq!(
+            channels.len(),
+            1,
+            "expected the clip on exactly one channel"
+        );
+        channels[0].clone()
+    }
+}
+
+pub fn clip() -> Handle<AudioSource> {
+    Handle::weak(HandleId::random::<AudioSource>())
+}
+
+pub fn assert_near(value: f32, expected: f32) {
+    assert!(
+        (value - expected).abs() < 0.03,
+        "expected {} to be near {}",
+        value,
+        expected
+    );
+}
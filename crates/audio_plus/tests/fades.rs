@@ -0,0 +1,144 @@
+mod common;
+
+use audio_plus::{mock::AudioPlusMockEventKind, prelude::*};
+use common::*;
+
+#[test]
+fn fade_in_ramps_volume() {
+    let mut test = TestApp::new();
+    let clip = clip();
+    test.spawn(
+        AudioPlusSource::new(AudioPlusSoundEffect::single(clip.clone()).with_fade(1., 0.))
+            .as_looping(),
+    );
+    test.run(0.25);
+    let early = test.playing(&clip).volume;
+    test.run(0.25);
+    let middle = test.playing(&clip).volume;
+    assert!(early > 0. && early < middle, "{} then {}", early, middle);
+    assert!(middle < 0.75);
+    test.run(1.);
+    assert_near(test.playing(&clip).volume, 1.);
+}
+
+#[test]
+fn fade_in_respects_effect_volume() {
+    let mut test = TestApp::new();
+    let clip = clip();
+    test.spawn(
+        AudioPlusSource::new(
+            AudioPlusSoundEffect::single(clip.clone())
+                .with_volume(0.5, 0.)
+                .with_fade(0.5, 0.),
+        )
+        .as_looping(),
+    );
+    test.run(1.);
+    assert_near(test.playing(&clip).volume, 0.5);
+}
+
+#[test]
+fn fade_out_then_stop() {
+    let mut test = TestApp::new();
+    let clip = clip();
+    let entity = test.spawn(
+        AudioPlusSource::new(AudioPlusSoundEffect::single(clip.clone()).with_fade(0., 1.))
+            .as_looping(),
+    );
+    test.run(0.5);
+    assert_near(test.playing(&clip).volume, 1.);
+
+    test.source(entity).stop();
+    test.run(0.5);
+    let volume = test.playing(&clip).volume;
+    assert!(volume > 0.3 && volume < 0.7, "{}", volume);
+    assert!(test.source(entity).playing());
+
+    test.run(0.75);
+    assert_eq!(test.backend().playing_channels().count(), 0);
+    assert!(!test.source(entity).playing());
+    let stop = test
+        .backend()
+        .events()
+        .iter()
+        .find(|event| event.kind == AudioPlusMockEventKind::Stop)
+        .expect("the channel should have been stopped");
+    assert!(stop.time > 1.4 && stop.time < 1.6, "{}", stop.time);
+}
+
+#[test]
+fn stop_without_fade_is_immediate() {
+    let mut test = TestApp::new();
+    let clip = clip();
+    let entity =
+        test.spawn(AudioPlusSource::new(AudioPlusSoundEffect::single(clip.clone())).as_looping());
+    test.run(0.25);
+    test.source(entity).stop();
+    test.run(0.1);
+    assert_eq!(test.backend().playing_channels().count(), 0);
+}
+
+#[test]
+fn source_volume_scales_voices() {
+    let mut test = TestApp::new();
+    let clip = clip();
+    let entity =
+        test.spawn(AudioPlusSource::new(AudioPlusSoundEffect::single(clip.clone())).as_looping());
+    test.run(0.1);
+    test.source(entity).set_volume(0.25);
+    test.run(0.1);
+    assert_near(test.playing(&clip).volume, 0.25);
+}
+
+#[test]
+fn mixer_channel_volume_applies() {
+    let mut test = TestApp::new();
+    let clip = clip();
+    test.app
+        .world
+        .resource_mut::<AudioPlusMixer>()
+        .set_volume(AudioPlusMixerChannel::Music, 0.5);
+    test.spawn(
+        AudioPlusSource::new(
+            AudioPlusSoundEffect::single(clip.clone()).with_channel(AudioPlusMixerChannel::Music),
+        )
+        .as_looping(),
+    );
+    test.run(0.1);
+    assert_near(test.playing(&clip).volume, 0.5);
+
+    test.app
+        .world
+        .resource_mut::<AudioPlusMixer>()
+        .set_muted(true);
+    test.run(0.1);
+    assert_eq!(test.backend().playing_channels().count(), 0);
+}
+
+#[test]
+fn snapshot_reverb_sends_reach_the_channel() {
+    let mut test = TestApp::new();
+    let clip = clip();
+    let mut buses = test.app.world.resource_mut::<AudioPlusBuses>();
+    buses.add_snapshot(
+        "Town",
+        AudioPlusMixSnapshot::new().with_reverb_send(AudioPlusMixerChannel::Sfx, 0.4),
+    );
+    buses.set_snapshot("Town", 0.);
+    test.spawn(
+        AudioPlusSource::new(
+            AudioPlusSoundEffect::single(clip.clone()).with_channel(AudioPlusMixerChannel::Sfx),
+        )
+        .as_looping(),
+    );
+    test.run(0.1);
+    assert_near(test.playing(&clip).reverb_send, 0.4);
+    assert_near(test.playing(&clip).volume, 1.);
+
+    test.app
+        .world
+        .resource_mut::<AudioPlusBuses>()
+        .clear_snapshot(0.);
+    test.run(0.1);
+    assert_near(test.playing(&clip).reverb_send, 0.);
+}
@@ -0,0 +1,128 @@
+mod common;
+
+use audio_plus::prelude::*;
+use common::*;
+
+#[test]
+fn play_reaches_a_channel() {
+    let mut test = TestApp::new();
+    let clip = clip();
+    test.spawn(AudioPlusSource::new(AudioPlusSoundEffect::single(clip.clone())).as_playing());
+    test.run(0.1);
+    let channel = test.playing(&clip);
+    assert!(!channel.looped);
+    assert_near(channel.volume, 1.);
+    assert_near(channel.panning, 0.5);
+    assert_near(channel.playback_rate, 1.);
+}
+
+#[test]
+fn voices_cycle_and_restart_the_oldest() {
+    let mut test = TestApp::new();
+    let clip = clip();
+    let entity = test.spawn(AudioPlusSource::new(
+        AudioPlusSoundEffect::single(clip.clone()).with_voices(2),
+    ));
+    for _ in 0..2 {
+        test.source(entity).play();
+        test.run(0.1);
+    }
+    assert_eq!(test.backend().playing_source(&clip).count(), 2);
+    assert_eq!(test.plays(), 2);
+
+    // the third play takes the first voice back, which restarts on the channel it already had
+    test.source(entity).play();
+    test.run(0.1);
+    assert_eq!(test.backend().playing_source(&clip).count(), 2);
+    assert_eq!(test.plays(), 3);
+    assert_eq!(test.stops(), 1);
+    let events = test.backend().events();
+    assert_eq!(events[0].channel, events[2].channel);
+}
+
+#[test]
+fn single_voice_restarts() {
+    let mut test = TestApp::new();
+    let clip = clip();
+    let entity = test.spawn(AudioPlusSource::new(AudioPlusSoundEffect::single(
+        clip.clone(),
+    )));
+    for _ in 0..3 {
+        test.source(entity).play();
+        test.run(0.1);
+    }
+    assert_eq!(test.backend().playing_source(&clip).count(), 1);
+    assert_eq!(test.plays(), 3);
+}
+
+#[test]
+fn chance_zero_never_plays() {
+    let mut test = TestApp::new();
+    let clip = clip();
+    let entity = test.spawn(AudioPlusSource::new(
+        AudioPlusSoundEffect::single(clip.clone()).with_chance(0.),
+    ));
+    for _ in 0..20 {
+        test.source(entity).play();
+        test.step(FRAME);
+    }
+    test.run(0.1);
+    assert_eq!(test.plays(), 0);
+    assert!(!test.source(entity).playing());
+}
+
+#[test]
+fn chance_one_always_plays() {
+    let mut test = TestApp::new();
+    let clip = clip();
+    let entity = test.spawn(AudioPlusSource::new(
+        AudioPlusSoundEffect::single(clip.clone()).with_chance(1.),
+    ));
+    for _ in 0..5 {
+        test.source(entity).play();
+        test.run(0.1);
+    }
+    assert_eq!(test.plays(), 5);
+}
+
+#[test]
+fn one_shot_releases_its_channel_when_finished() {
+    let mut test = TestApp::new();
+    let clip = clip();
+    test.backend_mut().set_length(&clip, 0.5);
+    let entity =
+        test.spawn(AudioPlusSource::new(AudioPlusSoundEffect::single(clip.clone())).as_playing());
+    test.run(0.2);
+    assert!(test.source(entity).playing());
+    test.run(0.6);
+    assert_eq!(test.backend().playing_channels().count(), 0);
+    assert!(!test.source(entity).playing());
+    assert_eq!(
+        test.count(|kind| matches!(kind, audio_plus::mock::AudioPlusMockEventKind::Finish)),
+        1
+    );
+}
+
+#[test]
+fn loops_keep_playing() {
+    let mut test = TestApp::new();
+    let clip = clip();
+    test.backend_mut().set_length(&clip, 0.5);
+    test.spawn(AudioPlusSource::new(AudioPlusSoundEffect::single(clip.clone())).as_looping());
+    test.run(2.);
+    assert!(test.playing(&clip).looped);
+    assert_eq!(test.plays(), 1);
+}
+
+#[test]
+fn despawned_sources_stop() {
+    let mut test = TestApp::new();
+    let clip = clip();
+    let entity =
+        test.spawn(AudioPlusSource::new(AudioPlusSoundEffect::single(clip.clone())).as_looping());
+    test.run(0.1);
+    test.app.world.despawn(entity);
+    test.run(0.1);
+    assert_eq!(test.backend().playing_channels().count(), 0);
+    assert_eq!(test.stops(), 1);
+}
@@ -1,26 +1,22 @@
 use crate::common::prelude::*;
 use crate::common::sound_effects::{sound_effects_create, SoundEffects};
 use asset_struct::AssetStruct;
+use audio_plus::prelude::*;
 use bevy::prelude::*;
-use bevy_kira_audio::AudioSource;
 
 #[derive(Default, AssetStruct, Resource)]
 pub struct AssetLibrary {
     pub sound_effects: SoundEffects,
 
-    /***************
-     * Placeholder *
-     ***************/
-    #[asset("audio/sfx/placeholder.ogg")]
-    pub audio_sfx_placeholder: Handle<AudioSource>,
-    #[asset("audio/music/placeholder.ogg")]
-    pub audio_music_placeholder: Handle<AudioSource>,
+    /*********
+     * Audio *
+     *********/
+    #[asset("audio/sound_effects.sfx.ron")]
+    pub sound_effect_definitions: Handle<AudioPlusSoundEffects>,
 
     /***********
      * Generic *
      ***********/
-    #[asset("audio/sfx/amb_sea_01.ogg")]
-    pub audio_sfx_sea: Handle<AudioSource>,
     #[asset("fonts/IMFellDoublePica-Regular.ttf")]
     pub font_default: Handle<Font>,
     #[asset("fonts/Pirate Kids.otf")]
@@ -41,26 +37,12 @@ pub struct AssetLibrary {
     pub sprite_dialogue_portrait_barkeep: Handle<Image>,
     #[asset("sprites/portrait_governor.png")]
     pub sprite_dialogue_portrait_governor: Handle<Image>,
-    #[asset("audio/sfx/dialogue_voice_generic_01.ogg")]
-    pub audio_sfx_dialogue_voice_generic_01: Handle<AudioSource>,
-    #[asset("audio/sfx/dialogue_voice_generic_02.ogg")]
-    pub audio_sfx_dialogue_voice_generic_02: Handle<AudioSource>,
-    #[asset("audio/sfx/dialogue_voice_generic_03.ogg")]
-    pub audio_sfx_dialogue_voice_generic_03: Handle<AudioSource>,
-    #[asset("audio/sfx/dialogue_voice_generic_04.ogg")]
-    pub audio_sfx_dialogue_voice_generic_04: Handle<AudioSource>,
-    #[asset("audio/sfx/dialogue_voice_generic_05.ogg")]
-    pub audio_sfx_dialogue_voice_generic_05: Handle<AudioSource>,
-    #[asset("audio/sfx/dialogue_voice_generic_06.ogg")]
-    pub audio_sfx_dialogue_voice_generic_06: Handle<AudioSource>,
-    #[asset("audio/sfx/dialogue_voice_generic_07.ogg")]
-    pub audio_sfx_dialogue_voice_generic_07: Handle<AudioSource>,
-    #[asset("audio/sfx/dialogue_voice_generic_08.ogg")]
-    pub audio_sfx_dialogue_voice_generic_08: Handle<AudioSource>,
-    #[asset("audio/sfx/dialogue_voice_generic_09.ogg")]
-    pub audio_sfx_dialogue_voice_generic_09: Handle<AudioSource>,
-    #[asset("audio/sfx/ui_button_click_01.ogg")]
-    pub audio_sfx_preview: Handle<AudioSource>,
+    #[asset("dialogue/story.dialogue.ron")]
+    pub dialogue_scripts: Handle<DialogueAsset>,
+    #[asset("locale/en.locale.ron")]
+    pub locale_en: Handle<LocaleAsset>,
+    #[asset("locale/es.locale.ron")]
+    pub locale_es: Handle<LocaleAsset>,
 
     /*********
      * Level *
@@ -108,15 +90,6 @@ pub struct AssetLibrary {
     #[asset("sprites/icon_volume.png")]
     pub menu_slider_icon: Handle<Image>,
 
-    #[asset("audio/music/menu.ogg")]
-    pub menu_music: Handle<AudioSource>,
-    #[asset("audio/sfx/ui_button_click_01.ogg")]
-    pub menu_sfx_button_click: Handle<AudioSource>,
-    #[asset("audio/sfx/ui_button_hover_01.ogg")]
-    pub menu_sfx_button_hover: Handle<AudioSource>,
-    #[asset("audio/sfx/ui_menu_play_01.ogg")]
-    pub menu_sfx_play: Handle<AudioSource>,
-
     /*************
      * Cutscenes *
      *************/
@@ -128,16 +101,6 @@ pub struct AssetLibrary {
     pub cutscene_image_intro3: Handle<Image>,
     #[asset("sprites/cutscene_intro4.png")]
     pub cutscene_image_intro4: Handle<Image>,
-    #[asset("audio/voice/intro1.ogg")]
-    pub cutscene_voice_intro1: Handle<AudioSource>,
-    #[asset("audio/voice/intro2.ogg")]
-    pub cutscene_voice_intro2: Handle<AudioSource>,
-    #[asset("audio/voice/intro3.ogg")]
-    pub cutscene_voice_intro3: Handle<AudioSource>,
-    #[asset("audio/voice/intro4.ogg")]
-    pub cutscene_voice_intro4: Handle<AudioSource>,
-    #[asset("audio/voice/intro5.ogg")]
-    pub cutscene_voice_intro5: Handle<AudioSource>,
 
     #[asset("sprites/cutscene_outro1.png")]
     pub cutscene_image_outro1: Handle<Image>,
@@ -145,12 +108,6 @@ pub struct AssetLibrary {
     pub cutscene_image_outro2: Handle<Image>,
     #[asset("sprites/cutscene_outro3.png")]
     pub cutscene_image_outro3: Handle<Image>,
-    #[asset("audio/voice/outro1.ogg")]
-    pub cutscene_voice_outro1: Handle<AudioSource>,
-    #[asset("audio/voice/outro2.ogg")]
-    pub cutscene_voice_outro2: Handle<AudioSource>,
-    #[asset("audio/voice/outro3.ogg")]
-    pub cutscene_voice_outro3: Handle<AudioSource>,
 
     /*************
      * Overworld *
@@ -223,41 +180,6 @@ pub struct AssetLibrary {
     #[asset("sprites/Bottle2.png")]
     pub sprite_rum_bottle: Handle<Image>,
 
-    #[asset("audio/sfx/ui_xp_collect_01.ogg")]
-    pub audio_sfx_xp_01: Handle<AudioSource>,
-    #[asset("audio/sfx/ui_xp_collect_02.ogg")]
-    pub audio_sfx_xp_02: Handle<AudioSource>,
-    #[asset("audio/sfx/ui_xp_collect_03.ogg")]
-    pub audio_sfx_xp_03: Handle<AudioSource>,
-
-    #[asset("audio/sfx/player_take_damage_01.ogg")]
-    pub audio_sfx_player_damage_01: Handle<AudioSource>,
-    #[asset("audio/sfx/player_take_damage_02.ogg")]
-    pub audio_sfx_player_damage_02: Handle<AudioSource>,
-    #[asset("audio/sfx/player_take_damage_03.ogg")]
-    pub audio_sfx_player_damage_03: Handle<AudioSource>,
-    #[asset("audio/sfx/player_died_jingle_01.ogg")]
-    pub audio_sfx_player_died: Handle<AudioSource>,
-
-    #[asset("audio/sfx/enemy_take_damage_01.ogg")]
-    pub audio_sfx_enemy_damage_01: Handle<AudioSource>,
-    #[asset("audio/sfx/enemy_take_damage_02.ogg")]
-    pub audio_sfx_enemy_damage_02: Handle<AudioSource>,
-    #[asset("audio/sfx/enemy_take_damage_03.ogg")]
-    pub audio_sfx_enemy_damage_03: Handle<AudioSource>,
-
-    #[asset("audio/sfx/ui_map_open_01.ogg")]
-    pub audio_sfx_map_open_01: Handle<AudioSource>,
-
-    #[asset("audio/sfx/ui_map_close_01.ogg")]
-    pub audio_sfx_map_close_01: Handle<AudioSource>,
-
-    #[asset("audio/sfx/ui_town_enter_01.ogg")]
-    pub audio_sfx_town_enter: Handle<AudioSource>,
-
-    #[asset("audio/sfx/ui_town_leave_01.ogg")]
-    pub audio_sfx_town_leave: Handle<AudioSource>,
-
     /****************
      * Overworld UI *
      ****************/
@@ -303,9 +225,6 @@ pub struct AssetLibrary {
     #[asset("sprites/HUD_Skill_Points_Notify_BG.png")]
     pub sprite_experience_skill_point_bg: Handle<Image>,
 
-    #[asset("audio/sfx/ui_level_up_01.ogg")]
-    pub audio_sfx_level_up: Handle<AudioSource>,
-
     /***********
      * Attacks *
      ***********/
@@ -322,67 +241,6 @@ pub struct AssetLibrary {
     pub sprite_tentacle: Handle<Image>,
     pub sprite_tentacle_atlas: Handle<TextureAtlas>,
 
-    #[asset("audio/music/shoot_drums_01.ogg")]
-    pub audio_sfx_attack_player_01: Handle<AudioSource>,
-    #[asset("audio/music/shoot_drums_02.ogg")]
-    pub audio_sfx_attack_player_02: Handle<AudioSource>,
-    #[asset("audio/music/shoot_drums_03.ogg")]
-    pub audio_sfx_attack_player_03: Handle<AudioSource>,
-    #[asset("audio/music/shoot_electricguitar_01.ogg")]
-    pub audio_sfx_attack_jagerossa_01: Handle<AudioSource>,
-    #[asset("audio/music/shoot_electricguitar_02.ogg")]
-    pub audio_sfx_attack_jagerossa_02: Handle<AudioSource>,
-    #[asset("audio/music/shoot_electricguitar_03.ogg")]
-    pub audio_sfx_attack_jagerossa_03: Handle<AudioSource>,
-    #[asset("audio/music/shoot_flute_01.ogg")]
-    pub audio_sfx_attack_ringo_01: Handle<AudioSource>,
-    #[asset("audio/music/shoot_flute_02.ogg")]
-    pub audio_sfx_attack_ringo_02: Handle<AudioSource>,
-    #[asset("audio/music/shoot_flute_03.ogg")]
-    pub audio_sfx_attack_ringo_03: Handle<AudioSource>,
-    #[asset("audio/music/shoot_harmonica_01.ogg")]
-    pub audio_sfx_attack_plank_01: Handle<AudioSource>,
-    #[asset("audio/music/shoot_harmonica_02.ogg")]
-    pub audio_sfx_attack_plank_02: Handle<AudioSource>,
-    #[asset("audio/music/shoot_harmonica_03.ogg")]
-    pub audio_sfx_attack_plank_03: Handle<AudioSource>,
-    #[asset("audio/music/shoot_accordion_01.ogg")]
-    pub audio_sfx_attack_davy_01: Handle<AudioSource>,
-    #[asset("audio/music/shoot_accordion_02.ogg")]
-    pub audio_sfx_attack_davy_02: Handle<AudioSource>,
-    #[asset("audio/music/shoot_accordion_03.ogg")]
-    pub audio_sfx_attack_davy_03: Handle<AudioSource>,
-
-    #[asset("audio/sfx/bomb_explode_01.ogg")]
-    pub audio_sfx_bomb_explode_01: Handle<AudioSource>,
-    #[asset("audio/sfx/bomb_explode_02.ogg")]
-    pub audio_sfx_bomb_explode_02: Handle<AudioSource>,
-    #[asset("audio/sfx/bomb_explode_03.ogg")]
-    pub audio_sfx_bomb_explode_03: Handle<AudioSource>,
-    #[asset("audio/sfx/bomb_throw_01.ogg")]
-    pub audio_sfx_bomb_throw_01: Handle<AudioSource>,
-    #[asset("audio/sfx/bomb_throw_02.ogg")]
-    pub audio_sfx_bomb_throw_02: Handle<AudioSource>,
-    #[asset("audio/sfx/bomb_throw_03.ogg")]
-    pub audio_sfx_bomb_throw_03: Handle<AudioSource>,
-
-    #[asset("audio/sfx/tentacle_appear_01.ogg")]
-    pub audio_sfx_tentacle_01: Handle<AudioSource>,
-    #[asset("audio/sfx/tentacle_appear_02.ogg")]
-    pub audio_sfx_tentacle_02: Handle<AudioSource>,
-    #[asset("audio/sfx/tentacle_appear_03.ogg")]
-    pub audio_sfx_tentacle_03: Handle<AudioSource>,
-
-    /********
-     * Dash *
-     ********/
-    #[asset("audio/sfx/player_dash_01.ogg")]
-    pub audio_sfx_dash_01: Handle<AudioSource>,
-    #[asset("audio/sfx/player_dash_02.ogg")]
-    pub audio_sfx_dash_02: Handle<AudioSource>,
-    #[asset("audio/sfx/player_dash_03.ogg")]
-    pub audio_sfx_dash_03: Handle<AudioSource>,
-
     /********
      * Town *
      ********/
@@ -402,16 +260,6 @@ pub struct AssetLibrary {
     pub sprite_town_mayor_notify: Handle<Image>,
     #[asset("sprites/WorldSkillMarker_Icon.png")]
     pub sprite_town_concert_hall_notify: Handle<Image>,
-    #[asset("audio/sfx/ui_rum_refill_jingle_01.ogg")]
-    pub audio_sfx_town_rum_refill_jingle: Handle<AudioSource>,
-    #[asset("audio/sfx/ui_rum_refill_clank_01.ogg")]
-    pub audio_sfx_town_rum_refill_clank_01: Handle<AudioSource>,
-    #[asset("audio/sfx/ui_rum_refill_clank_02.ogg")]
-    pub audio_sfx_town_rum_refill_clank_02: Handle<AudioSource>,
-    #[asset("audio/sfx/ui_rum_refill_clank_03.ogg")]
-    pub audio_sfx_town_rum_refill_clank_03: Handle<AudioSource>,
-    #[asset("audio/sfx/ui_confirm_01.ogg")]
-    pub audio_sfx_upgrade_01: Handle<AudioSource>,
 
     /***********************
      * Town / Concert Hall *
@@ -648,7 +496,9 @@ impl AssetLibrary {
         self.sprite_upgrades_button_atlas = texture_atlas_assets.add(texture_atlas);
     }
 
-    pub fn create_sound_effects(&mut self) {
-        self.sound_effects = sound_effects_create(&self);
+    pub fn create_sound_effects(&mut self, sound_effect_assets: &Assets<AudioPlusSoundEffects>) {
+        if let Some(definitions) = sound_effect_assets.get(&self.sound_effect_definitions) {
+            self.sound_effects = sound_effects_create(definitions);
+        }
     }
 }
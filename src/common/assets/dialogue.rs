@@ -0,0 +1,61 @@
+use crate::common::prelude::*;
+use bevy::{
+    asset::{AssetLoader, LoadContext, LoadedAsset},
+    prelude::*,
+    reflect::TypeUuid,
+    utils::BoxedFuture,
+};
+use serde::Deserialize;
+
+pub struct DialogueAssetPlugin;
+
+impl Plugin for DialogueAssetPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_asset::<DialogueAsset>()
+            .init_asset_loader::<DialogueAssetLoader>();
+    }
+}
+
+#[derive(Debug, Deserialize, TypeUuid)]
+#[uuid = "5c7a1f0e-3b8d-4e52-9a61-2f4d8c9b7e13"]
+pub struct DialogueAsset {
+    pub scripts: Vec<DialogueScript>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct DialogueScript {
+    pub id: String,
+    pub lines: Vec<DialogueLine>,
+    #[serde(default)]
+    pub choices: Vec<DialogueChoice>,
+}
+
+// the id names the line in other languages' locale tables, so it stays put when lines around it
+// are added or removed
+#[derive(Debug, Clone, Deserialize)]
+pub struct DialogueLine {
+    pub id: String,
+    pub portrait: DialoguePortrait,
+    pub text: String,
+}
+
+#[derive(Default)]
+pub struct DialogueAssetLoader;
+
+impl AssetLoader for DialogueAssetLoader {
+    fn load<'a>(
+        &'a self,
+        bytes: &'a [u8],
+        load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<(), bevy::asset::Error>> {
+        Box::pin(async move {
+            let scripts = ron::de::from_bytes::<Vec<DialogueScript>>(bytes)?;
+            load_context.set_default_asset(LoadedAsset::new(DialogueAsset { scripts }));
+            Ok(())
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["dialogue.ron"]
+    }
+}
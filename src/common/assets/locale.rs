@@ -0,0 +1,46 @@
+use crate::common::prelude::*;
+use bevy::{
+    asset::{AssetLoader, LoadContext, LoadedAsset},
+    prelude::*,
+    reflect::TypeUuid,
+    utils::BoxedFuture,
+};
+use serde::Deserialize;
+use std::collections::HashMap;
+
+pub struct LocaleAssetPlugin;
+
+impl Plugin for LocaleAssetPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_asset::<LocaleAsset>()
+            .init_asset_loader::<LocaleAssetLoader>();
+    }
+}
+
+#[derive(Debug, Deserialize, TypeUuid)]
+#[uuid = "9e41c2d7-6a0b-4f3e-8d15-7b2c0a4e6f98"]
+pub struct LocaleAsset {
+    pub language: Language,
+    pub strings: HashMap<String, String>,
+}
+
+#[derive(Default)]
+pub struct LocaleAssetLoader;
+
+impl AssetLoader for LocaleAssetLoader {
+    fn load<'a>(
+        &'a self,
+        bytes: &'a [u8],
+        load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<(), bevy::asset::Error>> {
+        Box::pin(async move {
+            let asset = ron::de::from_bytes::<LocaleAsset>(bytes)?;
+            load_context.set_default_asset(LoadedAsset::new(asset));
+            Ok(())
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["locale.ron"]
+    }
+}
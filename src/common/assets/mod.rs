@@ -4,8 +4,12 @@ pub struct AssetsPlugin;
 
 impl Plugin for AssetsPlugin {
     fn build(&self, app: &mut App) {
-        app.add_plugin(ldtk::LdtkAssetPlugin);
+        app.add_plugin(ldtk::LdtkAssetPlugin)
+            .add_plugin(dialogue::DialogueAssetPlugin)
+            .add_plugin(locale::LocaleAssetPlugin);
     }
 }
 
+pub mod dialogue;
 pub mod ldtk;
+pub mod locale;
@@ -0,0 +1,81 @@
+use crate::common::prelude::*;
+use audio_plus::prelude::*;
+use bevy::prelude::*;
+use bevy_egui::{egui, EguiContext};
+
+pub struct AudioDebugPlugin;
+
+impl Plugin for AudioDebugPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system(audio_debug);
+    }
+}
+
+// draws the min distance and falloff radius of every positional source over the world
+fn audio_debug(
+    mut egui_query: Query<&mut EguiContext>,
+    mut menu_bar: ResMut<MenuBar>,
+    camera_query: Query<(&Camera, &GlobalTransform)>,
+    source_query: Query<(&AudioPlusSource, &GlobalTransform)>,
+    listener_query: Query<&GlobalTransform, With<AudioPlusListener>>,
+    stats: Res<AudioPlusVoiceStats>,
+) {
+    menu_bar.item("Audio Sources", |open| {
+        let Some(mut egui_context) = egui_query.get_single_mut().ok() else { return };
+        let ctx = egui_context.get_mut();
+        egui::Window::new("Audio Sources")
+            .open(open)
+            .show(ctx, |ui| {
+                ui.label(format!("Assigned voices: {}", stats.assigned));
+                ui.label(format!("Unassigned voices: {}", stats.unassigned));
+                ui.label(format!("Stolen voices: {}", stats.stolen));
+            });
+        if !*open {
+            return;
+        }
+        let Ok((camera, camera_transform)) = camera_query.get_single() else { return };
+        let Some(viewport_size) = camera.logical_viewport_size() else { return };
+        let to_screen = |position: Vec3| {
+            camera
+                .world_to_viewport(camera_transform, position)
+                .map(|point| egui::pos2(point.x, viewport_size.y - point.y))
+        };
+        let painter = ctx.layer_painter(egui::LayerId::new(
+            egui::Order::Background,
+            egui::Id::new("audio_debug"),
+        ));
+        for (source, transform) in source_query.iter() {
+            let effect = source.effect();
+            if !effect.positional() {
+                continue;
+            }
+            let center = transform.translation();
+            let Some(screen_center) = to_screen(center) else { continue };
+            let color = if source.playing() {
+                egui::Color32::from_rgb(255, 200, 60)
+            } else {
+                egui::Color32::from_gray(120)
+            };
+            for (radius, alpha) in [(effect.min_distance(), 255), (effect.distance(), 120)] {
+                let Some(edge) = to_screen(center + Vec3::X * radius) else { continue };
+                painter.circle_stroke(
+                    screen_center,
+                    (edge.x - screen_center.x).abs(),
+                    egui::Stroke::new(
+                        1.,
+                        egui::Color32::from_rgba_unmultiplied(
+                            color.r(),
+                            color.g(),
+                            color.b(),
+                            alpha,
+                        ),
+                    ),
+                );
+            }
+        }
+        for transform in listener_query.iter() {
+            let Some(screen_center) = to_screen(transform.translation()) else { continue };
+            painter.circle_filled(screen_center, 4., egui::Color32::from_rgb(80, 200, 255));
+        }
+    });
+}
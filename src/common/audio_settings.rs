@@ -0,0 +1,271 @@
+use crate::common::{prelude::*, storage};
+use audio_plus::prelude::*;
+use bevy::{prelude::*, sprite::Anchor};
+use serde::{Deserialize, Serialize};
+
+const AUDIO_SETTINGS_KEY: &str = "audio.json";
+const AUDIO_SETTINGS_SAVE_DELAY: f32 = 0.5;
+const AUDIO_SETTINGS_ROW_HEIGHT: f32 = 48.;
+const AUDIO_SETTINGS_SLIDER_WIDTH: f32 = 116.;
+
+pub struct AudioSettingsPlugin;
+
+impl Plugin for AudioSettingsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_startup_system(audio_settings_load)
+            .add_system(audio_settings_save)
+            .add_system(audio_settings_slider_update)
+            .add_system(audio_settings_mute_update);
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct AudioSettings {
+    master: f32,
+    music: f32,
+    sfx: f32,
+    ambience: f32,
+    voice: f32,
+    ui: f32,
+    muted: bool,
+}
+
+impl AudioSettings {
+    fn from_mixer(mixer: &AudioPlusMixer) -> Self {
+        Self {
+            master: mixer.get_master_volume(),
+            music: mixer.get_volume(AudioPlusMixerChannel::Music),
+            sfx: mixer.get_volume(AudioPlusMixerChannel::Sfx),
+            ambience: mixer.get_volume(AudioPlusMixerChannel::Ambience),
+            voice: mixer.get_volume(AudioPlusMixerChannel::Voice),
+            ui: mixer.get_volume(AudioPlusMixerChannel::Ui),
+            muted: mixer.muted(),
+        }
+    }
+
+    fn apply(&self, mixer: &mut AudioPlusMixer) {
+        mixer.set_master_volume(self.master);
+        mixer.set_volume(AudioPlusMixerChannel::Music, self.music);
+        mixer.set_volume(AudioPlusMixerChannel::Sfx, self.sfx);
+        mixer.set_volume(AudioPlusMixerChannel::Ambience, self.ambience);
+        mixer.set_volume(AudioPlusMixerChannel::Voice, self.voice);
+        mixer.set_volume(AudioPlusMixerChannel::Ui, self.ui);
+        mixer.set_muted(self.muted);
+    }
+}
+
+fn audio_settings_load(mut mixer: ResMut<AudioPlusMixer>) {
+    let Some(contents) = storage::storage_read(AUDIO_SETTINGS_KEY) else { return };
+    match serde_json::from_str::<AudioSettings>(&contents) {
+        Ok(settings) => settings.apply(&mut mixer),
+        Err(err) => warn!("failed to read audio settings: {}", err),
+    }
+}
+
+// waits for the mixer to settle so dragging a slider doesn't write every frame
+fn audio_settings_save(mixer: Res<AudioPlusMixer>, mut timer: Local<Option<f32>>, time: Res<Time>) {
+    if mixer.is_changed() && !mixer.is_added() {
+        *timer = Some(AUDIO_SETTINGS_SAVE_DELAY);
+    }
+    let Some(remaining) = timer.as_mut() else { return };
+    *remaining -= time.raw_delta_seconds();
+    if *remaining <= 0. {
+        *timer = None;
+        let Ok(contents) = serde_json::to_string(&AudioSettings::from_mixer(&mixer)) else {
+            return;
+        };
+        if !storage::storage_write(AUDIO_SETTINGS_KEY, &contents) {
+            warn!("failed to save audio settings");
+        }
+    }
+}
+
+#[derive(Component)]
+pub struct AudioSettingsSlider {
+    // None controls the master volume
+    channel: Option<AudioPlusMixerChannel>,
+}
+
+#[derive(Component)]
+pub struct AudioSettingsKnob;
+
+#[derive(Component)]
+pub struct AudioSettingsMute;
+
+fn channel_name(channel: Option<AudioPlusMixerChannel>) -> &'static str {
+    match channel {
+        None => "audio.master",
+        Some(AudioPlusMixerChannel::Music) => "audio.music",
+        Some(AudioPlusMixerChannel::Sfx) => "audio.sfx",
+        Some(AudioPlusMixerChannel::Ambience) => "audio.ambience",
+        Some(AudioPlusMixerChannel::Voice) => "audio.voice",
+        Some(AudioPlusMixerChannel::Ui) => "audio.ui",
+        Some(AudioPlusMixerChannel::None) => "",
+    }
+}
+
+// spawns a slider for the master volume and every mixer channel followed by a mute toggle
+pub fn spawn_audio_settings_panel(
+    parent: &mut ChildBuilder,
+    asset_library: &AssetLibrary,
+    position: Vec2,
+    depth: (DepthLayer, f32),
+) {
+    let (depth_layer, depth) = depth;
+    let text_style = TextStyle {
+        font: asset_library.font_bold.clone(),
+        font_size: 28.,
+        color: Color::BLACK,
+    };
+    let channels = std::iter::once(None).chain(AudioPlusMixerChannel::ALL.into_iter().map(Some));
+    let mut row_position = position;
+    for channel in channels {
+        parent
+            .spawn((
+                VisibilityBundle::default(),
+                TransformBundle::default(),
+                Transform2::from_translation(row_position).with_depth((depth_layer, depth)),
+                Clickable {
+                    shape: CollisionShape::Rect {
+                        size: Vec2::new(AUDIO_SETTINGS_SLIDER_WIDTH + 24., 40.),
+                    },
+                    use_global: true,
+                    ..Default::default()
+                },
+                AudioSettingsSlider { channel },
+            ))
+            .with_children(|parent| {
+                parent.spawn((
+                    Text2dBundle {
+                        text: Text::from_section("", text_style.clone())
+                            .with_alignment(TextAlignment::Left),
+                        text_anchor: Anchor::CenterRight,
+                        ..Default::default()
+                    },
+                    Transform2::from_xy(-240., 0.).with_depth((depth_layer, depth + 0.001)),
+                    LocalizedText::new(channel_name(channel)),
+                ));
+                parent.spawn((
+                    SpriteBundle {
+                        texture: asset_library.menu_slider_back.clone(),
+                        ..Default::default()
+                    },
+                    Transform2::new()
+                        .with_scale(Vec2::ONE * 0.5)
+                        .with_depth((depth_layer, depth + 0.001)),
+                ));
+                parent.spawn((
+                    SpriteBundle {
+                        texture: asset_library.menu_slider_knob.clone(),
+                        ..Default::default()
+                    },
+                    Transform2::new()
+                        .with_scale(Vec2::ONE * 0.5)
+                        .with_depth((depth_layer, depth + 0.002)),
+                    AudioSettingsKnob,
+                ));
+            });
+        row_position.y -= AUDIO_SETTINGS_ROW_HEIGHT;
+    }
+    parent.spawn((
+        Text2dBundle {
+            text: Text::from_section("", text_style).with_alignment(TextAlignment::Left),
+            text_anchor: Anchor::CenterRight,
+            ..Default::default()
+        },
+        Transform2::from_translation(row_position + Vec2::new(-240., 0.))
+            .with_depth((depth_layer, depth + 0.001)),
+        Clickable {
+            shape: CollisionShape::Rect {
+                size: Vec2::new(240., 40.),
+            },
+            offset: Vec2::new(120., 0.),
+            use_global: true,
+            ..Default::default()
+        },
+        AudioSettingsMute,
+    ));
+}
+
+fn audio_settings_slider_update(
+    slider_query: Query<(
+        &AudioSettingsSlider,
+        &Clickable,
+        &GlobalTransform,
+        &ComputedVisibility,
+        &Children,
+    )>,
+    mut knob_query: Query<&mut Transform2, With<AudioSettingsKnob>>,
+    mouse: Res<Mouse>,
+    mut mixer: ResMut<AudioPlusMixer>,
+    asset_library: Res<AssetLibrary>,
+    mut commands: Commands,
+) {
+    for (slider, clickable, transform, visibility, children) in slider_query.iter() {
+        // the main menu keeps the panel around while it's hidden
+        let active = visibility.is_visible();
+        if active && clickable.just_clicked() {
+            commands.spawn((
+                AudioPlusSource::new(asset_library.sound_effects.sfx_menu_button_click.clone())
+                    .as_playing(),
+                TimeToLive { seconds: 3. },
+            ));
+        }
+        if active && clickable.just_released() {
+            let mut preview = asset_library.sound_effects.sfx_audio_preview.clone();
+            if let Some(channel) = slider.channel {
+                preview.set_channel(channel);
+            }
+            commands.spawn((
+                AudioPlusSource::new(preview).as_playing(),
+                Persistent,
+                TimeToLive { seconds: 3. },
+            ));
+        }
+        if active && clickable.clicked {
+            let volume = ((mouse.position.x - transform.translation().x)
+                / AUDIO_SETTINGS_SLIDER_WIDTH
+                + 0.5)
+                .clamp(0., 1.);
+            match slider.channel {
+                Some(channel) => mixer.set_volume(channel, volume),
+                None => mixer.set_master_volume(volume),
+            }
+        }
+        let volume = match slider.channel {
+            Some(channel) => mixer.get_volume(channel),
+            None => mixer.get_master_volume(),
+        };
+        for child in children.iter() {
+            if let Ok(mut knob_transform) = knob_query.get_mut(*child) {
+                knob_transform.translation.x = (volume - 0.5) * AUDIO_SETTINGS_SLIDER_WIDTH;
+            }
+        }
+    }
+}
+
+fn audio_settings_mute_update(
+    mut query: Query<(&mut Text, &Clickable, &ComputedVisibility), With<AudioSettingsMute>>,
+    mut mixer: ResMut<AudioPlusMixer>,
+    locale: Res<Locale>,
+) {
+    for (mut text, clickable, visibility) in query.iter_mut() {
+        if clickable.confirmed && visibility.is_visible() {
+            let muted = mixer.muted();
+            mixer.set_muted(!muted);
+        }
+        let label = locale.get(if mixer.muted() {
+            "audio.muted"
+        } else {
+            "audio.on"
+        });
+        if text.sections[0].value != label {
+            text.sections[0].value = label.to_owned();
+        }
+        text.sections[0].style.color = if clickable.hovered {
+            Color::rgb(0.5, 0.3, 0.1)
+        } else {
+            Color::BLACK
+        };
+    }
+}
@@ -0,0 +1,135 @@
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+
+pub const BROADPHASE_CELL_SIZE: f32 = 256.;
+
+// anything covering more cells than this skips the grid and is always a candidate
+const BROADPHASE_MAX_CELLS: f32 = 64.;
+
+// uniform grid over axis aligned bounds, stores indices into a list owned by the caller
+pub struct Broadphase {
+    cell_size: f32,
+    cells: HashMap<IVec2, Vec<usize>>,
+    large: Vec<usize>,
+    indices: Vec<usize>,
+    // first cell of each gridded index, so one spanning several cells is only reported once
+    first_cells: Vec<IVec2>,
+}
+
+impl Default for Broadphase {
+    fn default() -> Self {
+        Self::new(BROADPHASE_CELL_SIZE)
+    }
+}
+
+impl Broadphase {
+    pub fn new(cell_size: f32) -> Self {
+        Self {
+            cell_size,
+            cells: HashMap::default(),
+            large: vec![],
+            indices: vec![],
+            first_cells: vec![],
+        }
+    }
+
+    pub fn clear(&mut self) {
+        // keep the cell allocations around, entities rarely move far in one frame
+        for cell in self.cells.values_mut() {
+            cell.clear();
+        }
+        self.large.clear();
+        self.indices.clear();
+        self.first_cells.clear();
+    }
+
+    pub fn insert(&mut self, index: usize, min: Vec2, max: Vec2) {
+        self.indices.push(index);
+        self.insert_cells(index, self.cell_range(min, max));
+    }
+
+    // moves an index inserted with the old bounds over to the new ones
+    pub fn update(&mut self, index: usize, old: (Vec2, Vec2), new: (Vec2, Vec2)) {
+        let old_range = self.cell_range(old.0, old.1);
+        let new_range = self.cell_range(new.0, new.1);
+        if old_range == new_range {
+            return;
+        }
+        if let Some((cell_min, cell_max)) = old_range {
+            for y in cell_min.y..=cell_max.y {
+                for x in cell_min.x..=cell_max.x {
+                    if let Some(cell) = self.cells.get_mut(&IVec2::new(x, y)) {
+                        cell.retain(|other| *other != index);
+                    }
+                }
+            }
+        } else {
+            self.large.retain(|other| *other != index);
+        }
+        self.insert_cells(index, new_range);
+    }
+
+    // candidates are sorted so callers see them in insertion order
+    pub fn query(&self, min: Vec2, max: Vec2, results: &mut Vec<usize>) {
+        results.clear();
+        results.extend(self.iter(min, max));
+        results.sort_unstable();
+    }
+
+    // every index whose cells overlap the bounds exactly once, in no particular order
+    pub fn iter(&self, min: Vec2, max: Vec2) -> impl Iterator<Item = usize> + '_ {
+        let finite = min.is_finite() && max.is_finite();
+        let range = self.cell_range(min, max);
+        // an empty range when the query is too big for the grid or not finite
+        let (cell_min, cell_max) = range.unwrap_or((IVec2::ONE, IVec2::ZERO));
+        let all: &[usize] = if finite && range.is_none() {
+            &self.indices
+        } else {
+            &[]
+        };
+        let large: &[usize] = if range.is_some() { &self.large } else { &[] };
+        let gridded = (cell_min.y..=cell_max.y)
+            .flat_map(move |y| (cell_min.x..=cell_max.x).map(move |x| IVec2::new(x, y)))
+            .flat_map(move |cell| {
+                self.cells
+                    .get(&cell)
+                    .into_iter()
+                    .flatten()
+                    .copied()
+                    .filter(move |index| self.first_cells[*index].max(cell_min) == cell)
+            });
+        all.iter()
+            .copied()
+            .chain(gridded)
+            .chain(large.iter().copied())
+    }
+
+    fn insert_cells(&mut self, index: usize, range: Option<(IVec2, IVec2)>) {
+        let Some((cell_min, cell_max)) = range else {
+            self.large.push(index);
+            return;
+        };
+        if self.first_cells.len() <= index {
+            self.first_cells.resize(index + 1, IVec2::ZERO);
+        }
+        self.first_cells[index] = cell_min;
+        for y in cell_min.y..=cell_max.y {
+            for x in cell_min.x..=cell_max.x {
+                self.cells.entry(IVec2::new(x, y)).or_default().push(index);
+            }
+        }
+    }
+
+    fn cell_range(&self, min: Vec2, max: Vec2) -> Option<(IVec2, IVec2)> {
+        if !min.is_finite() || !max.is_finite() {
+            return None;
+        }
+        let cell_min = (min / self.cell_size).floor();
+        let cell_max = (max / self.cell_size).floor();
+        let cells = (cell_max - cell_min + Vec2::ONE).max(Vec2::ONE);
+        if cells.x * cells.y > BROADPHASE_MAX_CELLS {
+            return None;
+        }
+        Some((cell_min.as_ivec2(), cell_max.as_ivec2()))
+    }
+}
@@ -1,27 +1,47 @@
 use bevy::prelude::*;
 use bevy::transform::TransformSystem;
+use bevy::utils::HashMap;
+use broadphase::Broadphase;
 use shape::CollisionShape;
 
 pub const COLLISION_FLAG: u32 = 1;
 
+#[derive(Debug, Hash, PartialEq, Eq, Clone, SystemSet)]
+pub enum CollisionSystem {
+    Update,
+}
+
 pub struct CollisionPlugin;
 
 impl Plugin for CollisionPlugin {
     fn build(&self, app: &mut App) {
+        // rebuilt once a frame from the propagated transforms, for everything in the next update,
+        // anything moving during the update has to move its own entry with `translate`. other
+        // layers are inserted after `CollisionSystem::Update`
         app.init_resource::<CollisionQuery>().add_system(
             update_collision_query
+                .in_set(CollisionSystem::Update)
                 .in_base_set(CoreSet::PostUpdate)
-                .before(TransformSystem::TransformPropagate),
+                .after(TransformSystem::TransformPropagate),
         );
     }
 }
 
+// entries on different layers share the grid but never see each other, collision checks only
+// look at `CollisionLayer::Collision`
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum CollisionLayer {
+    Collision,
+    Hitbox,
+}
+
 #[derive(Component, Default)]
 pub struct Collision {
     pub shape: CollisionShape,
     pub flags: u32,
 }
 
+#[derive(Clone, Copy)]
 pub struct CollisionQueryEntry {
     pub entity: Entity,
     pub position: Vec2,
@@ -32,6 +52,9 @@ pub struct CollisionQueryEntry {
 #[derive(Default, Resource)]
 pub struct CollisionQuery {
     entries: Vec<CollisionQueryEntry>,
+    layers: Vec<CollisionLayer>,
+    broadphase: Broadphase,
+    lookup: HashMap<Entity, usize>,
 }
 
 #[derive(Copy, Clone)]
@@ -47,7 +70,7 @@ impl CollisionQuery {
         shape: CollisionShape,
         filter: Option<CollisionFilter>,
     ) -> Option<(Entity, Vec2)> {
-        for entry in self.entries.iter() {
+        for entry in self.candidates(shape.bounds(position)) {
             if shape.overlaps(position, entry.shape, entry.position) {
                 if let Some(ref filter) = filter {
                     if filter.exclude_entity != entry.entity && filter.flags & entry.flags != 0 {
@@ -69,7 +92,10 @@ impl CollisionQuery {
         filter: Option<CollisionFilter>,
     ) -> Option<(Entity, f32)> {
         let mut result: Option<(Entity, f32)> = None;
-        for entry in self.entries.iter() {
+        let bounds = shape
+            .bounds(position)
+            .map(|(min, max)| (min.min(min + velocity), max.max(max + velocity)));
+        for entry in self.candidates(bounds) {
             if let Some(collide_time) =
                 shape.overlaps_moving(position, velocity, entry.shape, entry.position, Vec2::ZERO)
             {
@@ -104,7 +130,7 @@ impl CollisionQuery {
         filter: Option<CollisionFilter>,
     ) -> Vec<Entity> {
         let mut vec: Vec<Entity> = vec![];
-        for entry in self.entries.iter() {
+        for entry in self.candidates(shape.bounds(position)) {
             if shape.overlaps(position, entry.shape, entry.position) {
                 if let Some(ref filter) = filter {
                     if filter.exclude_entity != entry.entity && filter.flags & entry.flags != 0 {
@@ -118,11 +144,55 @@ impl CollisionQuery {
         vec
     }
 
-    pub fn update(&mut self, query: &Query<(Entity, &GlobalTransform, &Collision)>) {
+    // every entry on the layer whose bounds touch the given ones, in no particular order
+    pub fn layer_candidates(
+        &self,
+        layer: CollisionLayer,
+        min: Vec2,
+        max: Vec2,
+    ) -> impl Iterator<Item = &CollisionQueryEntry> + '_ {
+        self.broadphase
+            .iter(min, max)
+            .filter(move |index| self.layers[*index] == layer)
+            .map(move |index| &self.entries[index])
+    }
+
+    pub fn clear(&mut self) {
         self.entries.clear();
+        self.layers.clear();
+        self.broadphase.clear();
+        self.lookup.clear();
+    }
+
+    pub fn insert(&mut self, entry: CollisionQueryEntry) {
+        self.lookup.insert(entry.entity, self.entries.len());
+        self.insert_layer(CollisionLayer::Collision, entry);
+    }
+
+    // entries on other layers can't be moved with `translate`
+    pub fn insert_layer(&mut self, layer: CollisionLayer, entry: CollisionQueryEntry) {
+        if let Some((min, max)) = entry.shape.bounds(entry.position) {
+            self.broadphase.insert(self.entries.len(), min, max);
+        }
+        self.entries.push(entry);
+        self.layers.push(layer);
+    }
+
+    pub fn translate(&mut self, entity: Entity, offset: Vec2) {
+        let Some(index) = self.lookup.get(&entity).copied() else { return };
+        let entry = &mut self.entries[index];
+        let old = entry.shape.bounds(entry.position);
+        entry.position += offset;
+        if let (Some(old), Some(new)) = (old, entry.shape.bounds(entry.position)) {
+            self.broadphase.update(index, old, new);
+        }
+    }
+
+    pub fn update(&mut self, query: &Query<(Entity, &GlobalTransform, &Collision)>) {
+        self.clear();
         for (entity, transform, collision) in query.iter() {
             if transform.translation().is_finite() {
-                self.entries.push(CollisionQueryEntry {
+                self.insert(CollisionQueryEntry {
                     entity,
                     position: transform.translation().truncate(),
                     shape: collision.shape,
@@ -131,6 +201,15 @@ impl CollisionQuery {
             }
         }
     }
+
+    fn candidates(
+        &self,
+        bounds: Option<(Vec2, Vec2)>,
+    ) -> impl Iterator<Item = &CollisionQueryEntry> + '_ {
+        bounds
+            .into_iter()
+            .flat_map(move |(min, max)| self.layer_candidates(CollisionLayer::Collision, min, max))
+    }
 }
 
 fn update_collision_query(
@@ -140,4 +219,5 @@ fn update_collision_query(
     collision_query.update(&query);
 }
 
+pub mod broadphase;
 pub mod shape;
@@ -4,30 +4,71 @@ use bevy::prelude::*;
 pub enum CollisionShape {
     None,
     Point,
-    Rect { size: Vec2 },
+    Rect {
+        size: Vec2,
+    },
+    Circle {
+        radius: f32,
+    },
+    // a segment of the given length swept by a circle, rotation in radians from the x axis
+    Capsule {
+        length: f32,
+        radius: f32,
+        rotation: f32,
+    },
+    OrientedRect {
+        size: Vec2,
+        rotation: f32,
+    },
 }
 
 impl CollisionShape {
     pub fn overlaps(&self, a_position: Vec2, b_shape: CollisionShape, b_position: Vec2) -> bool {
-        match *self {
-            CollisionShape::None => false,
-            CollisionShape::Point => match b_shape {
-                CollisionShape::None => false,
-                CollisionShape::Point => check_point_point(a_position, b_position),
-                CollisionShape::Rect { size: b_size } => {
-                    check_point_rect(a_position, b_position, b_size)
-                }
-            },
-            CollisionShape::Rect { size: a_size } => match b_shape {
-                CollisionShape::None => false,
-                CollisionShape::Point => check_point_rect(b_position, a_position, a_size),
-                CollisionShape::Rect { size: b_size } => {
-                    check_rect_rect(a_position, a_size, b_position, b_size)
-                }
+        match (*self, b_shape) {
+            (CollisionShape::None, _) | (_, CollisionShape::None) => false,
+            (CollisionShape::Point, CollisionShape::Point) => {
+                check_point_point(a_position, b_position)
+            }
+            (CollisionShape::Point, CollisionShape::Rect { size: b_size }) => {
+                check_point_rect(a_position, b_position, b_size)
+            }
+            (CollisionShape::Rect { size: a_size }, CollisionShape::Point) => {
+                check_point_rect(b_position, a_position, a_size)
+            }
+            (CollisionShape::Rect { size: a_size }, CollisionShape::Rect { size: b_size }) => {
+                check_rect_rect(a_position, a_size, b_position, b_size)
+            }
+            (a_shape, b_shape) => match (a_shape.hull(a_position), b_shape.hull(b_position)) {
+                (Some(a_hull), Some(b_hull)) => check_hull_hull(&a_hull, &b_hull),
+                _ => false,
             },
         }
     }
 
+    // axis aligned min and max corners, none for shapes that never overlap
+    pub fn bounds(&self, position: Vec2) -> Option<(Vec2, Vec2)> {
+        let extents = match *self {
+            CollisionShape::None => return None,
+            CollisionShape::Point => Vec2::ZERO,
+            CollisionShape::Rect { size } => size * 0.5,
+            CollisionShape::Circle { radius } => Vec2::splat(radius),
+            CollisionShape::Capsule {
+                length,
+                radius,
+                rotation,
+            } => (Vec2::from_angle(rotation) * length * 0.5).abs() + Vec2::splat(radius),
+            CollisionShape::OrientedRect { size, rotation } => {
+                let rotation = Vec2::from_angle(rotation).abs();
+                let half_size = size * 0.5;
+                Vec2::new(
+                    rotation.x * half_size.x + rotation.y * half_size.y,
+                    rotation.y * half_size.x + rotation.x * half_size.y,
+                )
+            }
+        };
+        Some((position - extents, position + extents))
+    }
+
     pub fn overlaps_moving(
         &self,
         a_position: Vec2,
@@ -36,26 +77,50 @@ impl CollisionShape {
         b_position: Vec2,
         b_velocity: Vec2,
     ) -> Option<f32> {
-        match *self {
-            CollisionShape::None => None,
-            CollisionShape::Point => match b_shape {
-                CollisionShape::None => None,
-                CollisionShape::Point => None,
-                CollisionShape::Rect { .. } => None,
-            },
-            CollisionShape::Rect { size: a_size } => match b_shape {
-                CollisionShape::None => None,
-                CollisionShape::Point => None,
-                CollisionShape::Rect { size: b_size } => check_rect_moving_rect(
+        match (*self, b_shape) {
+            (CollisionShape::None, _) | (_, CollisionShape::None) => None,
+            (CollisionShape::Rect { size: a_size }, CollisionShape::Rect { size: b_size }) => {
+                check_rect_moving_rect(
                     a_position,
                     a_size,
                     b_position,
                     b_size,
                     b_velocity - a_velocity,
-                ),
+                )
+            }
+            // moving points were never swept, only the newer shapes are
+            (
+                CollisionShape::Point | CollisionShape::Rect { .. },
+                CollisionShape::Point | CollisionShape::Rect { .. },
+            ) => None,
+            (a_shape, b_shape) => match (a_shape.hull(a_position), b_shape.hull(b_position)) {
+                (Some(a_hull), Some(b_hull)) => {
+                    check_hull_moving_hull(&a_hull, &b_hull, b_velocity - a_velocity)
+                }
+                _ => None,
             },
         }
     }
+
+    fn hull(&self, position: Vec2) -> Option<Hull> {
+        match *self {
+            CollisionShape::None => None,
+            CollisionShape::Point => Some(Hull::new(&[position], 0.)),
+            CollisionShape::Rect { size } => Some(Hull::new(&rect_corners(position, size, 0.), 0.)),
+            CollisionShape::Circle { radius } => Some(Hull::new(&[position], radius)),
+            CollisionShape::Capsule {
+                length,
+                radius,
+                rotation,
+            } => {
+                let axis = Vec2::from_angle(rotation) * length * 0.5;
+                Some(Hull::new(&[position - axis, position + axis], radius))
+            }
+            CollisionShape::OrientedRect { size, rotation } => {
+                Some(Hull::new(&rect_corners(position, size, rotation), 0.))
+            }
+        }
+    }
 }
 
 impl Default for CollisionShape {
@@ -178,3 +243,164 @@ pub fn check_rect_moving_rect(
         Some(t_first)
     }
 }
+
+// counter clockwise
+fn rect_corners(position: Vec2, size: Vec2, rotation: f32) -> [Vec2; 4] {
+    let rotation = Vec2::from_angle(rotation);
+    let half_size = size * 0.5;
+    [
+        Vec2::new(-half_size.x, -half_size.y),
+        Vec2::new(half_size.x, -half_size.y),
+        Vec2::new(half_size.x, half_size.y),
+        Vec2::new(-half_size.x, half_size.y),
+    ]
+    .map(|corner| position + rotation.rotate(corner))
+}
+
+// a point, segment or convex polygon grown by a radius, every shape but none is one of these
+#[derive(Clone, Copy, Debug)]
+struct Hull {
+    points: [Vec2; 4],
+    len: usize,
+    radius: f32,
+}
+
+impl Hull {
+    fn new(points: &[Vec2], radius: f32) -> Self {
+        let mut hull = Self {
+            points: [Vec2::ZERO; 4],
+            len: points.len(),
+            radius,
+        };
+        hull.points[..points.len()].copy_from_slice(points);
+        hull
+    }
+
+    fn translated(&self, offset: Vec2) -> Self {
+        Self {
+            points: self.points.map(|point| point + offset),
+            ..*self
+        }
+    }
+
+    fn edges(&self) -> impl Iterator<Item = (Vec2, Vec2)> + '_ {
+        let count = if self.len <= 2 { 1 } else { self.len };
+        (0..count).map(move |index| (self.points[index], self.points[(index + 1) % self.len]))
+    }
+
+    fn contains(&self, point: Vec2) -> bool {
+        // flat polygons are left to the edge checks
+        if self.len < 3 || self.edges().map(|(a, b)| a.perp_dot(b)).sum::<f32>() <= 0. {
+            return false;
+        }
+        self.edges().all(|(a, b)| (b - a).perp_dot(point - a) >= 0.)
+    }
+
+    // distance between the cores, ignoring the radius
+    fn distance(&self, other: &Hull) -> f32 {
+        if self.contains(other.points[0]) || other.contains(self.points[0]) {
+            return 0.;
+        }
+        let mut distance = f32::INFINITY;
+        for (a_start, a_end) in self.edges() {
+            for (b_start, b_end) in other.edges() {
+                distance = distance.min(segment_distance(a_start, a_end, b_start, b_end));
+            }
+        }
+        distance
+    }
+}
+
+fn point_segment_distance(point: Vec2, start: Vec2, end: Vec2) -> f32 {
+    let segment = end - start;
+    let length_squared = segment.length_squared();
+    let t = if length_squared == 0. {
+        0.
+    } else {
+        ((point - start).dot(segment) / length_squared).clamp(0., 1.)
+    };
+    point.distance(start + segment * t)
+}
+
+fn segment_distance(a_start: Vec2, a_end: Vec2, b_start: Vec2, b_end: Vec2) -> f32 {
+    let a = a_end - a_start;
+    let b = b_end - b_start;
+    let d1 = b.perp_dot(a_start - b_start);
+    let d2 = b.perp_dot(a_end - b_start);
+    let d3 = a.perp_dot(b_start - a_start);
+    let d4 = a.perp_dot(b_end - a_start);
+    if d1 * d2 < 0. && d3 * d4 < 0. {
+        return 0.;
+    }
+    point_segment_distance(a_start, b_start, b_end)
+        .min(point_segment_distance(a_end, b_start, b_end))
+        .min(point_segment_distance(b_start, a_start, a_end))
+        .min(point_segment_distance(b_end, a_start, a_end))
+}
+
+fn check_hull_hull(a_hull: &Hull, b_hull: &Hull) -> bool {
+    a_hull.distance(b_hull) <= a_hull.radius + b_hull.radius
+}
+
+// b moves by b_velocity over t = 0..1, returns the first t the two touch. the first contact is
+// always a corner of one core reaching an edge of the other grown by both radii, so every corner
+// is cast against the other's edges
+fn check_hull_moving_hull(a_hull: &Hull, b_hull: &Hull, b_velocity: Vec2) -> Option<f32> {
+    let radius = a_hull.radius + b_hull.radius;
+    if a_hull.distance(b_hull) <= radius {
+        return Some(0.);
+    }
+    if b_velocity.length_squared() == 0. {
+        return None;
+    }
+    let mut first = f32::INFINITY;
+    for (hull, other, velocity) in [(a_hull, b_hull, b_velocity), (b_hull, a_hull, -b_velocity)] {
+        for point in other.points[..other.len].iter() {
+            for (start, end) in hull.edges() {
+                if let Some(t) = cast_capsule(*point, velocity, start, end, radius) {
+                    first = first.min(t);
+                }
+            }
+        }
+    }
+    (first <= 1.).then_some(first)
+}
+
+// first t >= 0 the point moving by velocity is within radius of the segment
+fn cast_capsule(point: Vec2, velocity: Vec2, start: Vec2, end: Vec2, radius: f32) -> Option<f32> {
+    let mut first = cast_circle(point, velocity, start, radius);
+    if let Some(t) = cast_circle(point, velocity, end, radius) {
+        first = Some(first.map_or(t, |first| first.min(t)));
+    }
+    let axis = end - start;
+    let denominator = velocity.perp_dot(axis);
+    if axis.length_squared() == 0. || denominator == 0. {
+        return first;
+    }
+    let normal = axis.perp().normalize() * radius;
+    for side in [normal, -normal] {
+        let offset = start + side - point;
+        let t = offset.perp_dot(axis) / denominator;
+        let along = offset.perp_dot(velocity) / denominator;
+        if t >= 0. && (0. ..=1.).contains(&along) {
+            first = Some(first.map_or(t, |first| first.min(t)));
+        }
+    }
+    first
+}
+
+fn cast_circle(point: Vec2, velocity: Vec2, center: Vec2, radius: f32) -> Option<f32> {
+    let offset = point - center;
+    let a = velocity.length_squared();
+    let b = offset.dot(velocity);
+    let c = offset.length_squared() - radius * radius;
+    if c <= 0. {
+        return Some(0.);
+    }
+    let discriminant = b * b - a * c;
+    if discriminant < 0. || a == 0. {
+        return None;
+    }
+    let t = (-b - discriminant.sqrt()) / a;
+    (t >= 0.).then_some(t)
+}
@@ -1,94 +1,204 @@
 use crate::common::prelude::*;
-use bevy::prelude::*;
+use bevy::{ecs::query::QueryItem, prelude::*};
 use bevy_egui::{egui, EguiContext};
 use std::{
-    any::{type_name, TypeId},
+    any::{type_name, Any},
     collections::VecDeque,
     marker::PhantomData,
-    sync::{RwLock, RwLockWriteGuard},
 };
 
 pub trait CutsceneType: Cutscene + Default + Clone + Send + Sync + 'static {}
 impl<T> CutsceneType for T where T: Cutscene + Default + Clone + Send + Sync + 'static {}
 
-const UPDATE_BUFFER: u32 = 3;
+// every frame a running cutscene moves through these sets in order:
+// queued cutscenes begin, the current step's init or update systems run, then the step advances
+#[derive(Debug, Hash, PartialEq, Eq, Clone, SystemSet)]
+pub enum CutsceneSystem {
+    Queue,
+    Begin,
+    Init,
+    Update,
+    Advance,
+    Finish,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct CutsceneId(usize);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum CutscenePhase {
+    Begin,
+    Init,
+    Update,
+}
 
-#[derive(Default, Debug, Resource)]
+#[derive(Default, Resource)]
 pub struct Cutscenes {
-    // FIXME: the way cutscenes works is dreadful. it was a quick hack during the jam, made worse
-    // by Bevy's API forbidding our awful practices, but us doing it anyway. this class needs a
-    // serious overhaul, and it shouldn't be too difficult to do!
-    data: RwLock<CutscenesData>,
+    registered: Vec<RegisteredCutscene>,
+    running: Option<RunningCutscene>,
+    backlog: VecDeque<QueuedCutscene>,
+}
+
+struct RegisteredCutscene {
+    name: String,
+    // the steps of each chain, chain 0 is the cutscene itself
+    // true for steps that wait on a continue event instead of advancing themselves
+    chains: Vec<Vec<bool>>,
+}
+
+struct QueuedCutscene {
+    id: CutsceneId,
+    state: Box<dyn Any + Send + Sync>,
+}
+
+struct RunningCutscene {
+    id: CutsceneId,
+    state: Option<Box<dyn Any + Send + Sync>>,
+    tracks: Vec<CutsceneTrack>,
+    skip: bool,
+    finished: bool,
+}
+
+// a chain of steps that is currently playing
+// parallel and branch steps start tracks for their chains and wait for them to finish
+struct CutsceneTrack {
+    chain: usize,
+    step: usize,
+    phase: CutscenePhase,
+    time: f32,
+    advance: bool,
+    waiting: Vec<usize>,
 }
 
-#[derive(Default, Debug)]
-pub struct CutscenesData {
-    running_cutscene: Option<RunningCutscene>,
-    backlog_cutscenes: VecDeque<(String, TypeId)>,
+impl CutsceneTrack {
+    fn new(chain: usize, phase: CutscenePhase) -> Self {
+        Self {
+            chain,
+            step: 0,
+            phase,
+            time: 0.,
+            advance: false,
+            waiting: vec![],
+        }
+    }
+
+    fn next(&mut self) {
+        self.step += 1;
+        self.phase = CutscenePhase::Init;
+        self.time = 0.;
+        self.advance = false;
+    }
 }
 
 impl Cutscenes {
-    fn queue(&mut self, name: String, type_id: TypeId) {
-        let mut lock = self.data.write().unwrap();
-        lock.backlog_cutscenes.push_back((name, type_id));
+    fn register(&mut self, name: &str) -> CutsceneId {
+        self.registered.push(RegisteredCutscene {
+            name: name.to_owned(),
+            chains: vec![],
+        });
+        CutsceneId(self.registered.len() - 1)
+    }
+
+    fn add_chain(&mut self, id: CutsceneId) -> usize {
+        let chains = &mut self.registered[id.0].chains;
+        chains.push(vec![]);
+        chains.len() - 1
+    }
+
+    fn set_chain_steps(&mut self, id: CutsceneId, chain: usize, steps: Vec<bool>) {
+        self.registered[id.0].chains[chain] = steps;
+    }
+
+    fn queue(&mut self, id: CutsceneId, state: Box<dyn Any + Send + Sync>) {
+        self.backlog.push_back(QueuedCutscene { id, state });
+        self.try_run_next();
     }
 
     fn try_run_next(&mut self) {
-        let mut lock = self.data.write().unwrap();
-        if matches!(lock.running_cutscene, None) {
-            if let Some((name, type_id)) = lock.backlog_cutscenes.pop_front() {
-                lock.running_cutscene = Some(RunningCutscene {
-                    name,
-                    type_id,
-                    started: false,
-                    init: true,
-                    updates: 0,
-                    step: 0,
+        if self.running.is_none() {
+            if let Some(queued) = self.backlog.pop_front() {
+                self.running = Some(RunningCutscene {
+                    id: queued.id,
+                    state: Some(queued.state),
+                    tracks: vec![],
                     skip: false,
-                    time: 0.,
+                    finished: false,
                 });
             }
         }
     }
 
-    pub fn skipping(&self) -> bool {
-        let lock = self.data.read().unwrap();
-        if let Some(running_cutscene) = &lock.running_cutscene {
-            running_cutscene.skip
-        } else {
-            false
+    fn running_as(&self, id: CutsceneId) -> Option<&RunningCutscene> {
+        self.running.as_ref().filter(|running| running.id == id)
+    }
+
+    fn running_as_mut(&mut self, id: CutsceneId) -> Option<&mut RunningCutscene> {
+        self.running.as_mut().filter(|running| running.id == id)
+    }
+
+    fn track(&self, chain: usize) -> Option<&CutsceneTrack> {
+        let running = self.running.as_ref()?;
+        running.tracks.iter().find(|track| track.chain == chain)
+    }
+
+    fn track_mut(&mut self, chain: usize) -> Option<&mut CutsceneTrack> {
+        let running = self.running.as_mut()?;
+        running.tracks.iter_mut().find(|track| track.chain == chain)
+    }
+
+    fn at_step(&self, id: CutsceneId, chain: usize, step: usize, phase: CutscenePhase) -> bool {
+        self.running_as(id).is_some()
+            && self
+                .track(chain)
+                .map(|track| track.step == step && track.phase == phase)
+                .unwrap_or(false)
+    }
+
+    // skipped steps run their update once and should see themselves as done
+    fn step_time(&self, chain: usize) -> f32 {
+        if self.skipping() {
+            return f32::INFINITY;
         }
+        self.track(chain).map(|track| track.time).unwrap_or(0.)
     }
 
-    pub fn running(&self) -> bool {
-        let lock = self.data.read().unwrap();
-        !matches!(lock.running_cutscene, None)
+    fn next_step(&mut self, chain: usize) {
+        if let Some(track) = self.track_mut(chain) {
+            track.advance = true;
+        }
     }
 
-    pub fn clear(&mut self) {
-        let mut lock = self.data.write().unwrap();
-        lock.running_cutscene = None;
-        lock.backlog_cutscenes = VecDeque::new();
+    fn start_chains(&mut self, parent: usize, chains: &[usize]) {
+        let Some(running) = &mut self.running else { return };
+        for chain in chains.iter() {
+            running
+                .tracks
+                .push(CutsceneTrack::new(*chain, CutscenePhase::Begin));
+        }
+        if let Some(track) = running
+            .tracks
+            .iter_mut()
+            .find(|track| track.chain == parent)
+        {
+            track.waiting = chains.to_vec();
+            track.advance = true;
+        }
     }
-}
 
-#[derive(Debug)]
-struct RunningCutscene {
-    name: String,
-    type_id: TypeId,
-    started: bool,
-    init: bool,
-    updates: u32,
-    step: usize,
-    skip: bool,
-    time: f32,
-}
+    pub fn skipping(&self) -> bool {
+        self.running
+            .as_ref()
+            .map(|running| running.skip)
+            .unwrap_or(false)
+    }
 
-impl RunningCutscene {
-    fn reset(&mut self) {
-        self.init = true;
-        self.updates = 0;
-        self.time = 0.;
+    pub fn running(&self) -> bool {
+        self.running.is_some()
+    }
+
+    pub fn clear(&mut self) {
+        self.running = None;
+        self.backlog = VecDeque::new();
     }
 }
 
@@ -97,7 +207,18 @@ pub struct CutscenePlugin;
 impl Plugin for CutscenePlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<Cutscenes>()
-            .init_resource::<Cutscenes>()
+            .configure_sets(
+                (
+                    CutsceneSystem::Queue,
+                    CutsceneSystem::Begin,
+                    CutsceneSystem::Init,
+                    CutsceneSystem::Update,
+                    CutsceneSystem::Advance,
+                    CutsceneSystem::Finish,
+                )
+                    .chain(),
+            )
+            .add_system(cutscene_advance.in_set(CutsceneSystem::Advance))
             .add_system(cutscene_debug);
     }
 }
@@ -113,179 +234,329 @@ impl AddAppCutscene for App {
     where
         T: CutsceneType + Resource,
     {
+        self.init_resource::<Cutscenes>();
+        let id = self
+            .world
+            .resource_mut::<Cutscenes>()
+            .register(type_name::<T>());
         self.init_resource::<T>();
-        self.init_resource::<CutsceneInitialValues<T>>();
         self.add_event::<CutsceneStartEvent<T>>();
         self.add_event::<CutsceneContinueEvent<T>>();
         self.add_event::<CutsceneSkipEvent<T>>();
-        self.add_system(cutscene_start::<T>);
-        self.add_system(cutscene_continue::<T>);
-        self.add_system(cutscene_skip::<T>);
-        let mut builder = CutsceneBuilder {
-            app: self,
-            type_id: TypeId::of::<T>(),
-            step: 0,
-        };
-        T::build(&mut builder);
-        let step = builder.step;
+        self.add_event::<CutsceneFinishedEvent<T>>();
         self.add_system(
-            move |mut cutscene_state: ResMut<Cutscenes>,
-                  mut state: ResMut<T>,
-                  mut initial_values: ResMut<CutsceneInitialValues<T>>| {
-                let mut try_next_run = false;
-                let mut reset = false;
-                {
-                    let mut lock = cutscene_state.data.write().unwrap();
-                    if let Some(running_cutscene) = &mut lock.running_cutscene {
-                        if running_cutscene.type_id == TypeId::of::<T>() {
-                            if !running_cutscene.started {
-                                *state = initial_values.0.pop_front().unwrap();
-                                running_cutscene.started = true;
-                            } else if running_cutscene.step == step {
-                                lock.running_cutscene = None;
-                                try_next_run = true;
-                            } else if running_cutscene.skip {
-                                if running_cutscene.updates == UPDATE_BUFFER {
-                                    reset = true;
-                                    running_cutscene.step += 1;
-                                }
-                            }
+            (move |mut ev_cutscene_start: EventReader<CutsceneStartEvent<T>>,
+                   mut cutscenes: ResMut<Cutscenes>| {
+                for event in ev_cutscene_start.iter() {
+                    cutscenes.queue(id, Box::new(event.0.clone()));
+                }
+            })
+            .in_set(CutsceneSystem::Queue),
+        );
+        self.add_system(
+            (move |mut cutscenes: ResMut<Cutscenes>, mut state: ResMut<T>| {
+                let Some(running) = cutscenes.running_as_mut(id) else { return };
+                if let Some(initial) = running.state.take() {
+                    if let Ok(initial) = initial.downcast::<T>() {
+                        *state = *initial;
+                    }
+                    running
+                        .tracks
+                        .push(CutsceneTrack::new(0, CutscenePhase::Init));
+                }
+            })
+            .in_set(CutsceneSystem::Begin),
+        );
+        self.add_system(
+            (move |mut ev_cutscene_continue: EventReader<CutsceneContinueEvent<T>>,
+                   mut ev_cutscene_skip: EventReader<CutsceneSkipEvent<T>>,
+                   mut cutscenes: ResMut<Cutscenes>| {
+                let continued = ev_cutscene_continue.iter().last().is_some();
+                let skipped = ev_cutscene_skip.iter().last().is_some();
+                let Cutscenes {
+                    registered,
+                    running,
+                    ..
+                } = &mut *cutscenes;
+                let Some(running) = running.as_mut().filter(|running| running.id == id) else {
+                    return;
+                };
+                let chains = &registered[id.0].chains;
+                if continued {
+                    // tracks that advance on their own, like tweens, keep playing
+                    for track in running.tracks.iter_mut() {
+                        if track.phase == CutscenePhase::Update
+                            && track.waiting.is_empty()
+                            && chains[track.chain][track.step]
+                        {
+                            track.advance = true;
                         }
                     }
                 }
-                if try_next_run {
-                    cutscene_state.try_run_next();
+                if skipped {
+                    running.skip = true;
                 }
-                if reset {
-                    let mut lock = cutscene_state.data.write().unwrap();
-                    if let Some(running_cutscene) = &mut lock.running_cutscene {
-                        running_cutscene.reset();
-                    }
+            })
+            .in_set(CutsceneSystem::Advance)
+            .before(cutscene_advance),
+        );
+        build_chain(self, id, T::build);
+        self.add_system(
+            (move |mut cutscenes: ResMut<Cutscenes>,
+                   mut ev_cutscene_finished: EventWriter<CutsceneFinishedEvent<T>>| {
+                let Some(running) = cutscenes.running_as(id) else { return };
+                if running.finished {
+                    cutscenes.running = None;
+                    cutscenes.try_run_next();
+                    ev_cutscene_finished.send_default();
                 }
-            },
+            })
+            .in_set(CutsceneSystem::Finish),
         );
         self
     }
 }
 
+fn build_chain(app: &mut App, id: CutsceneId, build: impl FnOnce(&mut CutsceneBuilder)) -> usize {
+    let chain = app.world.resource_mut::<Cutscenes>().add_chain(id);
+    let mut builder = CutsceneBuilder {
+        app: &mut *app,
+        id,
+        chain,
+        steps: vec![],
+    };
+    build(&mut builder);
+    let steps = builder.steps;
+    app.world
+        .resource_mut::<Cutscenes>()
+        .set_chain_steps(id, chain, steps);
+    chain
+}
+
 pub struct CutsceneBuilder<'a> {
     app: &'a mut App,
-    type_id: TypeId,
-    step: usize,
+    id: CutsceneId,
+    chain: usize,
+    steps: Vec<bool>,
 }
 
 impl<'a> CutsceneBuilder<'a> {
+    // the update runs every frame until it calls for the next step or a continue event arrives.
+    // when skipping it runs once, so updates that keep their own time should check `skipping`
+    // and jump to their end
     pub fn add_step<ParamsA, ParamsB>(
         &mut self,
-        init: impl IntoSystemConfig<ParamsA> + IntoSystemConfig<ParamsA>,
-        update: impl IntoSystemConfig<ParamsB> + IntoSystemConfig<ParamsB>,
+        init: impl IntoSystemConfig<ParamsA>,
+        update: impl IntoSystemConfig<ParamsB>,
     ) -> &mut Self {
-        let type_id = self.type_id;
-        let step = self.step;
-        self.app
-            .add_system(init.run_if(move |cutscene_state: Res<Cutscenes>| {
-                let mut lock = cutscene_state.data.write().unwrap();
-                if let Some(running_cutscene) = &mut lock.running_cutscene {
-                    if running_cutscene.type_id == type_id
-                        && running_cutscene.init
-                        && running_cutscene.started
-                        && running_cutscene.step == step
-                    {
-                        running_cutscene.init = false;
-                        return true;
-                    }
-                }
-                false
-            }));
-        self.app
-            .add_system(update.run_if(move |cutscene_state: Res<Cutscenes>| {
-                let mut lock = cutscene_state.data.write().unwrap();
-                if let Some(running_cutscene) = &mut lock.running_cutscene {
-                    if running_cutscene.type_id == type_id
-                        && !running_cutscene.init
-                        && running_cutscene.started
-                        && running_cutscene.step == step
-                    {
-                        if running_cutscene.updates == UPDATE_BUFFER {
-                            return true;
-                        } else {
-                            running_cutscene.updates += 1;
-                        }
-                    }
-                }
-                false
-            }));
-        self.step += 1;
+        self.add_step_continued(init, update, true)
+    }
+
+    fn add_step_continued<ParamsA, ParamsB>(
+        &mut self,
+        init: impl IntoSystemConfig<ParamsA>,
+        update: impl IntoSystemConfig<ParamsB>,
+        continued: bool,
+    ) -> &mut Self {
+        let id = self.id;
+        let chain = self.chain;
+        let step = self.steps.len();
+        self.app.add_system(
+            init.run_if(move |cutscenes: Res<Cutscenes>| {
+                cutscenes.at_step(id, chain, step, CutscenePhase::Init)
+            })
+            .in_set(CutsceneSystem::Init),
+        );
+        self.app.add_system(
+            update
+                .run_if(move |cutscenes: Res<Cutscenes>| {
+                    cutscenes.at_step(id, chain, step, CutscenePhase::Update)
+                })
+                .in_set(CutsceneSystem::Update),
+        );
+        self.steps.push(continued);
         self
     }
 
     pub fn add_update_step<ParamsA>(
         &mut self,
-        update: impl IntoSystemConfig<ParamsA> + IntoSystemConfig<ParamsA>,
+        update: impl IntoSystemConfig<ParamsA>,
     ) -> &mut Self {
         self.add_step(|| {}, update)
     }
 
-    pub fn add_quick_step<ParamsA>(
+    pub fn add_quick_step<ParamsA>(&mut self, init: impl IntoSystemConfig<ParamsA>) -> &mut Self {
+        let chain = self.chain;
+        self.add_step_continued(
+            init,
+            move |mut cutscenes: ResMut<Cutscenes>| {
+                cutscenes.next_step(chain);
+            },
+            false,
+        )
+    }
+
+    pub fn add_dialogue_step<ParamsA>(
         &mut self,
-        init: impl IntoSystemConfig<ParamsA> + IntoSystemConfig<ParamsA>,
+        init: impl IntoSystemConfig<ParamsA>,
     ) -> &mut Self {
-        let step = self.step;
-        self.add_step(init, move |state: Res<Cutscenes>| {
-            let mut lock = state.data.write().unwrap();
-            Self::to_step(&mut lock, step + 1);
-        });
-        self
+        let chain = self.chain;
+        self.add_step_continued(
+            init,
+            move |mut cutscenes: ResMut<Cutscenes>, dialogue: Res<Dialogue>| {
+                if cutscenes.step_time(chain) > 0.2 && !dialogue.visible() {
+                    cutscenes.next_step(chain);
+                }
+            },
+            false,
+        )
     }
 
-    pub fn add_dialogue_step<ParamsA>(
+    pub fn add_timed_step<ParamsA>(
         &mut self,
-        init: impl IntoSystemConfig<ParamsA> + IntoSystemConfig<ParamsA>,
+        init: impl IntoSystemConfig<ParamsA>,
+        seconds: f32,
     ) -> &mut Self {
-        let step = self.step;
-        self.add_step(
+        let chain = self.chain;
+        self.add_step_continued(
             init,
-            move |state: Res<Cutscenes>, time: Res<Time>, dialogue: Res<Dialogue>| {
-                let mut lock = state.data.write().unwrap();
-                let advance = if let Some(running_cutscene) = &mut lock.running_cutscene {
-                    running_cutscene.time += time.delta_seconds();
-                    running_cutscene.time > 0.2 && !dialogue.visible()
+            move |mut cutscenes: ResMut<Cutscenes>| {
+                if cutscenes.step_time(chain) > seconds {
+                    cutscenes.next_step(chain);
+                }
+            },
+            false,
+        )
+    }
+
+    pub fn add_parallel(&mut self, build: impl FnOnce(&mut CutsceneParallelBuilder)) -> &mut Self {
+        let mut parallel = CutsceneParallelBuilder {
+            app: &mut *self.app,
+            id: self.id,
+            chains: vec![],
+        };
+        build(&mut parallel);
+        let chains = parallel.chains;
+        let parent = self.chain;
+        self.add_step_continued(
+            move |mut cutscenes: ResMut<Cutscenes>| {
+                cutscenes.start_chains(parent, &chains);
+            },
+            || {},
+            false,
+        )
+    }
+
+    pub fn add_branch<R: Resource>(
+        &mut self,
+        condition: impl Fn(&R) -> bool + Send + Sync + 'static,
+        then: impl FnOnce(&mut CutsceneBuilder),
+        otherwise: impl FnOnce(&mut CutsceneBuilder),
+    ) -> &mut Self {
+        let then = build_chain(self.app, self.id, then);
+        let otherwise = build_chain(self.app, self.id, otherwise);
+        let parent = self.chain;
+        self.add_step_continued(
+            move |mut cutscenes: ResMut<Cutscenes>, resource: Res<R>| {
+                let chain = if condition(&resource) {
+                    then
                 } else {
-                    false
+                    otherwise
                 };
-                if advance {
-                    Self::to_step(&mut lock, step + 1);
-                }
+                cutscenes.start_chains(parent, &[chain]);
             },
-        );
-        self
+            || {},
+            false,
+        )
     }
 
-    pub fn add_timed_step<ParamsA>(
+    pub fn add_tween<M: Component>(
         &mut self,
-        init: impl IntoSystemConfig<ParamsA> + IntoSystemConfig<ParamsA>,
+        tween: Tween,
+        easing: Easing,
         seconds: f32,
     ) -> &mut Self {
-        let step = self.step;
-        self.add_step(init, move |state: Res<Cutscenes>, time: Res<Time>| {
-            let mut lock = state.data.write().unwrap();
-            let advance = if let Some(running_cutscene) = &mut lock.running_cutscene {
-                running_cutscene.time += time.delta_seconds();
-                running_cutscene.time > seconds
-            } else {
-                false
-            };
-            if advance {
-                Self::to_step(&mut lock, step + 1);
-            }
-        });
+        let chain = self.chain;
+        self.add_step_continued(
+            move |mut query: Query<TweenTarget, With<M>>| {
+                for target in query.iter_mut() {
+                    tween.apply(target, ease(easing, 0.));
+                }
+            },
+            move |mut cutscenes: ResMut<Cutscenes>, mut query: Query<TweenTarget, With<M>>| {
+                let time = if seconds > 0. {
+                    (cutscenes.step_time(chain) / seconds).min(1.)
+                } else {
+                    1.
+                };
+                for target in query.iter_mut() {
+                    tween.apply(target, ease(easing, time));
+                }
+                if time == 1. {
+                    cutscenes.next_step(chain);
+                }
+            },
+            false,
+        )
+    }
+}
+
+pub struct CutsceneParallelBuilder<'a> {
+    app: &'a mut App,
+    id: CutsceneId,
+    chains: Vec<usize>,
+}
+
+impl<'a> CutsceneParallelBuilder<'a> {
+    pub fn add_chain(&mut self, build: impl FnOnce(&mut CutsceneBuilder)) -> &mut Self {
+        let chain = build_chain(self.app, self.id, build);
+        self.chains.push(chain);
         self
     }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum Tween {
+    Translation(Vec2, Vec2),
+    Rotation(f32, f32),
+    Scale(Vec2, Vec2),
+    // alpha of a sprite or a texture atlas sprite
+    Opacity(f32, f32),
+}
+
+type TweenTarget = (
+    Option<&'static mut Transform2>,
+    Option<&'static mut Sprite>,
+    Option<&'static mut TextureAtlasSprite>,
+);
 
-    fn to_step(lock: &mut RwLockWriteGuard<CutscenesData>, step: usize) {
-        if let Some(running_cutscene) = &mut lock.running_cutscene {
-            running_cutscene.reset();
-            running_cutscene.step = step;
+impl Tween {
+    fn apply(&self, (transform, sprite, atlas_sprite): QueryItem<'_, TweenTarget>, time: f32) {
+        match *self {
+            Tween::Translation(from, to) => {
+                if let Some(mut transform) = transform {
+                    transform.translation = from.lerp(to, time);
+                }
+            }
+            Tween::Rotation(from, to) => {
+                if let Some(mut transform) = transform {
+                    transform.rotation = from + (to - from) * time;
+                }
+            }
+            Tween::Scale(from, to) => {
+                if let Some(mut transform) = transform {
+                    transform.scale = from.lerp(to, time);
+                }
+            }
+            Tween::Opacity(from, to) => {
+                let opacity = from + (to - from) * time;
+                if let Some(mut sprite) = sprite {
+                    sprite.color.set_a(opacity);
+                }
+                if let Some(mut atlas_sprite) = atlas_sprite {
+                    atlas_sprite.color.set_a(opacity);
+                }
+            }
         }
     }
 }
@@ -315,81 +586,77 @@ where
     _phantom: PhantomData<T>,
 }
 
-#[derive(Default, Resource)]
-struct CutsceneInitialValues<T>(VecDeque<T>)
+#[derive(Default, Clone, Copy)]
+pub struct CutsceneFinishedEvent<T>
 where
-    T: CutsceneType;
-
-fn cutscene_start<T>(
-    mut ev_cutscene_start: EventReader<CutsceneStartEvent<T>>,
-    mut cutscene_state: ResMut<Cutscenes>,
-    mut initial_values: ResMut<CutsceneInitialValues<T>>,
-) where
     T: CutsceneType,
 {
-    for event in ev_cutscene_start.iter() {
-        initial_values.0.push_back(event.0.clone());
-        cutscene_state.queue(String::from(type_name::<T>()), TypeId::of::<T>());
-        cutscene_state.try_run_next();
-    }
+    _phantom: PhantomData<T>,
 }
 
-fn cutscene_continue<T>(
-    mut ev_cutscene_continue: EventReader<CutsceneContinueEvent<T>>,
-    cutscene_state: Res<Cutscenes>,
-) where
-    T: CutsceneType,
-{
-    let mut lock = cutscene_state.data.write().unwrap();
-    let mut continued = false;
-    for _ in ev_cutscene_continue.iter() {
-        if !continued {
-            continued = true;
-            if let Some(running_cutscene) = &mut lock.running_cutscene {
-                if running_cutscene.type_id == TypeId::of::<T>()
-                    && running_cutscene.updates == UPDATE_BUFFER
-                {
-                    running_cutscene.reset();
-                    running_cutscene.step += 1;
+fn cutscene_advance(mut cutscenes: ResMut<Cutscenes>, time: Res<Time>) {
+    let Cutscenes {
+        registered,
+        running,
+        ..
+    } = &mut *cutscenes;
+    let Some(running) = running else { return };
+    let chains = &registered[running.id.0].chains;
+    let playing: Vec<usize> = running.tracks.iter().map(|track| track.chain).collect();
+    for track in running.tracks.iter_mut() {
+        track.waiting.retain(|chain| playing.contains(chain));
+        match track.phase {
+            CutscenePhase::Begin => {
+                track.phase = CutscenePhase::Init;
+            }
+            CutscenePhase::Init => {
+                track.phase = CutscenePhase::Update;
+            }
+            CutscenePhase::Update => {
+                // when skipping, every update runs once and then moves on
+                if (track.advance || running.skip) && track.waiting.is_empty() {
+                    track.next();
+                } else {
+                    track.time += time.delta_seconds();
                 }
             }
         }
     }
-}
-
-fn cutscene_skip<T>(
-    mut ev_cutscene_skip: EventReader<CutsceneSkipEvent<T>>,
-    cutscene_state: Res<Cutscenes>,
-) where
-    T: CutsceneType,
-{
-    let mut lock = cutscene_state.data.write().unwrap();
-    for _ in ev_cutscene_skip.iter() {
-        if let Some(running_cutscene) = &mut lock.running_cutscene {
-            if running_cutscene.type_id == TypeId::of::<T>() {
-                running_cutscene.skip = true;
-            }
-        }
+    running
+        .tracks
+        .retain(|track| track.step < chains[track.chain].len());
+    if running.state.is_none() && running.tracks.is_empty() {
+        running.finished = true;
     }
 }
 
 fn cutscene_debug(
     mut egui_query: Query<&mut EguiContext>,
     mut menu_bar: ResMut<MenuBar>,
-    cutscene_state: Res<Cutscenes>,
+    mut cutscenes: ResMut<Cutscenes>,
 ) {
     menu_bar.item("Cutscenes", |open| {
         let Some(mut egui_context) = egui_query.get_single_mut().ok() else { return };
         egui::Window::new("Cutscenes")
             .open(open)
             .show(egui_context.get_mut(), |ui| {
-                let mut lock = cutscene_state.data.write().unwrap();
-                if let Some(cutscene) = &mut lock.running_cutscene {
-                    ui.label(format!("Cutscene running: {}", cutscene.name));
-                    ui.label(format!("Step: {}", cutscene.step));
+                let Cutscenes {
+                    registered,
+                    running,
+                    ..
+                } = &mut *cutscenes;
+                if let Some(cutscene) = running {
+                    ui.label(format!(
+                        "Cutscene running: {}",
+                        registered[cutscene.id.0].name
+                    ));
+                    for track in cutscene.tracks.iter() {
+                        ui.label(format!("Chain {} step: {}", track.chain, track.step));
+                    }
                     if ui.button("Next").clicked() {
-                        cutscene.reset();
-                        cutscene.step += 1;
+                        for track in cutscene.tracks.iter_mut() {
+                            track.advance = true;
+                        }
                     }
                     if ui.button("Skip All").clicked() {
                         cutscene.skip = true;
@@ -400,3 +667,330 @@ fn cutscene_debug(
             });
     });
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{Duration, Instant};
+
+    const FRAME: f32 = 1. / 60.;
+
+    #[derive(Default, Resource)]
+    struct Log(Vec<&'static str>);
+
+    #[derive(Default, Resource)]
+    struct Flag(bool);
+
+    #[derive(Component)]
+    struct Marker;
+
+    fn log(entry: &'static str) -> impl FnMut(ResMut<Log>) + Send + Sync + 'static {
+        move |mut log: ResMut<Log>| log.0.push(entry)
+    }
+
+    // the cutscene runtime without the debug window, only moving time when told to
+    struct TestApp {
+        app: App,
+        now: Instant,
+    }
+
+    impl TestApp {
+        fn new() -> Self {
+            let mut app = App::new();
+            let now = Instant::now();
+            let mut time = Time::default();
+            time.update_with_instant(now);
+            app.insert_resource(time)
+                .init_resource::<Cutscenes>()
+                .init_resource::<Log>()
+                .init_resource::<Flag>()
+                .configure_sets(
+                    (
+                        CutsceneSystem::Queue,
+                        CutsceneSystem::Begin,
+                        CutsceneSystem::Init,
+                        CutsceneSystem::Update,
+                        CutsceneSystem::Advance,
+                        CutsceneSystem::Finish,
+                    )
+                        .chain(),
+                )
+                .add_system(cutscene_advance.in_set(CutsceneSystem::Advance));
+            Self { app, now }
+        }
+
+        fn start<T: CutsceneType + Resource>(&mut self) {
+            self.app
+                .world
+                .resource_mut::<Events<CutsceneStartEvent<T>>>()
+                .send(CutsceneStartEvent(T::default()));
+        }
+
+        fn continue_cutscene<T: CutsceneType + Resource>(&mut self) {
+            self.app
+                .world
+                .resource_mut::<Events<CutsceneContinueEvent<T>>>()
+                .send_default();
+        }
+
+        fn skip<T: CutsceneType + Resource>(&mut self) {
+            self.app
+                .world
+                .resource_mut::<Events<CutsceneSkipEvent<T>>>()
+                .send_default();
+        }
+
+        fn run(&mut self, seconds: f32) {
+            let mut elapsed = 0.;
+            while elapsed < seconds {
+                self.now += Duration::from_secs_f32(FRAME);
+                self.app
+                    .world
+                    .resource_mut::<Time>()
+                    .update_with_instant(self.now);
+                self.app.update();
+                elapsed += FRAME;
+            }
+        }
+
+        fn log(&self) -> &[&'static str] {
+            &self.app.world.resource::<Log>().0
+        }
+
+        fn running(&self) -> bool {
+            self.app.world.resource::<Cutscenes>().running()
+        }
+    }
+
+    #[derive(Default, Clone, Resource)]
+    struct StepsCutscene;
+
+    impl Cutscene for StepsCutscene {
+        fn build(cutscene: &mut CutsceneBuilder) {
+            cutscene.add_quick_step(log("first"));
+            cutscene.add_timed_step(log("second"), 0.2);
+            cutscene.add_step(log("third"), || {});
+            cutscene.add_quick_step(log("fourth"));
+        }
+    }
+
+    #[derive(Default, Clone, Resource)]
+    struct SkipCutscene;
+
+    impl Cutscene for SkipCutscene {
+        fn build(cutscene: &mut CutsceneBuilder) {
+            cutscene.add_timed_step(log("first"), 10.);
+            cutscene.add_tween::<Marker>(
+                Tween::Translation(Vec2::ZERO, Vec2::new(10., 0.)),
+                Easing::Linear,
+                10.,
+            );
+            cutscene.add_step(log("second"), || {});
+        }
+    }
+
+    #[derive(Default, Clone, Resource)]
+    struct ParallelCutscene;
+
+    impl Cutscene for ParallelCutscene {
+        fn build(cutscene: &mut CutsceneBuilder) {
+            cutscene.add_parallel(|parallel| {
+                parallel.add_chain(|chain| {
+                    chain.add_timed_step(log("short"), 0.1);
+                });
+                parallel.add_chain(|chain| {
+                    chain.add_timed_step(log("long"), 0.5);
+                });
+            });
+            cutscene.add_quick_step(log("after"));
+        }
+    }
+
+    #[derive(Default, Clone, Resource)]
+    struct BranchCutscene;
+
+    impl Cutscene for BranchCutscene {
+        fn build(cutscene: &mut CutsceneBuilder) {
+            cutscene.add_branch::<Flag>(
+                |flag| flag.0,
+                |chain| {
+                    chain.add_quick_step(log("then"));
+                },
+                |_| {},
+            );
+            cutscene.add_quick_step(log("after"));
+        }
+    }
+
+    #[derive(Default, Clone, Resource)]
+    struct TweenCutscene;
+
+    impl Cutscene for TweenCutscene {
+        fn build(cutscene: &mut CutsceneBuilder) {
+            cutscene.add_parallel(|parallel| {
+                parallel.add_chain(|chain| {
+                    chain.add_tween::<Marker>(
+                        Tween::Translation(Vec2::ZERO, Vec2::new(10., 0.)),
+                        Easing::Linear,
+                        0.5,
+                    );
+                });
+                parallel.add_chain(|chain| {
+                    chain.add_tween::<Marker>(Tween::Opacity(1., 0.), Easing::Linear, 0.5);
+                });
+            });
+            cutscene.add_quick_step(log("after"));
+        }
+    }
+
+    #[test]
+    fn parallel_waits_for_every_chain() {
+        let mut app = TestApp::new();
+        app.app.add_cutscene::<ParallelCutscene>();
+        app.start::<ParallelCutscene>();
+        app.run(0.3);
+        assert_eq!(app.log(), ["short", "long"]);
+        assert!(app.running());
+        app.run(0.5);
+        assert_eq!(app.log(), ["short", "long", "after"]);
+        assert!(!app.running());
+    }
+
+    #[test]
+    fn branch_runs_the_chain_picked_by_the_condition() {
+        let mut app = TestApp::new();
+        app.app.add_cutscene::<BranchCutscene>();
+        app.app.world.resource_mut::<Flag>().0 = true;
+        app.start::<BranchCutscene>();
+        app.run(0.2);
+        assert_eq!(app.log(), ["then", "after"]);
+        assert!(!app.running());
+    }
+
+    #[test]
+    fn branch_to_an_empty_chain_moves_straight_on() {
+        let mut app = TestApp::new();
+        app.app.add_cutscene::<BranchCutscene>();
+        app.start::<BranchCutscene>();
+        app.run(0.2);
+        assert_eq!(app.log(), ["after"]);
+        assert!(!app.running());
+    }
+
+    #[test]
+    fn tween_completes_at_its_end_value() {
+        let mut app = TestApp::new();
+        app.app.add_cutscene::<TweenCutscene>();
+        let entity = app
+            .app
+            .world
+            .spawn((Transform2::new(), Sprite::default(), Marker))
+            .id();
+        app.start::<TweenCutscene>();
+        app.run(0.25);
+        let translation = app.app.world.get::<Transform2>(entity).unwrap().translation;
+        assert!(translation.x > 0. && translation.x < 10.);
+        assert!(app.log().is_empty());
+        app.run(0.5);
+        assert_eq!(
+            app.app.world.get::<Transform2>(entity).unwrap().translation,
+            Vec2::new(10., 0.)
+        );
+        assert_eq!(app.app.world.get::<Sprite>(entity).unwrap().color.a(), 0.);
+        assert_eq!(app.log(), ["after"]);
+        assert!(!app.running());
+    }
+
+    #[test]
+    fn skipping_a_tween_part_way_finishes_it() {
+        let mut app = TestApp::new();
+        app.app.add_cutscene::<TweenCutscene>();
+        let entity = app
+            .app
+            .world
+            .spawn((Transform2::new(), Sprite::default(), Marker))
+            .id();
+        app.start::<TweenCutscene>();
+        app.run(0.25);
+        app.skip::<TweenCutscene>();
+        app.run(0.2);
+        assert_eq!(
+            app.app.world.get::<Transform2>(entity).unwrap().translation,
+            Vec2::new(10., 0.)
+        );
+        assert_eq!(app.app.world.get::<Sprite>(entity).unwrap().color.a(), 0.);
+        assert_eq!(app.log(), ["after"]);
+        assert!(!app.running());
+    }
+
+    #[test]
+    fn continue_leaves_tracks_that_advance_themselves_alone() {
+        let mut app = TestApp::new();
+        app.app.add_cutscene::<TweenCutscene>();
+        let entity = app.app.world.spawn((Transform2::new(), Marker)).id();
+        app.start::<TweenCutscene>();
+        app.run(0.1);
+        app.continue_cutscene::<TweenCutscene>();
+        app.run(0.1);
+        let translation = app.app.world.get::<Transform2>(entity).unwrap().translation;
+        assert!(translation.x > 0. && translation.x < 10.);
+        assert!(app.log().is_empty());
+        assert!(app.running());
+    }
+
+    #[test]
+    fn steps_advance_in_order() {
+        let mut app = TestApp::new();
+        app.app.add_cutscene::<StepsCutscene>();
+        app.start::<StepsCutscene>();
+        app.run(0.1);
+        assert_eq!(app.log(), ["first", "second"]);
+        app.run(0.3);
+        assert_eq!(app.log(), ["first", "second", "third"]);
+        // a step without an update that advances waits for a continue event
+        app.run(1.);
+        assert_eq!(app.log(), ["first", "second", "third"]);
+        app.continue_cutscene::<StepsCutscene>();
+        app.run(0.1);
+        assert_eq!(app.log(), ["first", "second", "third", "fourth"]);
+        assert!(!app.running());
+    }
+
+    #[test]
+    fn skip_runs_every_remaining_step_once() {
+        let mut app = TestApp::new();
+        app.app.add_cutscene::<SkipCutscene>();
+        let entity = app.app.world.spawn((Transform2::new(), Marker)).id();
+        app.start::<SkipCutscene>();
+        app.run(0.1);
+        assert_eq!(app.log(), ["first"]);
+        app.skip::<SkipCutscene>();
+        app.run(0.2);
+        assert_eq!(app.log(), ["first", "second"]);
+        assert_eq!(
+            app.app.world.get::<Transform2>(entity).unwrap().translation,
+            Vec2::new(10., 0.)
+        );
+        assert!(!app.running());
+    }
+
+    #[test]
+    fn finishing_sends_an_event_and_starts_the_next_queued_cutscene() {
+        let mut app = TestApp::new();
+        // read before the next cutscene's steps run so the log order is fixed
+        app.app.add_cutscene::<BranchCutscene>().add_system(
+            (|mut ev_finished: EventReader<CutsceneFinishedEvent<BranchCutscene>>,
+              mut log: ResMut<Log>| {
+                for _ in ev_finished.iter() {
+                    log.0.push("finished");
+                }
+            })
+            .in_set(CutsceneSystem::Queue),
+        );
+        app.start::<BranchCutscene>();
+        app.start::<BranchCutscene>();
+        app.run(0.3);
+        assert_eq!(app.log(), ["after", "finished", "after", "finished"]);
+        assert!(!app.running());
+    }
+}
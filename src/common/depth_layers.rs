@@ -21,9 +21,11 @@ pub const DEPTH_LAYER_DIALOGUE_FADE: Layer = (DepthLayer::Front, 0.94);
 pub const DEPTH_LAYER_DIALOGUE_BACK: Layer = (DepthLayer::Front, 0.95);
 pub const DEPTH_LAYER_DIALOGUE_TEXT: Layer = (DepthLayer::Front, 0.96);
 pub const DEPTH_LAYER_DIALOGUE_PORTRAIT: Layer = (DepthLayer::Front, 0.94);
+pub const DEPTH_LAYER_DIALOGUE_CHOICE_TEXT: Layer = (DepthLayer::Front, 0.961);
 
 pub const DEPTH_LAYER_HEALTHBAR_BORDER: Layer = (DepthLayer::Front, 0.1);
 pub const DEPTH_LAYER_HEALTHBAR: Layer = (DepthLayer::Front, 0.11);
+pub const DEPTH_LAYER_DAMAGE_NUMBER: Layer = (DepthLayer::Front, 0.12);
 
 pub const DEPTH_LAYER_TOWN_NAME: Layer = (DepthLayer::Front, 0.);
 
@@ -35,6 +37,15 @@ pub const DEPTH_LAYER_MAP_OBJECTIVE: Layer = (DepthLayer::Front, 0.953);
 pub const DEPTH_LAYER_MAP_LABEL: Layer = (DepthLayer::Front, 0.955);
 pub const DEPTH_LAYER_MAP_COMPASS: Layer = (DepthLayer::Front, 0.956);
 
+pub const DEPTH_LAYER_QUEST_LOG_BACK: Layer = (DepthLayer::Front, 0.95);
+pub const DEPTH_LAYER_QUEST_LOG_TEXT: Layer = (DepthLayer::Front, 0.951);
+
+pub const DEPTH_LAYER_DIALOGUE_LOG_BACK: Layer = (DepthLayer::Front, 0.95);
+pub const DEPTH_LAYER_DIALOGUE_LOG_TEXT: Layer = (DepthLayer::Front, 0.951);
+
+pub const DEPTH_LAYER_PAUSE_BACK: Layer = (DepthLayer::Front, 0.95);
+pub const DEPTH_LAYER_PAUSE_PANEL: Layer = (DepthLayer::Front, 0.951);
+
 pub const DEPTH_LAYER_CHECKPOINT_BACKGROUND: Layer = (DepthLayer::Front, 0.96);
 pub const DEPTH_LAYER_CHECKPOINT_TEXT: Layer = (DepthLayer::Front, 0.961);
 pub const DEPTH_LAYER_LEVEL_UP_BACKGROUND: Layer = (DepthLayer::Front, 0.97);
@@ -52,6 +63,7 @@ pub const DEPTH_LAYER_UI_BOSS_HEALTHBAR: Layer = (DepthLayer::Front, 0.861);
 pub const DEPTH_LAYER_UI_HEALTH_BOTTLE: Layer = (DepthLayer::Front, 0.905);
 pub const DEPTH_LAYER_UI_CONTROLS: Layer = (DepthLayer::Front, 0.905);
 pub const DEPTH_LAYER_UI_CONTROLS_KEY: Layer = (DepthLayer::Front, 0.906);
+pub const DEPTH_LAYER_UI_CONTROLS_BINDING: Layer = (DepthLayer::Front, 0.907);
 pub const DEPTH_LAYER_UI_EXPERIENCE_BAR_BACK: Layer = (DepthLayer::Front, 0.905);
 pub const DEPTH_LAYER_UI_EXPERIENCE_BAR: Layer = (DepthLayer::Front, 0.906);
 pub const DEPTH_LAYER_UI_EXPERIENCE_LEVEL: Layer = (DepthLayer::Front, 0.907);
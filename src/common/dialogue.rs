@@ -1,6 +1,7 @@
-use crate::common::prelude::*;
+use crate::common::{assets::dialogue::DialogueScript, prelude::*};
 use audio_plus::prelude::*;
-use bevy::{prelude::*, sprite::Anchor};
+use bevy::{prelude::*, sprite::Anchor, text::Text2dBounds};
+use serde::{Deserialize, Serialize};
 use std::collections::VecDeque;
 
 pub struct DialoguePlugin;
@@ -9,28 +10,106 @@ impl Plugin for DialoguePlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<Dialogue>()
             .add_event::<DialogueInitEvent>()
+            .add_event::<DialogueChoiceEvent>()
+            .add_event::<DialogueLogEvent>()
             .add_system(dialogue_init)
-            .add_system(dialogue_update);
+            .add_system(dialogue_scripts_update)
+            .add_system(dialogue_update)
+            .add_system(dialogue_choices.after(dialogue_update));
     }
 }
 
 #[derive(Default, Clone, Copy)]
 pub struct DialogueInitEvent;
 
+#[derive(Clone)]
+pub struct DialogueChoiceEvent {
+    pub id: String,
+}
+
+// sent once for every entry as it's shown
+#[derive(Clone)]
+pub struct DialogueLogEvent {
+    pub entry: DialogueHistoryEntry,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DialogueHistoryEntry {
+    pub name: String,
+    pub text: String,
+}
+
+const DIALOGUE_CHOICES_MAX: usize = 4;
+const DIALOGUE_TEXT_WIDTH: f32 = 1080.;
+const DIALOGUE_CHOICE_KEYS: [KeyCode; DIALOGUE_CHOICES_MAX] =
+    [KeyCode::Key1, KeyCode::Key2, KeyCode::Key3, KeyCode::Key4];
+
 #[derive(Default, Resource)]
 pub struct Dialogue {
     entries: VecDeque<DialogueEntry>,
+    scripts: Vec<DialogueScript>,
     time: f32,
     last_characters: usize,
+    bark_letters: usize,
+    logged: bool,
 }
 
 #[derive(Clone)]
 struct DialogueEntry {
     portrait: DialoguePortrait,
-    text: String,
+    text: RichText,
+    choices: Vec<DialogueChoice>,
+    script: Option<String>,
+    // locale string id, see `dialogue_line_key`
+    key: Option<String>,
+    // the script's own text, the english source and the fallback for missing translations
+    source: String,
+}
+
+pub fn dialogue_line_key(script: &str, line: &str) -> String {
+    format!("dialogue.{}.{}", script, line)
+}
+
+pub fn dialogue_choice_key(script: &str, choice: &str) -> String {
+    format!("dialogue.{}.choice.{}", script, choice)
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct DialogueChoice {
+    pub id: String,
+    pub text: String,
+    #[serde(default)]
+    pub script: Option<String>,
+    #[serde(default)]
+    pub event: Option<String>,
 }
 
-#[derive(Clone, Copy, PartialEq, Eq)]
+impl DialogueChoice {
+    pub fn new(id: &str, text: &str) -> Self {
+        Self {
+            id: String::from(id),
+            text: String::from(text),
+            script: None,
+            event: None,
+        }
+    }
+
+    pub fn with_script(self, script: &str) -> Self {
+        Self {
+            script: Some(String::from(script)),
+            ..self
+        }
+    }
+
+    pub fn with_event(self, event: &str) -> Self {
+        Self {
+            event: Some(String::from(event)),
+            ..self
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
 pub enum DialoguePortrait {
     None,
     Jagerossa,
@@ -41,39 +120,194 @@ pub enum DialoguePortrait {
     Barkeep,
 }
 
+// how a portrait talks: letters between barks, letters per second and how long punctuation holds
+#[derive(Clone, Copy)]
+struct DialogueVoice {
+    cadence: usize,
+    speed: f32,
+    pause: f32,
+}
+
+impl DialogueVoice {
+    fn pause(&self, character: char) -> f32 {
+        match character {
+            '.' | '!' | '?' => 0.3 * self.pause,
+            ',' | ';' | ':' => 0.12 * self.pause,
+            _ => 0.,
+        }
+    }
+}
+
 impl DialoguePortrait {
+    fn voice(&self) -> DialogueVoice {
+        let (cadence, speed, pause) = match *self {
+            Self::None => (8, 50., 1.),
+            Self::Jagerossa => (5, 48., 1.2),
+            Self::Ringo => (3, 60., 0.6),
+            Self::Plank => (7, 38., 1.5),
+            Self::Davy => (5, 52., 1.),
+            Self::Mayor => (9, 42., 1.3),
+            Self::Barkeep => (6, 46., 0.8),
+        };
+        DialogueVoice {
+            cadence,
+            speed,
+            pause,
+        }
+    }
+
     fn name(&self) -> &'static str {
         match *self {
-            Self::None => "???",
-            Self::Jagerossa => "Captain Mick Jagerossa",
-            Self::Ringo => "Captain Ringo Yarr",
-            Self::Plank => "Captain Plank Presley",
-            Self::Davy => "Captain Davy Bowie",
-            Self::Mayor => "Governor",
-            Self::Barkeep => "Barkeep",
+            Self::None => "portrait.none",
+            Self::Jagerossa => "portrait.jagerossa",
+            Self::Ringo => "portrait.ringo",
+            Self::Plank => "portrait.plank",
+            Self::Davy => "portrait.davy",
+            Self::Mayor => "portrait.mayor",
+            Self::Barkeep => "portrait.barkeep",
         }
     }
 }
 
 impl Dialogue {
     pub fn add_text(&mut self, portrait: DialoguePortrait, text: String) {
-        self.entries.push_back(DialogueEntry { portrait, text });
+        self.add_choices(portrait, text, vec![]);
+    }
+
+    pub fn add_choices(
+        &mut self,
+        portrait: DialoguePortrait,
+        text: String,
+        choices: Vec<DialogueChoice>,
+    ) {
+        self.entries.push_back(DialogueEntry {
+            portrait,
+            text: RichText::parse(&text),
+            choices,
+            script: None,
+            key: None,
+            source: text,
+        });
+    }
+
+    pub fn add_script(&mut self, id: &str) {
+        self.entries.extend(self.script_entries(id));
+    }
+
+    // continues from a choice, ahead of anything that was already queued
+    pub fn continue_script(&mut self, id: &str) {
+        for entry in self.script_entries(id).into_iter().rev() {
+            self.entries.push_front(entry);
+        }
+    }
+
+    fn script_entries(&self, id: &str) -> Vec<DialogueEntry> {
+        let Some(script) = self.scripts.iter().find(|script| script.id == id) else {
+            warn!("dialogue script not found: {}", id);
+            return vec![];
+        };
+        let mut entries: Vec<DialogueEntry> = script
+            .lines
+            .iter()
+            .map(|line| DialogueEntry {
+                portrait: line.portrait,
+                text: RichText::parse(&line.text),
+                choices: vec![],
+                script: Some(script.id.clone()),
+                key: Some(dialogue_line_key(&script.id, &line.id)),
+                source: line.text.clone(),
+            })
+            .collect();
+        if let Some(last) = entries.last_mut() {
+            last.choices = script.choices.clone();
+        }
+        entries
+    }
+
+    pub fn choices_visible(&self) -> bool {
+        self.all_characters_visible() && !self.entries[0].choices.is_empty()
+    }
+
+    fn choose(&mut self, index: usize) -> Option<DialogueChoice> {
+        if !self.choices_visible() {
+            return None;
+        }
+        let choice = self.entries[0].choices.get(index)?.clone();
+        self.entries.pop_front();
+        self.restart();
+        if let Some(script) = &choice.script {
+            self.continue_script(script);
+        }
+        Some(choice)
+    }
+
+    pub fn script_ids(&self) -> impl Iterator<Item = &str> {
+        self.scripts.iter().map(|script| script.id.as_str())
     }
 
     pub fn clear(&mut self) {
         self.entries = VecDeque::new();
+        self.restart();
+    }
+
+    fn restart(&mut self) {
+        self.time = 0.;
+        self.last_characters = 0;
+        self.bark_letters = 0;
+        self.logged = false;
+    }
+
+    // swaps in the current language's text, the reveal carries on from the same point
+    fn translate(&mut self, locale: &Locale) {
+        let Some(entry) = self.entries.get_mut(0) else { return };
+        let Some(key) = &entry.key else { return };
+        entry.text = RichText::parse(locale.try_translate(key).unwrap_or(&entry.source));
+        self.last_characters = self.characters();
     }
 
     pub fn visible(&self) -> bool {
         self.entries.len() > 0
     }
 
+    // byte length of the revealed text, punctuation and pause tags hold the reveal for a moment
     pub fn characters(&self) -> usize {
-        if self.entries.len() > 0 {
-            ((self.time * 50.) as usize).clamp(0, self.entries[0].text.len())
-        } else {
-            0
+        let Some(entry) = self.entries.get(0) else { return 0 };
+        let voice = entry.portrait.voice();
+        let mut elapsed = 0.;
+        for segment in entry.text.segments() {
+            elapsed += segment.pause;
+            let text = &entry.text.as_str()[segment.range.clone()];
+            for (index, character) in text.char_indices() {
+                elapsed += 1. / (voice.speed * segment.style.speed);
+                if elapsed > self.time {
+                    return segment.range.start + index;
+                }
+                elapsed += voice.pause(character);
+            }
+        }
+        entry.text.len()
+    }
+
+    // barks on the first letter of a phrase and then every few letters of the voice's cadence
+    fn bark(&mut self, characters: usize) -> bool {
+        let Some(entry) = self.entries.get(0) else { return false };
+        if characters <= self.last_characters {
+            return false;
         }
+        let voice = entry.portrait.voice();
+        let mut bark = false;
+        for character in entry.text.as_str()[self.last_characters..characters].chars() {
+            if character.is_alphanumeric() {
+                if self.bark_letters == 0 {
+                    bark = true;
+                }
+                self.bark_letters = (self.bark_letters + 1) % voice.cadence;
+            } else if voice.pause(character) > 0. {
+                self.bark_letters = 0;
+            }
+        }
+        self.last_characters = characters;
+        bark
     }
 
     pub fn all_characters_visible(&self) -> bool {
@@ -102,6 +336,22 @@ pub struct DialoguePortraitComp {
     portrait: DialoguePortrait,
 }
 
+#[derive(Component)]
+pub struct DialogueChoiceButton {
+    index: usize,
+}
+
+#[derive(Component)]
+pub struct DialogueChoiceText;
+
+fn dialogue_text_style(asset_library: &AssetLibrary) -> TextStyle {
+    TextStyle {
+        font: asset_library.font_default.clone(),
+        font_size: 48.0,
+        color: Color::rgb_u8(66, 53, 24),
+    }
+}
+
 fn dialogue_init(
     mut ev_dialogue_init: EventReader<DialogueInitEvent>,
     mut commands: Commands,
@@ -149,20 +399,17 @@ fn dialogue_init(
                 ));
                 parent.spawn((
                     Text2dBundle {
-                        text: Text::from_section(
-                            "",
-                            TextStyle {
-                                font: asset_library.font_default.clone(),
-                                font_size: 48.0,
-                                color: Color::rgb_u8(66, 53, 24),
-                            },
-                        )
-                        .with_alignment(TextAlignment::Left),
+                        text: Text::from_section("", dialogue_text_style(&asset_library))
+                            .with_alignment(TextAlignment::Left),
                         text_anchor: Anchor::TopRight,
+                        text_2d_bounds: Text2dBounds {
+                            size: Vec2::new(DIALOGUE_TEXT_WIDTH, f32::INFINITY),
+                        },
                         ..Default::default()
                     },
                     Transform2::from_xy(-540., 60.).with_depth(DEPTH_LAYER_DIALOGUE_TEXT),
                     DialogueText,
+                    RichTextEffects::default(),
                     AudioPlusSource::new(asset_library.sound_effects.sfx_dialogue_start.clone()),
                 ));
                 parent.spawn((
@@ -193,6 +440,12 @@ fn dialogue_init(
                     DialoguePortraitComp {
                         portrait: DialoguePortrait::Jagerossa,
                     },
+                    AudioPlusSource::new(
+                        asset_library
+                            .sound_effects
+                            .sfx_dialogue_voice_jagerossa
+                            .clone(),
+                    ),
                 ));
                 parent.spawn((
                     SpriteBundle {
@@ -204,6 +457,9 @@ fn dialogue_init(
                     DialoguePortraitComp {
                         portrait: DialoguePortrait::Plank,
                     },
+                    AudioPlusSource::new(
+                        asset_library.sound_effects.sfx_dialogue_voice_plank.clone(),
+                    ),
                 ));
                 parent.spawn((
                     SpriteBundle {
@@ -215,6 +471,9 @@ fn dialogue_init(
                     DialoguePortraitComp {
                         portrait: DialoguePortrait::Davy,
                     },
+                    AudioPlusSource::new(
+                        asset_library.sound_effects.sfx_dialogue_voice_davy.clone(),
+                    ),
                 ));
                 parent.spawn((
                     SpriteBundle {
@@ -226,6 +485,9 @@ fn dialogue_init(
                     DialoguePortraitComp {
                         portrait: DialoguePortrait::Ringo,
                     },
+                    AudioPlusSource::new(
+                        asset_library.sound_effects.sfx_dialogue_voice_ringo.clone(),
+                    ),
                 ));
                 parent.spawn((
                     SpriteBundle {
@@ -237,6 +499,12 @@ fn dialogue_init(
                     DialoguePortraitComp {
                         portrait: DialoguePortrait::Barkeep,
                     },
+                    AudioPlusSource::new(
+                        asset_library
+                            .sound_effects
+                            .sfx_dialogue_voice_barkeep
+                            .clone(),
+                    ),
                 ));
                 parent.spawn((
                     SpriteBundle {
@@ -248,36 +516,115 @@ fn dialogue_init(
                     DialoguePortraitComp {
                         portrait: DialoguePortrait::Mayor,
                     },
+                    AudioPlusSource::new(
+                        asset_library
+                            .sound_effects
+                            .sfx_dialogue_voice_governor
+                            .clone(),
+                    ),
                 ));
+                for index in 0..DIALOGUE_CHOICES_MAX {
+                    parent
+                        .spawn((
+                            SpriteBundle {
+                                sprite: Sprite {
+                                    color: Color::rgba(0., 0., 0., 0.6),
+                                    custom_size: Some(Vec2::new(600., 44.)),
+                                    ..Default::default()
+                                },
+                                visibility: Visibility::Hidden,
+                                ..Default::default()
+                            },
+                            Transform2::from_xy(
+                                -240.,
+                                150. + (DIALOGUE_CHOICES_MAX - 1 - index) as f32 * 52.,
+                            )
+                            .with_depth(DEPTH_LAYER_DIALOGUE_TEXT),
+                            DialogueChoiceButton { index },
+                            Clickable {
+                                shape: CollisionShape::Rect {
+                                    size: Vec2::new(600., 44.),
+                                },
+                                use_global: true,
+                                ..Default::default()
+                            },
+                        ))
+                        .with_children(|parent| {
+                            parent.spawn((
+                                Text2dBundle {
+                                    text: Text::from_section(
+                                        "",
+                                        TextStyle {
+                                            font: asset_library.font_default.clone(),
+                                            font_size: 32.0,
+                                            color: Color::WHITE,
+                                        },
+                                    )
+                                    .with_alignment(TextAlignment::Left),
+                                    text_anchor: Anchor::CenterRight,
+                                    ..Default::default()
+                                },
+                                Transform2::from_xy(-285., 0.)
+                                    .with_depth(DEPTH_LAYER_DIALOGUE_CHOICE_TEXT),
+                                DialogueChoiceText,
+                            ));
+                        });
+                }
             });
     }
 }
 
+fn dialogue_scripts_update(
+    mut ev_asset: EventReader<AssetEvent<DialogueAsset>>,
+    mut dialogue: ResMut<Dialogue>,
+    dialogue_assets: Res<Assets<DialogueAsset>>,
+    asset_library: Res<AssetLibrary>,
+) {
+    for event in ev_asset.iter() {
+        match event {
+            AssetEvent::Created { handle } | AssetEvent::Modified { handle } => {
+                if *handle == asset_library.dialogue_scripts {
+                    if let Some(asset) = dialogue_assets.get(handle) {
+                        dialogue.scripts = asset.scripts.clone();
+                    }
+                }
+            }
+            AssetEvent::Removed { .. } => {}
+        }
+    }
+}
+
 fn dialogue_update(
     mut dialogue: ResMut<Dialogue>,
     mut queries: ParamSet<(
         Query<&mut Visibility, With<DialogueBack>>,
-        Query<&mut Text, With<DialogueText>>,
+        Query<(&mut Text, &mut RichTextEffects), With<DialogueText>>,
         Query<&mut Text, With<DialogueName>>,
         Query<(&mut Visibility, &DialoguePortraitComp)>,
         Query<&mut AudioPlusSource, With<DialogueBack>>,
-        Query<&mut AudioPlusSource, With<DialogueName>>,
+        Query<
+            (&mut AudioPlusSource, Option<&DialoguePortraitComp>),
+            Or<(With<DialogueName>, With<DialoguePortraitComp>)>,
+        >,
         Query<&mut AudioPlusSource, With<DialogueText>>,
         Query<&mut Sprite, With<DialogueFade>>,
     )>,
     screen_fade: Res<ScreenFade>,
     mut input: ResMut<Input<KeyCode>>,
     mut mouse: ResMut<Input<MouseButton>>,
+    mut ev_log: EventWriter<DialogueLogEvent>,
+    asset_library: Res<AssetLibrary>,
+    locale: Res<Locale>,
     time: Res<Time>,
 ) {
     let allow = screen_fade.faded_in();
     let space_pressed = input.just_pressed(KeyCode::Space);
     let mouse_pressed = mouse.just_pressed(MouseButton::Left);
-    if (space_pressed || mouse_pressed) && allow {
+    if (space_pressed || mouse_pressed) && allow && !dialogue.choices_visible() {
         if !dialogue.entries.is_empty() {
             if dialogue.all_characters_visible() {
                 dialogue.entries.pop_front();
-                dialogue.time = 0.;
+                dialogue.restart();
                 if space_pressed {
                     input.reset(KeyCode::Space);
                 }
@@ -293,15 +640,29 @@ fn dialogue_update(
         }
     }
     let mut hide = false;
+    // once as each entry comes up, and again if the language changes partway through it
+    if !dialogue.logged || locale.is_changed() {
+        dialogue.translate(&locale);
+    }
     if let Some(entry) = dialogue.entries.get(0).cloned() {
         if allow {
+            if !dialogue.logged {
+                ev_log.send(DialogueLogEvent {
+                    entry: DialogueHistoryEntry {
+                        name: String::from(locale.get(entry.portrait.name())),
+                        text: String::from(entry.text.as_str()),
+                    },
+                });
+                dialogue.logged = true;
+            }
             let characters = dialogue.characters();
-            let characters_sfx = characters / 8;
-            if dialogue.last_characters != characters_sfx {
-                for mut sound in queries.p5().iter_mut() {
-                    sound.play();
+            if dialogue.bark(characters) {
+                for (mut sound, portrait) in queries.p5().iter_mut() {
+                    let portrait = portrait.map_or(DialoguePortrait::None, |comp| comp.portrait);
+                    if portrait == entry.portrait {
+                        sound.play();
+                    }
                 }
-                dialogue.last_characters = characters_sfx;
             }
             dialogue.time += time.delta_seconds();
             for mut back_visibility in queries.p0().iter_mut() {
@@ -311,11 +672,22 @@ fn dialogue_update(
                 let a = fade_sprite.color.a();
                 fade_sprite.color.set_a(0.1_f32.lerp(a, 1.));
             }
-            for mut dialogue_text in queries.p1().iter_mut() {
-                dialogue_text.sections[0].value = String::from(&entry.text[0..characters]);
+            for (mut dialogue_text, mut dialogue_effects) in queries.p1().iter_mut() {
+                dialogue_text.sections = entry.text.sections(
+                    characters,
+                    &dialogue_text_style(&asset_library),
+                    &asset_library.font_bold,
+                );
+                let effects = entry.text.effects();
+                if dialogue_effects.effects != effects {
+                    dialogue_effects.effects = effects;
+                }
             }
             for mut dialogue_name in queries.p2().iter_mut() {
-                dialogue_name.sections[0].value = String::from(entry.portrait.name());
+                let name = locale.get(entry.portrait.name());
+                if dialogue_name.sections[0].value != name {
+                    dialogue_name.sections[0].value = String::from(name);
+                }
             }
             for (mut portrait_visibility, portrait) in queries.p3().iter_mut() {
                 *portrait_visibility = if entry.portrait == portrait.portrait {
@@ -338,9 +710,13 @@ fn dialogue_update(
             let a = fade_sprite.color.a();
             fade_sprite.color.set_a(0.1_f32.lerp(a, 0.));
         }
-        for mut dialogue_text in queries.p1().iter_mut() {
-            if dialogue_text.sections[0].value != "" {
-                dialogue_text.sections[0].value = "".to_owned();
+        for (mut dialogue_text, _) in queries.p1().iter_mut() {
+            if dialogue_text
+                .sections
+                .iter()
+                .any(|section| !section.value.is_empty())
+            {
+                dialogue_text.sections.clear();
             }
         }
         for mut dialogue_name in queries.p2().iter_mut() {
@@ -353,3 +729,71 @@ fn dialogue_update(
         }
     }
 }
+
+fn dialogue_choices(
+    mut dialogue: ResMut<Dialogue>,
+    mut choice_query: Query<(
+        &mut Visibility,
+        &mut Sprite,
+        &Clickable,
+        &DialogueChoiceButton,
+        &Children,
+    )>,
+    mut text_query: Query<&mut Text, With<DialogueChoiceText>>,
+    mut sound_query: Query<&mut AudioPlusSource, With<DialogueBack>>,
+    mut ev_choice: EventWriter<DialogueChoiceEvent>,
+    screen_fade: Res<ScreenFade>,
+    mut input: ResMut<Input<KeyCode>>,
+    mut mouse: ResMut<Input<MouseButton>>,
+    locale: Res<Locale>,
+) {
+    let (choices, script) = if screen_fade.faded_in() && dialogue.choices_visible() {
+        (
+            dialogue.entries[0].choices.clone(),
+            dialogue.entries[0].script.clone(),
+        )
+    } else {
+        (vec![], None)
+    };
+    let mut selected = None;
+    for (mut visibility, mut sprite, clickable, button, children) in choice_query.iter_mut() {
+        let Some(choice) = choices.get(button.index) else {
+            *visibility = Visibility::Hidden;
+            continue;
+        };
+        *visibility = Visibility::Inherited;
+        sprite
+            .color
+            .set_a(if clickable.hovered { 0.85 } else { 0.6 });
+        for child in children.iter() {
+            if let Ok(mut text) = text_query.get_mut(*child) {
+                let translation = script.as_ref().and_then(|script| {
+                    locale.try_translate(&dialogue_choice_key(script, &choice.id))
+                });
+                let value = format!(
+                    "{}. {}",
+                    button.index + 1,
+                    translation.unwrap_or(&choice.text)
+                );
+                if text.sections[0].value != value {
+                    text.sections[0].value = value;
+                }
+            }
+        }
+        if clickable.confirmed || input.just_pressed(DIALOGUE_CHOICE_KEYS[button.index]) {
+            selected = Some(button.index);
+        }
+    }
+    if let Some(index) = selected {
+        input.reset(DIALOGUE_CHOICE_KEYS[index]);
+        mouse.reset(MouseButton::Left);
+        if let Some(choice) = dialogue.choose(index) {
+            for mut sound in sound_query.iter_mut() {
+                sound.play();
+            }
+            if let Some(id) = choice.event {
+                ev_choice.send(DialogueChoiceEvent { id });
+            }
+        }
+    }
+}
@@ -2,6 +2,7 @@ use std::f32::consts::PI;
 
 #[derive(Clone, Copy, Debug)]
 pub enum Easing {
+    Linear,
     SineIn,
     SineOut,
     SineInOut,
@@ -43,6 +44,7 @@ const N1: f32 = 7.5625;
 const D1: f32 = 2.75;
 pub fn ease(func: Easing, x: f32) -> f32 {
     match func {
+        Easing::Linear => x,
         Easing::SineIn => 1. - ((x * PI) / 2.).cos(),
         Easing::SineOut => ((x * PI) / 2.).sin(),
         Easing::SineInOut => -((PI * x).cos() - 1.) / 2.,
@@ -0,0 +1,224 @@
+use crate::common::{prelude::*, storage};
+use bevy::{prelude::*, sprite::Anchor};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+const LOCALE_KEY: &str = "language.json";
+
+// english is compiled in so there's always something to show, even before the assets load
+const LOCALE_FALLBACK: &str = include_str!("../../assets/locale/en.locale.ron");
+
+pub struct LocalePlugin;
+
+impl Plugin for LocalePlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(Locale::load())
+            .add_system(locale_tables_update)
+            .add_system(locale_language_toggle_update)
+            .add_system(
+                locale_text_update
+                    .in_base_set(CoreSet::PostUpdate)
+                    .before(bevy::text::update_text2d_layout),
+            );
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Language {
+    English,
+    Spanish,
+}
+
+impl Language {
+    pub const ALL: [Language; 2] = [Language::English, Language::Spanish];
+
+    // always written in the language itself
+    pub fn name(&self) -> &'static str {
+        match self {
+            Language::English => "English",
+            Language::Spanish => "Español",
+        }
+    }
+
+    pub fn next(&self) -> Language {
+        let index = Self::ALL
+            .iter()
+            .position(|language| language == self)
+            .unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+}
+
+#[derive(Resource)]
+pub struct Locale {
+    language: Language,
+    tables: HashMap<Language, HashMap<String, String>>,
+}
+
+impl Locale {
+    pub fn load() -> Self {
+        let mut tables = HashMap::new();
+        match ron::de::from_str::<LocaleAsset>(LOCALE_FALLBACK) {
+            Ok(asset) => {
+                tables.insert(asset.language, asset.strings);
+            }
+            Err(err) => warn!("failed to read the fallback locale: {}", err),
+        }
+        let language = storage::storage_read(LOCALE_KEY)
+            .and_then(|contents| serde_json::from_str::<Language>(&contents).ok())
+            .unwrap_or(Language::English);
+        Self { language, tables }
+    }
+
+    pub fn language(&self) -> Language {
+        self.language
+    }
+
+    pub fn set_language(&mut self, language: Language) {
+        self.language = language;
+        let Ok(contents) = serde_json::to_string(&language) else { return };
+        if !storage::storage_write(LOCALE_KEY, &contents) {
+            warn!("failed to save language");
+        }
+    }
+
+    // looks in the current language first, then english
+    pub fn try_get(&self, id: &str) -> Option<&str> {
+        [self.language, Language::English]
+            .iter()
+            .find_map(|language| self.tables.get(language)?.get(id))
+            .map(String::as_str)
+    }
+
+    // for text written in english elsewhere, like dialogue scripts, only other languages' tables
+    // are looked at
+    pub fn try_translate(&self, id: &str) -> Option<&str> {
+        if self.language == Language::English {
+            return None;
+        }
+        self.tables.get(&self.language)?.get(id).map(String::as_str)
+    }
+
+    // missing strings show their id so they're easy to spot
+    pub fn get<'a>(&'a self, id: &'a str) -> &'a str {
+        self.try_get(id).unwrap_or(id)
+    }
+
+    // fills "{0}", "{1}", ... with the arguments, arguments that are string ids get translated too
+    pub fn format(&self, id: &str, args: &[&str]) -> String {
+        let mut value = String::from(self.get(id));
+        for (index, arg) in args.iter().enumerate() {
+            value = value.replace(&format!("{{{}}}", index), self.try_get(arg).unwrap_or(*arg));
+        }
+        value
+    }
+}
+
+// keeps the first section of a text in sync with the current language
+#[derive(Component, Clone)]
+pub struct LocalizedText {
+    pub id: String,
+    pub args: Vec<String>,
+}
+
+impl LocalizedText {
+    pub fn new(id: &str) -> Self {
+        Self {
+            id: String::from(id),
+            args: vec![],
+        }
+    }
+
+    pub fn with_arg(mut self, arg: impl ToString) -> Self {
+        self.args.push(arg.to_string());
+        self
+    }
+}
+
+fn locale_tables_update(
+    mut ev_asset: EventReader<AssetEvent<LocaleAsset>>,
+    mut locale: ResMut<Locale>,
+    locale_assets: Res<Assets<LocaleAsset>>,
+) {
+    for event in ev_asset.iter() {
+        match event {
+            AssetEvent::Created { handle } | AssetEvent::Modified { handle } => {
+                if let Some(asset) = locale_assets.get(handle) {
+                    locale.tables.insert(asset.language, asset.strings.clone());
+                }
+            }
+            AssetEvent::Removed { .. } => {}
+        }
+    }
+}
+
+fn locale_text_update(mut query: Query<(&mut Text, &LocalizedText)>, locale: Res<Locale>) {
+    for (mut text, localized) in query.iter_mut() {
+        let args = localized
+            .args
+            .iter()
+            .map(String::as_str)
+            .collect::<Vec<_>>();
+        let value = locale.format(&localized.id, &args);
+        if text.sections[0].value != value {
+            text.sections[0].value = value;
+        }
+    }
+}
+
+#[derive(Component)]
+pub struct LocaleLanguageToggle;
+
+pub fn spawn_language_toggle(
+    parent: &mut ChildBuilder,
+    asset_library: &AssetLibrary,
+    position: Vec2,
+    depth: (DepthLayer, f32),
+) {
+    parent.spawn((
+        Text2dBundle {
+            text: Text::from_section(
+                "",
+                TextStyle {
+                    font: asset_library.font_bold.clone(),
+                    font_size: 28.,
+                    color: Color::BLACK,
+                },
+            )
+            .with_alignment(TextAlignment::Left),
+            text_anchor: Anchor::CenterRight,
+            ..Default::default()
+        },
+        Transform2::from_translation(position).with_depth(depth),
+        Clickable {
+            shape: CollisionShape::Rect {
+                size: Vec2::new(240., 40.),
+            },
+            offset: Vec2::new(120., 0.),
+            use_global: true,
+            ..Default::default()
+        },
+        LocaleLanguageToggle,
+    ));
+}
+
+fn locale_language_toggle_update(
+    mut query: Query<(&mut Text, &Clickable, &ComputedVisibility), With<LocaleLanguageToggle>>,
+    mut locale: ResMut<Locale>,
+) {
+    for (mut text, clickable, visibility) in query.iter_mut() {
+        if clickable.confirmed && visibility.is_visible() {
+            let language = locale.language().next();
+            locale.set_language(language);
+        }
+        let label = locale.format("options.language", &[locale.language().name()]);
+        if text.sections[0].value != label {
+            text.sections[0].value = label;
+        }
+        text.sections[0].style.color = if clickable.hovered {
+            Color::rgb(0.5, 0.3, 0.1)
+        } else {
+            Color::BLACK
+        };
+    }
+}
@@ -17,6 +17,8 @@ impl Plugin for CommonPlugin {
             .add_plugin(AudioPlusPlugin)
             .add_plugin(menu_bar::MenuBarPlugin)
             .add_plugin(mouse::MousePlugin)
+            .add_plugin(locale::LocalePlugin)
+            .add_plugin(player_input::PlayerInputPlugin)
             .add_plugin(assets::AssetsPlugin)
             .add_plugin(transform2::Transform2Plugin)
             .add_plugin(ldtk::LdtkPlugin)
@@ -27,6 +29,7 @@ impl Plugin for CommonPlugin {
             .add_plugin(clickable::ClickablePlugin)
             .add_plugin(screen_fade::ScreenFadePlugin)
             .add_plugin(cutscene::CutscenePlugin)
+            .add_plugin(rich_text::RichTextPlugin)
             .add_plugin(dialogue::DialoguePlugin)
             .add_plugin(follow_camera::FollowCameraPlugin)
             .add_plugin(world_locations::WorldLocationsPlugin)
@@ -34,6 +37,9 @@ impl Plugin for CommonPlugin {
             .add_plugin(force_camera_ratio::ForceRatioPlugin)
             .add_plugin(wasm::WasmPlugin)
             .add_plugin(volume_control::VolumeControlPlugin)
+            .add_plugin(audio_settings::AudioSettingsPlugin)
+            .add_plugin(audio_debug::AudioDebugPlugin)
+            .add_plugin(sound_effects::SoundEffectsPlugin)
             .add_global_state::<app_state::AppState>()
             .init_resource::<asset_library::AssetLibrary>()
             .add_system(nan_fix.in_base_set(CoreSet::PreUpdate))
@@ -54,6 +60,8 @@ fn safe_to_state_change() {}
 pub mod app_state;
 pub mod asset_library;
 pub mod assets;
+pub mod audio_debug;
+pub mod audio_settings;
 pub mod clickable;
 pub mod collision;
 pub mod cutscene;
@@ -66,13 +74,17 @@ pub mod follow_camera;
 pub mod force_camera_ratio;
 pub mod label;
 pub mod ldtk;
+pub mod locale;
 pub mod map_builder;
 pub mod math;
 pub mod menu_bar;
 pub mod mouse;
+pub mod player_input;
 pub mod prelude;
+pub mod rich_text;
 pub mod screen_fade;
 pub mod sound_effects;
+pub mod storage;
 pub mod time_to_live;
 pub mod timed_chance;
 pub mod transform2;
@@ -1,11 +1,19 @@
 use bevy::prelude::*;
 
+#[derive(Debug, Hash, PartialEq, Eq, Clone, SystemSet)]
+pub enum MouseSystem {
+    Position,
+}
+
 pub struct MousePlugin;
 
 impl Plugin for MousePlugin {
     fn build(&self, app: &mut App) {
-        app.init_resource::<Mouse>()
-            .add_system(mouse_position.in_base_set(CoreSet::PreUpdate));
+        app.init_resource::<Mouse>().add_system(
+            mouse_position
+                .in_base_set(CoreSet::PreUpdate)
+                .in_set(MouseSystem::Position),
+        );
     }
 }
 
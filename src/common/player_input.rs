@@ -0,0 +1,418 @@
+use crate::common::{mouse::MouseSystem, prelude::*, storage};
+use bevy::{input::mouse::MouseMotion, prelude::*};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+const GAMEPAD_DEADZONE: f32 = 0.2;
+const PLAYER_INPUT_MAPPING_KEY: &str = "controls.json";
+
+pub struct PlayerInputPlugin;
+
+impl Plugin for PlayerInputPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PlayerInput>()
+            .insert_resource(PlayerInputMapping::load())
+            .add_systems(
+                (
+                    player_input_reset,
+                    player_input_mouse,
+                    player_input_keyboard,
+                    player_input_gamepad,
+                )
+                    .chain()
+                    .in_base_set(CoreSet::PreUpdate)
+                    .after(MouseSystem::Position),
+            );
+    }
+}
+
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlayerInputDevice {
+    #[default]
+    Mouse,
+    Keyboard,
+    Gamepad,
+}
+
+// the boat controls for this frame, filled in by whichever device was used last
+#[derive(Default, Debug, Resource)]
+pub struct PlayerInput {
+    pub device: PlayerInputDevice,
+    // analog stick or wasd, length up to 1
+    pub movement: Vec2,
+    // right stick or arrow keys, zero when not aiming
+    pub aim: Vec2,
+    // world position of the cursor, only used by the mouse device
+    pub cursor: Vec2,
+    pub cursor_move: bool,
+    pub dash: bool,
+    pub toggle_shoot: bool,
+}
+
+impl PlayerInput {
+    // direction the boat should face, falls back to the movement direction
+    pub fn aim_direction(&self) -> Option<Vec2> {
+        if self.aim != Vec2::ZERO {
+            Some(self.aim)
+        } else if self.movement != Vec2::ZERO {
+            Some(self.movement)
+        } else {
+            None
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum InputAction {
+    Steer,
+    MoveUp,
+    MoveDown,
+    MoveLeft,
+    MoveRight,
+    AimUp,
+    AimDown,
+    AimLeft,
+    AimRight,
+    Dash,
+    ToggleShoot,
+    Map,
+    QuestLog,
+    DialogueLog,
+    VolumeUp,
+    VolumeDown,
+    Pause,
+}
+
+impl InputAction {
+    pub const ALL: [InputAction; 17] = [
+        InputAction::Steer,
+        InputAction::MoveUp,
+        InputAction::MoveDown,
+        InputAction::MoveLeft,
+        InputAction::MoveRight,
+        InputAction::AimUp,
+        InputAction::AimDown,
+        InputAction::AimLeft,
+        InputAction::AimRight,
+        InputAction::Dash,
+        InputAction::ToggleShoot,
+        InputAction::Map,
+        InputAction::QuestLog,
+        InputAction::DialogueLog,
+        InputAction::VolumeUp,
+        InputAction::VolumeDown,
+        InputAction::Pause,
+    ];
+
+    // locale string id
+    pub fn name(&self) -> &'static str {
+        match self {
+            InputAction::Steer => "action.steer",
+            InputAction::MoveUp => "action.move_up",
+            InputAction::MoveDown => "action.move_down",
+            InputAction::MoveLeft => "action.move_left",
+            InputAction::MoveRight => "action.move_right",
+            InputAction::AimUp => "action.aim_up",
+            InputAction::AimDown => "action.aim_down",
+            InputAction::AimLeft => "action.aim_left",
+            InputAction::AimRight => "action.aim_right",
+            InputAction::Dash => "action.dash",
+            InputAction::ToggleShoot => "action.toggle_shoot",
+            InputAction::Map => "action.map",
+            InputAction::QuestLog => "action.quest_log",
+            InputAction::DialogueLog => "action.dialogue_log",
+            InputAction::VolumeUp => "action.volume_up",
+            InputAction::VolumeDown => "action.volume_down",
+            InputAction::Pause => "action.pause",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum InputBinding {
+    Key(KeyCode),
+    Mouse(MouseButton),
+}
+
+impl InputBinding {
+    pub fn label(&self) -> String {
+        match self {
+            InputBinding::Key(KeyCode::Space) => "Space".to_owned(),
+            InputBinding::Key(KeyCode::Up) => "Up".to_owned(),
+            InputBinding::Key(KeyCode::Down) => "Down".to_owned(),
+            InputBinding::Key(KeyCode::Left) => "Left".to_owned(),
+            InputBinding::Key(KeyCode::Right) => "Right".to_owned(),
+            InputBinding::Key(key) => format!("{:?}", key).trim_start_matches("Key").to_owned(),
+            InputBinding::Mouse(MouseButton::Left) => "LMB".to_owned(),
+            InputBinding::Mouse(MouseButton::Right) => "RMB".to_owned(),
+            InputBinding::Mouse(MouseButton::Middle) => "MMB".to_owned(),
+            InputBinding::Mouse(MouseButton::Other(button)) => format!("Mouse {}", button),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Resource, Serialize, Deserialize)]
+pub struct PlayerInputMapping {
+    bindings: HashMap<InputAction, InputBinding>,
+    gamepad: HashMap<InputAction, GamepadButtonType>,
+}
+
+impl Default for PlayerInputMapping {
+    fn default() -> Self {
+        let bindings = [
+            (InputAction::Steer, InputBinding::Mouse(MouseButton::Left)),
+            (InputAction::MoveUp, InputBinding::Key(KeyCode::W)),
+            (InputAction::MoveDown, InputBinding::Key(KeyCode::S)),
+            (InputAction::MoveLeft, InputBinding::Key(KeyCode::A)),
+            (InputAction::MoveRight, InputBinding::Key(KeyCode::D)),
+            (InputAction::AimUp, InputBinding::Key(KeyCode::Up)),
+            (InputAction::AimDown, InputBinding::Key(KeyCode::Down)),
+            (InputAction::AimLeft, InputBinding::Key(KeyCode::Left)),
+            (InputAction::AimRight, InputBinding::Key(KeyCode::Right)),
+            (InputAction::Dash, InputBinding::Key(KeyCode::Space)),
+            (InputAction::ToggleShoot, InputBinding::Key(KeyCode::F)),
+            (InputAction::Map, InputBinding::Key(KeyCode::M)),
+            (InputAction::QuestLog, InputBinding::Key(KeyCode::J)),
+            (InputAction::DialogueLog, InputBinding::Key(KeyCode::H)),
+            (InputAction::VolumeUp, InputBinding::Key(KeyCode::P)),
+            (InputAction::VolumeDown, InputBinding::Key(KeyCode::O)),
+            (InputAction::Pause, InputBinding::Key(KeyCode::Escape)),
+        ];
+        let gamepad = [
+            (InputAction::Dash, GamepadButtonType::South),
+            (InputAction::ToggleShoot, GamepadButtonType::West),
+            (InputAction::Map, GamepadButtonType::Select),
+            (InputAction::QuestLog, GamepadButtonType::North),
+            (InputAction::Pause, GamepadButtonType::Start),
+        ];
+        Self {
+            bindings: bindings.into_iter().collect(),
+            gamepad: gamepad.into_iter().collect(),
+        }
+    }
+}
+
+impl PlayerInputMapping {
+    // stored bindings are layered over the defaults so new actions still get a binding
+    pub fn load() -> Self {
+        let mut mapping = Self::default();
+        let Some(contents) = storage::storage_read(PLAYER_INPUT_MAPPING_KEY) else {
+            return mapping;
+        };
+        match serde_json::from_str::<PlayerInputMapping>(&contents) {
+            Ok(stored) => {
+                mapping.bindings.extend(stored.bindings);
+                mapping.gamepad.extend(stored.gamepad);
+            }
+            Err(err) => warn!("failed to read controls: {}", err),
+        }
+        mapping
+    }
+
+    pub fn save(&self) -> bool {
+        let Ok(contents) = serde_json::to_string(self) else { return false };
+        storage::storage_write(PLAYER_INPUT_MAPPING_KEY, &contents)
+    }
+
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    pub fn binding(&self, action: InputAction) -> Option<InputBinding> {
+        self.bindings.get(&action).copied()
+    }
+
+    pub fn gamepad_button(&self, action: InputAction) -> Option<GamepadButtonType> {
+        self.gamepad.get(&action).copied()
+    }
+
+    // an action already using the binding takes this action's old one instead, and is returned
+    pub fn bind(&mut self, action: InputAction, binding: InputBinding) -> Option<InputAction> {
+        swap_binding(&mut self.bindings, action, binding)
+    }
+
+    pub fn bind_gamepad(
+        &mut self,
+        action: InputAction,
+        button: GamepadButtonType,
+    ) -> Option<InputAction> {
+        swap_binding(&mut self.gamepad, action, button)
+    }
+
+    pub fn label(&self, action: InputAction) -> String {
+        let binding = self
+            .binding(action)
+            .map(|binding| binding.label())
+            .unwrap_or_else(|| "-".to_owned());
+        if let Some(button) = self.gamepad_button(action) {
+            format!("{} / {:?}", binding, button)
+        } else {
+            binding
+        }
+    }
+
+    pub fn pressed(
+        &self,
+        action: InputAction,
+        keys: &Input<KeyCode>,
+        mouse: &Input<MouseButton>,
+    ) -> bool {
+        match self.binding(action) {
+            Some(InputBinding::Key(key)) => keys.pressed(key),
+            Some(InputBinding::Mouse(button)) => mouse.pressed(button),
+            None => false,
+        }
+    }
+
+    pub fn just_pressed(
+        &self,
+        action: InputAction,
+        keys: &Input<KeyCode>,
+        mouse: &Input<MouseButton>,
+    ) -> bool {
+        match self.binding(action) {
+            Some(InputBinding::Key(key)) => keys.just_pressed(key),
+            Some(InputBinding::Mouse(button)) => mouse.just_pressed(button),
+            None => false,
+        }
+    }
+
+    pub fn gamepad_pressed(
+        &self,
+        action: InputAction,
+        gamepads: &Gamepads,
+        buttons: &Input<GamepadButton>,
+    ) -> bool {
+        let Some(button) = self.gamepad_button(action) else { return false };
+        gamepads
+            .iter()
+            .any(|gamepad| buttons.pressed(GamepadButton::new(gamepad, button)))
+    }
+
+    pub fn gamepad_just_pressed(
+        &self,
+        action: InputAction,
+        gamepads: &Gamepads,
+        buttons: &Input<GamepadButton>,
+    ) -> bool {
+        let Some(button) = self.gamepad_button(action) else { return false };
+        gamepads
+            .iter()
+            .any(|gamepad| buttons.just_pressed(GamepadButton::new(gamepad, button)))
+    }
+}
+
+fn swap_binding<T: Copy + PartialEq>(
+    bindings: &mut HashMap<InputAction, T>,
+    action: InputAction,
+    binding: T,
+) -> Option<InputAction> {
+    let previous = bindings.insert(action, binding);
+    let other = bindings
+        .iter()
+        .find(|(other, other_binding)| **other != action && **other_binding == binding)
+        .map(|(other, _)| *other)?;
+    match previous {
+        Some(previous) => bindings.insert(other, previous),
+        None => bindings.remove(&other),
+    };
+    Some(other)
+}
+
+fn player_input_reset(mut player_input: ResMut<PlayerInput>) {
+    player_input.movement = Vec2::ZERO;
+    player_input.aim = Vec2::ZERO;
+    player_input.cursor_move = false;
+    player_input.dash = false;
+    player_input.toggle_shoot = false;
+}
+
+fn player_input_mouse(
+    mut player_input: ResMut<PlayerInput>,
+    mut ev_mouse_motion: EventReader<MouseMotion>,
+    mouse: Res<Mouse>,
+    mouse_buttons: Res<Input<MouseButton>>,
+    keys: Res<Input<KeyCode>>,
+    mapping: Res<PlayerInputMapping>,
+) {
+    if ev_mouse_motion.iter().last().is_some() || mouse_buttons.get_just_pressed().next().is_some()
+    {
+        player_input.device = PlayerInputDevice::Mouse;
+    }
+    player_input.cursor = mouse.position;
+    if player_input.device == PlayerInputDevice::Mouse {
+        player_input.cursor_move = mapping.pressed(InputAction::Steer, &keys, &mouse_buttons);
+    }
+}
+
+fn player_input_keyboard(
+    mut player_input: ResMut<PlayerInput>,
+    keys: Res<Input<KeyCode>>,
+    mouse_buttons: Res<Input<MouseButton>>,
+    mapping: Res<PlayerInputMapping>,
+) {
+    let pressed = |action: InputAction| mapping.pressed(action, &keys, &mouse_buttons);
+    let axis = |negative: InputAction, positive: InputAction| {
+        pressed(positive) as i32 as f32 - pressed(negative) as i32 as f32
+    };
+    let movement = Vec2::new(
+        axis(InputAction::MoveLeft, InputAction::MoveRight),
+        axis(InputAction::MoveDown, InputAction::MoveUp),
+    )
+    .normalize_or_zero();
+    let aim = Vec2::new(
+        axis(InputAction::AimLeft, InputAction::AimRight),
+        axis(InputAction::AimDown, InputAction::AimUp),
+    )
+    .normalize_or_zero();
+    if movement != Vec2::ZERO || aim != Vec2::ZERO {
+        player_input.device = PlayerInputDevice::Keyboard;
+        player_input.movement = movement;
+        player_input.aim = aim;
+    }
+    player_input.dash |= pressed(InputAction::Dash);
+    player_input.toggle_shoot |=
+        mapping.just_pressed(InputAction::ToggleShoot, &keys, &mouse_buttons);
+}
+
+fn player_input_gamepad(
+    mut player_input: ResMut<PlayerInput>,
+    gamepads: Res<Gamepads>,
+    axes: Res<Axis<GamepadAxis>>,
+    buttons: Res<Input<GamepadButton>>,
+    mapping: Res<PlayerInputMapping>,
+) {
+    for gamepad in gamepads.iter() {
+        let stick = |x: GamepadAxisType, y: GamepadAxisType| {
+            let stick = Vec2::new(
+                axes.get(GamepadAxis::new(gamepad, x)).unwrap_or(0.),
+                axes.get(GamepadAxis::new(gamepad, y)).unwrap_or(0.),
+            );
+            if stick.length() < GAMEPAD_DEADZONE {
+                Vec2::ZERO
+            } else {
+                stick.clamp_length_max(1.)
+            }
+        };
+        let movement = stick(GamepadAxisType::LeftStickX, GamepadAxisType::LeftStickY);
+        let aim = stick(GamepadAxisType::RightStickX, GamepadAxisType::RightStickY);
+        let button = |action: InputAction| {
+            mapping
+                .gamepad_button(action)
+                .map(|button| GamepadButton::new(gamepad, button))
+        };
+        let dash = button(InputAction::Dash).map_or(false, |button| buttons.pressed(button));
+        let toggle_shoot =
+            button(InputAction::ToggleShoot).map_or(false, |button| buttons.just_pressed(button));
+        if movement != Vec2::ZERO || aim != Vec2::ZERO {
+            player_input.device = PlayerInputDevice::Gamepad;
+            player_input.movement = movement;
+            player_input.aim = aim.normalize_or_zero();
+        }
+        if dash || toggle_shoot {
+            player_input.device = PlayerInputDevice::Gamepad;
+        }
+        player_input.dash |= dash;
+        player_input.toggle_shoot |= toggle_shoot;
+    }
+}
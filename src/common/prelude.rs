@@ -1,26 +1,34 @@
 pub use super::{
     app_state::AppState,
     asset_library::AssetLibrary,
-    assets::ldtk::LdtkAsset,
+    assets::{dialogue::DialogueAsset, ldtk::LdtkAsset, locale::LocaleAsset},
     clickable::Clickable,
     collision::{
-        shape::CollisionShape, Collision, CollisionFilter, CollisionQuery, COLLISION_FLAG,
+        broadphase::Broadphase, shape::CollisionShape, Collision, CollisionFilter, CollisionLayer,
+        CollisionQuery, CollisionQueryEntry, CollisionSystem, COLLISION_FLAG,
     },
     cutscene::{
-        AddAppCutscene, Cutscene, CutsceneBuilder, CutsceneContinueEvent, CutsceneSkipEvent,
-        CutsceneStartEvent, Cutscenes,
+        AddAppCutscene, Cutscene, CutsceneBuilder, CutsceneContinueEvent, CutsceneFinishedEvent,
+        CutsceneParallelBuilder, CutsceneSkipEvent, CutsceneStartEvent, CutsceneSystem, Cutscenes,
+        Tween,
     },
     depth_layers::*,
-    dialogue::{Dialogue, DialogueInitEvent, DialoguePortrait},
+    dialogue::{
+        Dialogue, DialogueChoice, DialogueChoiceEvent, DialogueHistoryEntry, DialogueInitEvent,
+        DialogueLogEvent, DialoguePortrait,
+    },
     easing::*,
     facing::Facing,
     follow_camera::FollowCamera,
     label::Label,
     ldtk::{Ldtk, LdtkSpawnEvent},
+    locale::{Language, Locale, LocalizedText},
     map_builder::MapBuilder,
     math::{Lerp, Rect},
     menu_bar::MenuBar,
     mouse::Mouse,
+    player_input::{InputAction, InputBinding, PlayerInput, PlayerInputDevice, PlayerInputMapping},
+    rich_text::{RichText, RichTextEffect, RichTextEffects},
     screen_fade::ScreenFade,
     time_to_live::TimeToLive,
     timed_chance::TimedChance,
@@ -0,0 +1,241 @@
+use bevy::{prelude::*, text::TextLayoutInfo};
+use std::ops::Range;
+
+pub struct RichTextPlugin;
+
+impl Plugin for RichTextPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system(
+            rich_text_effects
+                .in_base_set(CoreSet::PostUpdate)
+                .after(bevy::text::update_text2d_layout),
+        );
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum RichTextEffect {
+    #[default]
+    None,
+    Shake,
+    Wave,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct RichTextStyle {
+    pub color: Option<Color>,
+    pub bold: bool,
+    pub effect: RichTextEffect,
+    pub speed: f32,
+}
+
+impl Default for RichTextStyle {
+    fn default() -> Self {
+        Self {
+            color: None,
+            bold: false,
+            effect: RichTextEffect::None,
+            speed: 1.,
+        }
+    }
+}
+
+impl RichTextStyle {
+    fn with_tag(self, tag: &str) -> Option<Self> {
+        let (name, value) = match tag.split_once('=') {
+            Some((name, value)) => (name, Some(value)),
+            None => (tag, None),
+        };
+        match (name, value) {
+            ("b", None) => Some(Self { bold: true, ..self }),
+            ("shake", None) => Some(Self {
+                effect: RichTextEffect::Shake,
+                ..self
+            }),
+            ("wave", None) => Some(Self {
+                effect: RichTextEffect::Wave,
+                ..self
+            }),
+            ("color", Some(value)) => Some(Self {
+                color: Some(rich_text_color(value)?),
+                ..self
+            }),
+            ("speed", Some(value)) => Some(Self {
+                speed: value.parse::<f32>().ok()?.max(0.01),
+                ..self
+            }),
+            _ => None,
+        }
+    }
+}
+
+fn rich_text_color(value: &str) -> Option<Color> {
+    match value {
+        "red" => Some(Color::rgb_u8(150, 28, 28)),
+        "gold" => Some(Color::rgb_u8(176, 122, 16)),
+        "blue" => Some(Color::rgb_u8(28, 66, 150)),
+        "green" => Some(Color::rgb_u8(38, 110, 44)),
+        _ => Color::hex(value.trim_start_matches('#')).ok(),
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RichTextSegment {
+    pub range: Range<usize>,
+    pub style: RichTextStyle,
+    // seconds held before the segment starts revealing
+    pub pause: f32,
+}
+
+// text with inline markup stripped out, e.g.
+// "[b]Ahoy[/b] [color=red][shake]ye dogs[/shake][/color]![pause=0.5] [speed=0.5]Ha...[/speed]"
+#[derive(Debug, Default, Clone)]
+pub struct RichText {
+    text: String,
+    segments: Vec<RichTextSegment>,
+}
+
+impl RichText {
+    pub fn parse(markup: &str) -> Self {
+        let mut rich_text = Self::default();
+        let mut styles = vec![RichTextStyle::default()];
+        let mut start = 0;
+        let mut pause = 0.;
+        let mut rest = markup;
+        while let Some(open) = rest.find('[') {
+            let Some(close) = rest[open..].find(']').map(|close| open + close) else { break };
+            rich_text.text.push_str(&rest[..open]);
+            let tag = &rest[open + 1..close];
+            rest = &rest[close + 1..];
+            rich_text.split(&mut start, styles[styles.len() - 1], &mut pause);
+            if tag.starts_with('/') {
+                if styles.len() > 1 {
+                    styles.pop();
+                }
+            } else if let Some(seconds) = tag.strip_prefix("pause=") {
+                pause += seconds.parse::<f32>().unwrap_or(0.);
+            } else if let Some(style) = styles[styles.len() - 1].with_tag(tag) {
+                styles.push(style);
+            } else {
+                warn!("unknown rich text tag: [{}]", tag);
+            }
+        }
+        rich_text.text.push_str(rest);
+        rich_text.split(&mut start, styles[styles.len() - 1], &mut pause);
+        rich_text
+    }
+
+    fn split(&mut self, start: &mut usize, style: RichTextStyle, pause: &mut f32) {
+        if self.text.len() > *start || *pause > 0. {
+            self.segments.push(RichTextSegment {
+                range: *start..self.text.len(),
+                style,
+                pause: *pause,
+            });
+        }
+        *start = self.text.len();
+        *pause = 0.;
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    pub fn len(&self) -> usize {
+        self.text.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+
+    pub fn segments(&self) -> &[RichTextSegment] {
+        &self.segments
+    }
+
+    // the whole text is always laid out so lines don't rewrap as it reveals, each segment is a
+    // shown section followed by a transparent one holding whatever isn't revealed yet
+    pub fn sections(
+        &self,
+        characters: usize,
+        style: &TextStyle,
+        bold: &Handle<Font>,
+    ) -> Vec<TextSection> {
+        self.segments
+            .iter()
+            .flat_map(|segment| {
+                let split = segment.range.end.min(characters).max(segment.range.start);
+                let style = TextStyle {
+                    font: if segment.style.bold {
+                        bold.clone()
+                    } else {
+                        style.font.clone()
+                    },
+                    font_size: style.font_size,
+                    color: segment.style.color.unwrap_or(style.color),
+                };
+                let mut hidden = style.clone();
+                hidden.color.set_a(0.);
+                [
+                    TextSection {
+                        value: String::from(&self.text[segment.range.start..split]),
+                        style,
+                    },
+                    TextSection {
+                        value: String::from(&self.text[split..segment.range.end]),
+                        style: hidden,
+                    },
+                ]
+            })
+            .collect()
+    }
+
+    // lines up with `sections`, two per segment
+    pub fn effects(&self) -> Vec<RichTextEffect> {
+        self.segments
+            .iter()
+            .flat_map(|segment| [segment.style.effect; 2])
+            .collect()
+    }
+}
+
+// moves laid out glyphs of text2d entities around, indexed by text section
+#[derive(Component, Default)]
+pub struct RichTextEffects {
+    pub effects: Vec<RichTextEffect>,
+    positions: Vec<Vec2>,
+    time: f32,
+}
+
+fn rich_text_effects(
+    mut query: Query<(&mut TextLayoutInfo, &mut RichTextEffects)>,
+    time: Res<Time>,
+) {
+    for (mut layout, mut effects) in query.iter_mut() {
+        effects.time += time.delta_seconds();
+        if layout.is_changed() {
+            effects.positions = layout.glyphs.iter().map(|glyph| glyph.position).collect();
+        }
+        if effects
+            .effects
+            .iter()
+            .all(|effect| *effect == RichTextEffect::None)
+        {
+            continue;
+        }
+        let effects = effects.as_ref();
+        for (index, glyph) in layout.glyphs.iter_mut().enumerate() {
+            let Some(position) = effects.positions.get(index) else { continue };
+            let offset = match effects.effects.get(glyph.section_index) {
+                Some(RichTextEffect::Shake) => {
+                    Vec2::new(rand::random::<f32>() - 0.5, rand::random::<f32>() - 0.5) * 4.
+                }
+                Some(RichTextEffect::Wave) => {
+                    Vec2::Y * (effects.time * 8. - index as f32 * 0.6).sin() * 4.
+                }
+                _ => Vec2::ZERO,
+            };
+            glyph.position = *position + offset;
+        }
+    }
+}
@@ -1,396 +1,122 @@
 pub use crate::common::prelude::*;
 pub use audio_plus::prelude::*;
+use bevy::prelude::*;
 
-#[derive(Default)]
-pub struct SoundEffects {
-    // placeholder
-    pub sfx_placeholder_music: AudioPlusSoundEffect,
-    pub sfx_placeholder_sound: AudioPlusSoundEffect,
-
-    // generic
-    pub sfx_dialogue_start: AudioPlusSoundEffect,
-    pub sfx_dialogue_proceed: AudioPlusSoundEffect,
-    pub sfx_dialogue_repeat: AudioPlusSoundEffect,
-    pub sfx_audio_preview: AudioPlusSoundEffect,
+pub struct SoundEffectsPlugin;
 
-    // main menu
-    pub sfx_menu_ambient: AudioPlusSoundEffect,
-    pub sfx_menu_music: AudioPlusSoundEffect,
-    pub sfx_menu_button_hover: AudioPlusSoundEffect,
-    pub sfx_menu_button_click: AudioPlusSoundEffect,
-    pub sfx_menu_button_click_confirm: AudioPlusSoundEffect,
-
-    // cutscenes
-    pub sfx_cutscene_intro_music: AudioPlusSoundEffect,
-    pub sfx_cutscene_intro1: AudioPlusSoundEffect,
-    pub sfx_cutscene_intro2: AudioPlusSoundEffect,
-    pub sfx_cutscene_intro3: AudioPlusSoundEffect,
-    pub sfx_cutscene_intro4: AudioPlusSoundEffect,
-    pub sfx_cutscene_intro5: AudioPlusSoundEffect,
+impl Plugin for SoundEffectsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system(sound_effects_reload);
+    }
+}
 
-    pub sfx_cutscene_outro_music: AudioPlusSoundEffect,
-    pub sfx_cutscene_outro1: AudioPlusSoundEffect,
-    pub sfx_cutscene_outro2: AudioPlusSoundEffect,
-    pub sfx_cutscene_outro3: AudioPlusSoundEffect,
+macro_rules! sound_effects {
+    ( $( $x:ident ),* $(,)? ) => {
+        #[derive(Default)]
+        pub struct SoundEffects {
+            $(
+                pub $x: AudioPlusSoundEffect,
+            )*
+        }
 
-    // overworld
-    pub sfx_overworld_ambient: AudioPlusSoundEffect,
-    pub sfx_overworld_music: AudioPlusSoundEffect,
-    pub sfx_overworld_town_enter: AudioPlusSoundEffect,
-    pub sfx_overworld_town_exit: AudioPlusSoundEffect,
-    pub sfx_overworld_dash: AudioPlusSoundEffect,
-    pub sfx_overworld_experience: AudioPlusSoundEffect,
-    pub sfx_overworld_player_damage: AudioPlusSoundEffect,
-    pub sfx_overworld_player_died: AudioPlusSoundEffect,
-    pub sfx_overworld_enemy_damage: AudioPlusSoundEffect,
-    pub sfx_overworld_map_open: AudioPlusSoundEffect,
-    pub sfx_overworld_map_close: AudioPlusSoundEffect,
-    pub sfx_overworld_level_up: AudioPlusSoundEffect,
-
-    // attacks
-    pub sfx_overworld_attack_forward_cannons: AudioPlusSoundEffect,
-    pub sfx_overworld_attack_shotgun_cannons: AudioPlusSoundEffect,
-    pub sfx_overworld_attack_shockwave: AudioPlusSoundEffect,
-    pub sfx_overworld_attack_bombs: AudioPlusSoundEffect,
-    pub sfx_overworld_attack_bomb_throw: AudioPlusSoundEffect,
-    pub sfx_overworld_attack_bomb_explode: AudioPlusSoundEffect,
-    pub sfx_overworld_attack_kraken: AudioPlusSoundEffect,
-    pub sfx_overworld_attack_tentacle: AudioPlusSoundEffect,
+        pub fn sound_effects_create(definitions: &AudioPlusSoundEffects) -> SoundEffects {
+            SoundEffects {
+                $(
+                    $x: sound_effect(definitions, stringify!($x)),
+                )*
+            }
+        }
+    };
+}
 
-    // town
-    pub sfx_town_ambient: AudioPlusSoundEffect,
-    pub sfx_town_music: AudioPlusSoundEffect,
-    pub sfx_town_outside_hover: AudioPlusSoundEffect,
-    pub sfx_town_outside_click: AudioPlusSoundEffect,
-    pub sfx_town_rum_refill_jingle: AudioPlusSoundEffect,
-    pub sfx_town_rum_refill_clank: AudioPlusSoundEffect,
-    pub sfx_town_upgrade: AudioPlusSoundEffect,
+fn sound_effect(definitions: &AudioPlusSoundEffects, name: &str) -> AudioPlusSoundEffect {
+    if let Some(effect) = definitions.get(name) {
+        effect.clone()
+    } else {
+        warn!("missing sound effect definition: {}", name);
+        AudioPlusSoundEffect::none()
+    }
 }
 
-pub fn sound_effects_create(asset_library: &AssetLibrary) -> SoundEffects {
-    SoundEffects {
-        sfx_placeholder_music: AudioPlusSoundEffect {
-            audio_sources: vec![asset_library.audio_music_placeholder.clone()],
-            volume: 1.0,
-            ..Default::default()
-        },
-        sfx_placeholder_sound: AudioPlusSoundEffect {
-            audio_sources: vec![asset_library.audio_sfx_placeholder.clone()],
-            volume: 1.0,
-            ..Default::default()
-        },
-        sfx_dialogue_start: AudioPlusSoundEffect {
-            audio_sources: vec![asset_library.audio_sfx_placeholder.clone()],
-            volume: 0.0,
-            ..Default::default()
-        },
-        sfx_dialogue_proceed: AudioPlusSoundEffect {
-            audio_sources: vec![asset_library.menu_sfx_button_click.clone()],
-            volume: 1.0,
-            ..Default::default()
-        },
-        sfx_dialogue_repeat: AudioPlusSoundEffect {
-            audio_sources: vec![
-                asset_library.audio_sfx_dialogue_voice_generic_01.clone(),
-                asset_library.audio_sfx_dialogue_voice_generic_02.clone(),
-                asset_library.audio_sfx_dialogue_voice_generic_03.clone(),
-                asset_library.audio_sfx_dialogue_voice_generic_04.clone(),
-                asset_library.audio_sfx_dialogue_voice_generic_05.clone(),
-                asset_library.audio_sfx_dialogue_voice_generic_06.clone(),
-                asset_library.audio_sfx_dialogue_voice_generic_07.clone(),
-                asset_library.audio_sfx_dialogue_voice_generic_08.clone(),
-                asset_library.audio_sfx_dialogue_voice_generic_09.clone(),
-            ],
-            volume: 0.6,
-            pitch_variation: 0.2,
-            chance: 0.85,
-            ..Default::default()
-        },
-        sfx_menu_ambient: AudioPlusSoundEffect {
-            audio_sources: vec![asset_library.audio_sfx_sea.clone()],
-            volume: 0.6,
-            fade_in: 1.,
-            fade_out: 1.,
-            ..Default::default()
-        },
-        sfx_menu_music: AudioPlusSoundEffect {
-            audio_sources: vec![asset_library.menu_music.clone()],
-            volume: 0.65,
-            fade_out: 1.7,
-            ..Default::default()
-        },
-        sfx_menu_button_hover: AudioPlusSoundEffect {
-            audio_sources: vec![asset_library.menu_sfx_button_hover.clone()],
-            volume: 1.,
-            ..Default::default()
-        },
-        sfx_menu_button_click: AudioPlusSoundEffect {
-            audio_sources: vec![asset_library.menu_sfx_button_click.clone()],
-            volume: 1.,
-            ..Default::default()
-        },
-        sfx_menu_button_click_confirm: AudioPlusSoundEffect {
-            audio_sources: vec![asset_library.menu_sfx_play.clone()],
-            volume: 1.,
-            ..Default::default()
-        },
-        sfx_cutscene_intro_music: AudioPlusSoundEffect {
-            audio_sources: vec![asset_library.menu_music.clone()],
-            volume: 0.1,
-            fade_out: 1.0,
-            ..Default::default()
-        },
-        sfx_cutscene_intro1: AudioPlusSoundEffect {
-            audio_sources: vec![asset_library.cutscene_voice_intro1.clone()],
-            volume: 0.7,
-            fade_out: 0.5,
-            ..Default::default()
-        },
-        sfx_cutscene_intro2: AudioPlusSoundEffect {
-            audio_sources: vec![asset_library.cutscene_voice_intro2.clone()],
-            volume: 0.7,
-            fade_out: 0.5,
-            ..Default::default()
-        },
-        sfx_cutscene_intro3: AudioPlusSoundEffect {
-            audio_sources: vec![asset_library.cutscene_voice_intro3.clone()],
-            volume: 0.7,
-            fade_out: 0.5,
-            ..Default::default()
-        },
-        sfx_cutscene_intro4: AudioPlusSoundEffect {
-            audio_sources: vec![asset_library.cutscene_voice_intro4.clone()],
-            volume: 0.7,
-            fade_out: 0.5,
-            ..Default::default()
-        },
-        sfx_cutscene_intro5: AudioPlusSoundEffect {
-            audio_sources: vec![asset_library.cutscene_voice_intro5.clone()],
-            volume: 0.7,
-            fade_out: 0.5,
-            ..Default::default()
-        },
-        sfx_cutscene_outro_music: AudioPlusSoundEffect {
-            audio_sources: vec![asset_library.menu_music.clone()],
-            volume: 0.1,
-            fade_out: 1.0,
-            ..Default::default()
-        },
-        sfx_cutscene_outro1: AudioPlusSoundEffect {
-            audio_sources: vec![asset_library.cutscene_voice_outro1.clone()],
-            volume: 0.7,
-            fade_out: 0.5,
-            ..Default::default()
-        },
-        sfx_cutscene_outro2: AudioPlusSoundEffect {
-            audio_sources: vec![asset_library.cutscene_voice_outro2.clone()],
-            volume: 0.7,
-            fade_out: 0.5,
-            ..Default::default()
-        },
-        sfx_cutscene_outro3: AudioPlusSoundEffect {
-            audio_sources: vec![asset_library.cutscene_voice_outro3.clone()],
-            volume: 0.7,
-            fade_out: 0.5,
-            ..Default::default()
-        },
-        sfx_overworld_ambient: AudioPlusSoundEffect {
-            audio_sources: vec![asset_library.audio_sfx_sea.clone()],
-            volume: 0.1,
-            fade_in: 1.,
-            fade_out: 1.,
-            ..Default::default()
-        },
-        sfx_overworld_town_enter: AudioPlusSoundEffect {
-            audio_sources: vec![asset_library.audio_sfx_town_enter.clone()],
-            volume: 0.8,
-            ..Default::default()
-        },
-        sfx_overworld_town_exit: AudioPlusSoundEffect {
-            audio_sources: vec![asset_library.audio_sfx_town_leave.clone()],
-            volume: 0.8,
-            ..Default::default()
-        },
-        sfx_overworld_dash: AudioPlusSoundEffect {
-            audio_sources: vec![
-                asset_library.audio_sfx_dash_01.clone(),
-                asset_library.audio_sfx_dash_02.clone(),
-                asset_library.audio_sfx_dash_03.clone(),
-            ],
-            volume: 0.1,
-            ..Default::default()
-        },
-        sfx_overworld_experience: AudioPlusSoundEffect {
-            audio_sources: vec![
-                asset_library.audio_sfx_xp_01.clone(),
-                asset_library.audio_sfx_xp_02.clone(),
-                asset_library.audio_sfx_xp_03.clone(),
-            ],
-            volume: 0.45,
-            ..Default::default()
-        },
-        sfx_overworld_player_damage: AudioPlusSoundEffect {
-            audio_sources: vec![
-                asset_library.audio_sfx_player_damage_01.clone(),
-                asset_library.audio_sfx_player_damage_02.clone(),
-                asset_library.audio_sfx_player_damage_03.clone(),
-            ],
-            volume: 0.6,
-            ..Default::default()
-        },
-        sfx_overworld_player_died: AudioPlusSoundEffect {
-            audio_sources: vec![asset_library.audio_sfx_player_died.clone()],
-            volume: 0.8,
-            ..Default::default()
-        },
-        sfx_overworld_enemy_damage: AudioPlusSoundEffect {
-            audio_sources: vec![
-                asset_library.audio_sfx_enemy_damage_01.clone(),
-                asset_library.audio_sfx_enemy_damage_02.clone(),
-                asset_library.audio_sfx_enemy_damage_03.clone(),
-            ],
-            positional: true,
-            volume: 0.6,
-            ..Default::default()
-        },
-        sfx_overworld_map_open: AudioPlusSoundEffect {
-            audio_sources: vec![asset_library.audio_sfx_map_open_01.clone()],
-            volume: 0.5,
-            ..Default::default()
-        },
-        sfx_overworld_map_close: AudioPlusSoundEffect {
-            audio_sources: vec![asset_library.audio_sfx_map_close_01.clone()],
-            volume: 0.5,
-            ..Default::default()
-        },
-        sfx_overworld_level_up: AudioPlusSoundEffect {
-            audio_sources: vec![asset_library.audio_sfx_level_up.clone()],
-            volume: 0.5,
-            ..Default::default()
-        },
-        sfx_overworld_attack_forward_cannons: AudioPlusSoundEffect {
-            audio_sources: vec![
-                asset_library.audio_sfx_attack_player_01.clone(),
-                asset_library.audio_sfx_attack_player_02.clone(),
-                asset_library.audio_sfx_attack_player_03.clone(),
-            ],
-            volume: 0.4,
-            positional: true,
-            ..Default::default()
-        },
-        sfx_overworld_attack_shotgun_cannons: AudioPlusSoundEffect {
-            audio_sources: vec![
-                asset_library.audio_sfx_attack_jagerossa_01.clone(),
-                asset_library.audio_sfx_attack_jagerossa_02.clone(),
-                asset_library.audio_sfx_attack_jagerossa_03.clone(),
-            ],
-            volume: 0.3,
-            positional: true,
-            ..Default::default()
-        },
-        sfx_overworld_attack_shockwave: AudioPlusSoundEffect {
-            audio_sources: vec![
-                asset_library.audio_sfx_attack_ringo_01.clone(),
-                asset_library.audio_sfx_attack_ringo_02.clone(),
-                asset_library.audio_sfx_attack_ringo_03.clone(),
-            ],
-            volume: 0.3,
-            positional: true,
-            ..Default::default()
-        },
-        sfx_overworld_attack_bombs: AudioPlusSoundEffect {
-            audio_sources: vec![
-                asset_library.audio_sfx_attack_plank_01.clone(),
-                asset_library.audio_sfx_attack_plank_02.clone(),
-                asset_library.audio_sfx_attack_plank_03.clone(),
-            ],
-            volume: 0.15,
-            positional: true,
-            ..Default::default()
-        },
-        sfx_overworld_attack_bomb_throw: AudioPlusSoundEffect {
-            audio_sources: vec![
-                asset_library.audio_sfx_bomb_throw_01.clone(),
-                asset_library.audio_sfx_bomb_throw_02.clone(),
-                asset_library.audio_sfx_bomb_throw_03.clone(),
-            ],
-            volume: 0.1,
-            positional: true,
-            ..Default::default()
-        },
-        sfx_overworld_attack_bomb_explode: AudioPlusSoundEffect {
-            audio_sources: vec![
-                asset_library.audio_sfx_bomb_explode_01.clone(),
-                asset_library.audio_sfx_bomb_explode_02.clone(),
-                asset_library.audio_sfx_bomb_explode_03.clone(),
-            ],
-            volume: 0.2,
-            positional: true,
-            ..Default::default()
-        },
-        sfx_overworld_attack_kraken: AudioPlusSoundEffect {
-            audio_sources: vec![
-                asset_library.audio_sfx_attack_davy_01.clone(),
-                asset_library.audio_sfx_attack_davy_02.clone(),
-                asset_library.audio_sfx_attack_davy_03.clone(),
-            ],
-            volume: 0.2,
-            positional: true,
-            ..Default::default()
-        },
-        sfx_overworld_attack_tentacle: AudioPlusSoundEffect {
-            audio_sources: vec![
-                asset_library.audio_sfx_tentacle_01.clone(),
-                asset_library.audio_sfx_tentacle_02.clone(),
-                asset_library.audio_sfx_tentacle_03.clone(),
-            ],
-            volume: 0.06,
-            positional: true,
-            pitch_variation: 0.1,
-            ..Default::default()
-        },
-        sfx_overworld_music: AudioPlusSoundEffect::none(),
-        sfx_town_ambient: AudioPlusSoundEffect::none(),
-        sfx_town_music: AudioPlusSoundEffect {
-            audio_sources: vec![asset_library.menu_music.clone()],
-            volume: 0.2,
-            fade_in: 0.2,
-            fade_out: 1.0,
-            ..Default::default()
-        },
-        sfx_town_outside_hover: AudioPlusSoundEffect {
-            audio_sources: vec![asset_library.menu_sfx_button_hover.clone()],
-            volume: 1.,
-            ..Default::default()
-        },
-        sfx_town_outside_click: AudioPlusSoundEffect {
-            audio_sources: vec![asset_library.menu_sfx_button_click.clone()],
-            volume: 1.,
-            ..Default::default()
-        },
-        sfx_town_rum_refill_jingle: AudioPlusSoundEffect {
-            audio_sources: vec![asset_library.audio_sfx_town_rum_refill_jingle.clone()],
-            volume: 1.0,
-            ..Default::default()
-        },
-        sfx_town_rum_refill_clank: AudioPlusSoundEffect {
-            audio_sources: vec![
-                asset_library.audio_sfx_town_rum_refill_clank_01.clone(),
-                asset_library.audio_sfx_town_rum_refill_clank_02.clone(),
-                asset_library.audio_sfx_town_rum_refill_clank_03.clone(),
-            ],
-            volume: 1.0,
-            ..Default::default()
-        },
-        sfx_town_upgrade: AudioPlusSoundEffect {
-            audio_sources: vec![asset_library.audio_sfx_upgrade_01.clone()],
-            volume: 1.0,
-            ..Default::default()
-        },
-        sfx_audio_preview: AudioPlusSoundEffect {
-            audio_sources: vec![asset_library.audio_sfx_preview.clone()],
-            volume: 1.0,
-            ..Default::default()
-        },
+// picks up edits to the definitions file, only sources spawned after the change hear it
+fn sound_effects_reload(
+    mut ev_asset: EventReader<AssetEvent<AudioPlusSoundEffects>>,
+    mut asset_library: ResMut<AssetLibrary>,
+    sound_effect_assets: Res<Assets<AudioPlusSoundEffects>>,
+) {
+    for event in ev_asset.iter() {
+        if let AssetEvent::Modified { handle } = event {
+            if *handle == asset_library.sound_effect_definitions {
+                asset_library.create_sound_effects(&sound_effect_assets);
+            }
+        }
     }
 }
+
+sound_effects!(
+    // placeholder
+    sfx_placeholder_music,
+    sfx_placeholder_sound,
+    // generic
+    sfx_dialogue_start,
+    sfx_dialogue_proceed,
+    sfx_dialogue_repeat,
+    sfx_dialogue_voice_jagerossa,
+    sfx_dialogue_voice_ringo,
+    sfx_dialogue_voice_plank,
+    sfx_dialogue_voice_davy,
+    sfx_dialogue_voice_governor,
+    sfx_dialogue_voice_barkeep,
+    sfx_audio_preview,
+    // music
+    sfx_music_stinger_quest_complete,
+    // main menu
+    sfx_menu_ambient,
+    sfx_menu_music,
+    sfx_menu_button_hover,
+    sfx_menu_button_click,
+    sfx_menu_button_click_confirm,
+    // cutscenes
+    sfx_cutscene_intro_music,
+    sfx_cutscene_intro1,
+    sfx_cutscene_intro2,
+    sfx_cutscene_intro3,
+    sfx_cutscene_intro4,
+    sfx_cutscene_intro5,
+    sfx_cutscene_outro_music,
+    sfx_cutscene_outro1,
+    sfx_cutscene_outro2,
+    sfx_cutscene_outro3,
+    // overworld
+    sfx_overworld_ambient,
+    sfx_overworld_music,
+    sfx_overworld_music_threat,
+    sfx_overworld_music_boss,
+    sfx_overworld_town_enter,
+    sfx_overworld_town_exit,
+    sfx_overworld_dash,
+    sfx_overworld_experience,
+    sfx_overworld_player_damage,
+    sfx_overworld_player_died,
+    sfx_overworld_enemy_damage,
+    sfx_overworld_map_open,
+    sfx_overworld_map_close,
+    sfx_overworld_level_up,
+    // attacks
+    sfx_overworld_attack_forward_cannons,
+    sfx_overworld_attack_shotgun_cannons,
+    sfx_overworld_attack_shockwave,
+    sfx_overworld_attack_bombs,
+    sfx_overworld_attack_bomb_throw,
+    sfx_overworld_attack_bomb_explode,
+    sfx_overworld_attack_kraken,
+    sfx_overworld_attack_tentacle,
+    // town
+    sfx_town_ambient,
+    sfx_town_music,
+    sfx_town_outside_hover,
+    sfx_town_outside_click,
+    sfx_town_rum_refill_jingle,
+    sfx_town_rum_refill_clank,
+    sfx_town_upgrade,
+);
@@ -0,0 +1,51 @@
+#[cfg(not(target_arch = "wasm32"))]
+const STORAGE_DIRECTORY: &str = "shanty-quest";
+
+#[cfg(target_arch = "wasm32")]
+const STORAGE_PREFIX: &str = "shanty-quest.";
+
+#[cfg(not(target_arch = "wasm32"))]
+fn storage_path(key: &str) -> Option<std::path::PathBuf> {
+    let mut path = dirs::config_dir()?;
+    path.push(STORAGE_DIRECTORY);
+    path.push(key);
+    Some(path)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub fn storage_read(key: &str) -> Option<String> {
+    std::fs::read_to_string(storage_path(key)?).ok()
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub fn storage_write(key: &str, value: &str) -> bool {
+    let Some(path) = storage_path(key) else { return false };
+    if let Some(parent) = path.parent() {
+        if std::fs::create_dir_all(parent).is_err() {
+            return false;
+        }
+    }
+    std::fs::write(path, value).is_ok()
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub fn storage_remove(key: &str) {
+    if let Some(path) = storage_path(key) {
+        let _ = std::fs::remove_file(path);
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+pub fn storage_read(key: &str) -> Option<String> {
+    super::wasm::local_storage_get(&format!("{}{}", STORAGE_PREFIX, key))
+}
+
+#[cfg(target_arch = "wasm32")]
+pub fn storage_write(key: &str, value: &str) -> bool {
+    super::wasm::local_storage_set(&format!("{}{}", STORAGE_PREFIX, key), value)
+}
+
+#[cfg(target_arch = "wasm32")]
+pub fn storage_remove(key: &str) {
+    super::wasm::local_storage_remove(&format!("{}{}", STORAGE_PREFIX, key))
+}
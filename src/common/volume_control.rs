@@ -13,18 +13,20 @@ impl Plugin for VolumeControlPlugin {
 fn volume_control(
     mut audio_mixer: ResMut<AudioPlusMixer>,
     input: Res<Input<KeyCode>>,
+    mouse: Res<Input<MouseButton>>,
+    mapping: Res<PlayerInputMapping>,
     mut commands: Commands,
     asset_library: Res<AssetLibrary>,
 ) {
     let volume = audio_mixer.get_master_volume();
     let mut play_sample = false;
-    if input.just_pressed(KeyCode::P) {
+    if mapping.just_pressed(InputAction::VolumeUp, &input, &mouse) {
         audio_mixer.set_master_volume(volume + 0.1);
         if audio_mixer.get_master_volume() != volume {
             play_sample = true;
         }
     }
-    if input.just_pressed(KeyCode::O) {
+    if mapping.just_pressed(InputAction::VolumeDown, &input, &mouse) {
         audio_mixer.set_master_volume((volume - 0.1).max(0.1));
         if audio_mixer.get_master_volume() != volume {
             play_sample = true;
@@ -29,3 +29,29 @@ fn wasm_fullscreen(mut window_query: Query<&mut Window>) {
         window.resolution.set(width, height);
     }
 }
+
+#[cfg(target_arch = "wasm32")]
+fn local_storage() -> Option<web_sys::Storage> {
+    web_sys::window()?.local_storage().ok()?
+}
+
+#[cfg(target_arch = "wasm32")]
+pub fn local_storage_get(key: &str) -> Option<String> {
+    local_storage()?.get_item(key).ok()?
+}
+
+#[cfg(target_arch = "wasm32")]
+pub fn local_storage_set(key: &str, value: &str) -> bool {
+    if let Some(storage) = local_storage() {
+        storage.set_item(key, value).is_ok()
+    } else {
+        false
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+pub fn local_storage_remove(key: &str) {
+    if let Some(storage) = local_storage() {
+        let _ = storage.remove_item(key);
+    }
+}
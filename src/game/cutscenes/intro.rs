@@ -15,7 +15,7 @@ impl Plugin for IntroCutscenePlugin {
             .add_cutscene::<IntroCutscene>()
             .add_system(init.in_schedule(OnEnter(AppState::IntroCutscene)))
             .add_system(skip.in_set(OnUpdate(AppState::IntroCutscene)))
-            .add_system(image_move.in_set(OnUpdate(AppState::IntroCutscene)));
+            .add_system(finished.in_set(OnUpdate(AppState::IntroCutscene)));
     }
 }
 
@@ -25,29 +25,80 @@ pub struct IntroCutscene;
 impl Cutscene for IntroCutscene {
     fn build(cutscene: &mut CutsceneBuilder) {
         cutscene.add_timed_step(step1_fade_in, 0.0); // 1.5s
-        cutscene.add_timed_step(step1_image, 12.75);
-        cutscene.add_timed_step(step1_fade_out, 2.0); // 2.5s
-
-        cutscene.add_timed_step(step2_start_audio, 0.5); // start audio during fade out
+        cutscene.add_parallel(|parallel| {
+            parallel.add_chain(|chain| {
+                chain.add_timed_step(step1_image, 12.75);
+                chain.add_timed_step(step1_fade_out, 2.0); // 2.5s
+                chain.add_timed_step(step2_start_audio, 0.5); // start audio during fade out
+            });
+            parallel.add_chain(|chain| {
+                chain.add_tween::<CutsceneImage<1>>(
+                    Tween::Translation(Vec2::new(200., -50.), Vec2::new(108.5, -141.5)),
+                    Easing::Linear,
+                    15.25,
+                );
+            });
+        });
 
         cutscene.add_timed_step(step2_fade_in, 0.0); // 2.5sec
-        cutscene.add_timed_step(step2_image, 9.5);
-
-        cutscene.add_timed_step(step2_fade_out, 1.0); //1s fade, Runs near "Find them they did!"
+        cutscene.add_parallel(|parallel| {
+            parallel.add_chain(|chain| {
+                chain.add_timed_step(step2_image, 9.5);
+                chain.add_timed_step(step2_fade_out, 1.0); //1s fade, Runs near "Find them they did!"
+            });
+            parallel.add_chain(|chain| {
+                chain.add_tween::<CutsceneImage<2>>(
+                    Tween::Translation(Vec2::new(245., 145.), Vec2::new(35., 82.)),
+                    Easing::Linear,
+                    10.5,
+                );
+            });
+        });
 
         cutscene.add_timed_step(step3_fade_in, 0.0); // 1.5sec
-        cutscene.add_timed_step(step3_image, 2.0);
-
-        cutscene.add_timed_step(step3_start_audio, 15.75);
-        cutscene.add_timed_step(step3_fade_out, 1.5); //1..5s
-
-        cutscene.add_timed_step(step4, 14.5);
-        cutscene.add_timed_step(step4_fade_out, 1.5); //1..5s
+        cutscene.add_parallel(|parallel| {
+            parallel.add_chain(|chain| {
+                chain.add_timed_step(step3_image, 2.0);
+                chain.add_timed_step(step3_start_audio, 15.75);
+                chain.add_timed_step(step3_fade_out, 1.5); //1..5s
+            });
+            parallel.add_chain(|chain| {
+                chain.add_tween::<CutsceneImage<3>>(
+                    Tween::Translation(Vec2::new(0., -100.), Vec2::new(0., -23.)),
+                    Easing::Linear,
+                    19.25,
+                );
+            });
+        });
+
+        cutscene.add_parallel(|parallel| {
+            parallel.add_chain(|chain| {
+                chain.add_timed_step(step4, 14.5);
+                chain.add_timed_step(step4_fade_out, 1.5); //1..5s
+            });
+            parallel.add_chain(|chain| {
+                chain.add_tween::<CutsceneImage<4>>(
+                    Tween::Translation(Vec2::new(0., 180.), Vec2::new(0., -60.)),
+                    Easing::Linear,
+                    16.,
+                );
+            });
+        });
 
         cutscene.add_timed_step(reset, 0.5);
-        cutscene.add_timed_step(step5, 10.5);
-        cutscene.add_timed_step(end, 1.0);
-        cutscene.add_quick_step(cleanup);
+        cutscene.add_parallel(|parallel| {
+            parallel.add_chain(|chain| {
+                chain.add_timed_step(step5, 10.5);
+                chain.add_timed_step(end, 1.0);
+            });
+            parallel.add_chain(|chain| {
+                chain.add_tween::<CutsceneImage<5>>(
+                    Tween::Translation(Vec2::new(-150., -100.), Vec2::new(-58., -31.)),
+                    Easing::Linear,
+                    11.5,
+                );
+            });
+        });
     }
 }
 
@@ -55,9 +106,7 @@ impl Cutscene for IntroCutscene {
 struct CutsceneText;
 
 #[derive(Component)]
-struct CutsceneImage {
-    velocity: Vec2,
-}
+struct CutsceneImage<const INDEX: usize>;
 
 fn init(
     mut cutscene_state: ResMut<IntroCutsceneState>,
@@ -89,10 +138,6 @@ fn init(
         Transform2::from_xy(0., -300.).with_depth((DepthLayer::Front, 1.)),
         CutsceneText,
     ));
-    commands.spawn(
-        AudioPlusSource::new(asset_library.sound_effects.sfx_cutscene_intro_music.clone())
-            .as_looping(),
-    );
 }
 
 fn skip(
@@ -101,7 +146,8 @@ fn skip(
     mouse: Res<Input<MouseButton>>,
     mut screen_fade: ResMut<ScreenFade>,
     mut ev_cutscene_skip: EventWriter<CutsceneSkipEvent<IntroCutscene>>,
-    mut query: Query<&mut AudioPlusSource>,
+    mut query: Query<&mut AudioPlusSource, Without<AudioPlusMusicLayer>>,
+    mut music_director: ResMut<AudioPlusMusicDirector>,
     mut text_query: Query<&mut Text, With<CutsceneText>>,
 ) {
     if input.just_pressed(KeyCode::Space) || mouse.just_pressed(MouseButton::Left) {
@@ -111,6 +157,7 @@ fn skip(
             }
             cutscene_state.proceed = true;
             screen_fade.fade_out(1.);
+            music_director.clear_state();
             for mut source in query.iter_mut() {
                 source.stop();
             }
@@ -121,12 +168,6 @@ fn skip(
     }
 }
 
-fn image_move(mut query: Query<(&mut Transform2, &CutsceneImage)>, time: Res<Time>) {
-    for (mut transform, image) in query.iter_mut() {
-        transform.translation += image.velocity * time.delta_seconds();
-    }
-}
-
 fn reset(mut screen_fade: ResMut<ScreenFade>, state: Res<IntroCutsceneState>) {
     if !state.proceed {
         screen_fade.fade_out(0.5);
@@ -136,11 +177,13 @@ fn reset(mut screen_fade: ResMut<ScreenFade>, state: Res<IntroCutsceneState>) {
 fn end(
     mut screen_fade: ResMut<ScreenFade>,
     state: Res<IntroCutsceneState>,
-    mut query: Query<&mut AudioPlusSource>,
+    mut query: Query<&mut AudioPlusSource, Without<AudioPlusMusicLayer>>,
+    mut music_director: ResMut<AudioPlusMusicDirector>,
 ) {
     if !state.proceed {
         screen_fade.fade_out(1.0);
     }
+    music_director.clear_state();
     for mut source in query.iter_mut() {
         source.stop();
     }
@@ -154,13 +197,14 @@ fn step1_fade_in(mut screen_fade: ResMut<ScreenFade>, state: Res<IntroCutsceneSt
 
 fn step1_image(
     mut query: Query<&mut Text, With<CutsceneText>>,
+    locale: Res<Locale>,
     mut commands: Commands,
     asset_library: Res<AssetLibrary>,
     cutscenes: Res<Cutscenes>,
 ) {
     if !cutscenes.skipping() {
         if let Ok(mut text) = query.get_single_mut() {
-            text.sections[0].value = "Well, ya oiled me tongue with a jug o' rum so lemme tell ya the story of treble at sea! Eh? How the Pirate Lords became... Lords?!".to_owned();
+            text.sections[0].value = locale.get("cutscene.intro1").to_owned();
         }
         commands.spawn((
             SpriteBundle {
@@ -171,9 +215,7 @@ fn step1_image(
                 .with_scale(Vec2::ONE * 0.65)
                 .with_depth((DepthLayer::Entity, 0.0))
                 .without_pixel_perfect(),
-            CutsceneImage {
-                velocity: Vec2::new(-6., -6.),
-            },
+            CutsceneImage::<1>,
             AudioPlusSource::new(asset_library.sound_effects.sfx_cutscene_intro1.clone())
                 .as_playing(),
         ));
@@ -188,14 +230,14 @@ fn step1_fade_out(mut screen_fade: ResMut<ScreenFade>, state: Res<IntroCutsceneS
 
 fn step2_start_audio(
     mut query: Query<&mut Text, With<CutsceneText>>,
+    locale: Res<Locale>,
     cutscenes: Res<Cutscenes>,
     asset_library: Res<AssetLibrary>,
     mut commands: Commands,
 ) {
     if !cutscenes.skipping() {
         if let Ok(mut text) = query.get_single_mut() {
-            text.sections[0].value =
-            "Royal Navy beat those scurvy dogs 'gain and again! Driven them to seek Rockdorado and the fabled lost weapons... Find them they did!".to_owned();
+            text.sections[0].value = locale.get("cutscene.intro2").to_owned();
         }
         commands.spawn(
             AudioPlusSource::new(asset_library.sound_effects.sfx_cutscene_intro2.clone())
@@ -225,9 +267,7 @@ fn step2_image(
                 .with_scale(Vec2::ONE * 0.44)
                 .with_depth((DepthLayer::Entity, 0.1))
                 .without_pixel_perfect(),
-            CutsceneImage {
-                velocity: Vec2::new(-20., -6.),
-            },
+            CutsceneImage::<2>,
         ));
     }
 }
@@ -259,23 +299,21 @@ fn step3_image(
                 .with_scale(Vec2::ONE * 0.4)
                 .with_depth((DepthLayer::Entity, 0.3))
                 .without_pixel_perfect(),
-            CutsceneImage {
-                velocity: Vec2::new(0., 4.),
-            },
+            CutsceneImage::<3>,
         ));
     }
 }
 
 fn step3_start_audio(
     mut query: Query<&mut Text, With<CutsceneText>>,
+    locale: Res<Locale>,
     mut commands: Commands,
     asset_library: Res<AssetLibrary>,
     cutscenes: Res<Cutscenes>,
 ) {
     if !cutscenes.skipping() {
         if let Ok(mut text) = query.get_single_mut() {
-            text.sections[0].value =
-            "Each Cap'n grabbed an instrument!\nHah. Gave 'em terrible powers of horrid noise, magical projectiles, power over sea monsters! With that, they smashed the Royal Navy ta bits!".to_owned();
+            text.sections[0].value = locale.get("cutscene.intro3").to_owned();
         }
         commands.spawn(
             AudioPlusSource::new(asset_library.sound_effects.sfx_cutscene_intro3.clone())
@@ -292,6 +330,7 @@ fn step3_fade_out(mut screen_fade: ResMut<ScreenFade>, state: Res<IntroCutsceneS
 
 fn step4(
     mut query: Query<&mut Text, With<CutsceneText>>,
+    locale: Res<Locale>,
     mut commands: Commands,
     mut screen_fade: ResMut<ScreenFade>,
     state: Res<IntroCutsceneState>,
@@ -303,8 +342,7 @@ fn step4(
     }
     if !cutscenes.skipping() {
         if let Ok(mut text) = query.get_single_mut() {
-            text.sections[0].value =
-            "But, right as rum, pirates be pirates. Each Lord wished to get more powa, to get all other instruments! That's how this Pirate Lords War started...".to_owned();
+            text.sections[0].value = locale.get("cutscene.intro4").to_owned();
         }
         commands.spawn((
             SpriteBundle {
@@ -315,9 +353,7 @@ fn step4(
                 .with_scale(Vec2::ONE * 0.42)
                 .with_depth((DepthLayer::Entity, 0.4))
                 .without_pixel_perfect(),
-            CutsceneImage {
-                velocity: Vec2::new(0., -15.),
-            },
+            CutsceneImage::<4>,
             AudioPlusSource::new(asset_library.sound_effects.sfx_cutscene_intro4.clone())
                 .as_playing(),
         ));
@@ -332,6 +368,7 @@ fn step4_fade_out(mut screen_fade: ResMut<ScreenFade>, state: Res<IntroCutsceneS
 
 fn step5(
     mut query: Query<&mut Text, With<CutsceneText>>,
+    locale: Res<Locale>,
     mut commands: Commands,
     mut screen_fade: ResMut<ScreenFade>,
     state: ResMut<IntroCutsceneState>,
@@ -343,8 +380,7 @@ fn step5(
     }
     if !cutscenes.skipping() {
         if let Ok(mut text) = query.get_single_mut() {
-            text.sections[0].value =
-            "That's why the rum ships sail less and less... Now! Buy me another jug or I'll yapper no more tales.".to_owned();
+            text.sections[0].value = locale.get("cutscene.intro5").to_owned();
         }
         commands.spawn((
             SpriteBundle {
@@ -355,15 +391,18 @@ fn step5(
                 .with_scale(Vec2::ONE * 0.65)
                 .with_depth((DepthLayer::Entity, 0.5))
                 .without_pixel_perfect(),
-            CutsceneImage {
-                velocity: Vec2::new(8., 6.),
-            },
+            CutsceneImage::<5>,
             AudioPlusSource::new(asset_library.sound_effects.sfx_cutscene_intro5.clone())
                 .as_playing(),
         ));
     }
 }
 
-fn cleanup(mut app_state: ResMut<NextState<AppState>>) {
-    app_state.set(AppState::Overworld);
+fn finished(
+    mut ev_finished: EventReader<CutsceneFinishedEvent<IntroCutscene>>,
+    mut app_state: ResMut<NextState<AppState>>,
+) {
+    if ev_finished.iter().last().is_some() {
+        app_state.set(AppState::Overworld);
+    }
 }
@@ -17,7 +17,7 @@ impl Plugin for OutroCutscenePlugin {
             .add_cutscene::<OutroCutscene>()
             .add_system(init.in_schedule(OnEnter(AppState::OutroCutscene)))
             .add_system(skip.in_set(OnUpdate(AppState::OutroCutscene)))
-            .add_system(image_move.in_set(OnUpdate(AppState::OutroCutscene)));
+            .add_system(finished.in_set(OnUpdate(AppState::OutroCutscene)));
     }
 }
 
@@ -26,15 +26,48 @@ pub struct OutroCutscene;
 
 impl Cutscene for OutroCutscene {
     fn build(cutscene: &mut CutsceneBuilder) {
-        cutscene.add_timed_step(step1, 12.5); // 13s
-        cutscene.add_timed_step(step1_fade_out, 0.5); // 1.5s fade
-        cutscene.add_timed_step(step2_start_audio, 1.0); // start audio during fade out
+        cutscene.add_parallel(|parallel| {
+            parallel.add_chain(|chain| {
+                chain.add_timed_step(step1, 12.5); // 13s
+                chain.add_timed_step(step1_fade_out, 0.5); // 1.5s fade
+                chain.add_timed_step(step2_start_audio, 1.0); // start audio during fade out
+            });
+            parallel.add_chain(|chain| {
+                chain.add_tween::<CutsceneImage<1>>(
+                    Tween::Translation(Vec2::new(200., -50.), Vec2::new(116., -134.)),
+                    Easing::Linear,
+                    14.,
+                );
+            });
+        });
 
-        cutscene.add_timed_step(step2, 8.5); // 9s
-        cutscene.add_timed_step(step2_fade_out, 1.0);
-        cutscene.add_timed_step(step3, 9.); // 9
-        cutscene.add_timed_step(end, 1.0);
-        cutscene.add_quick_step(cleanup);
+        cutscene.add_parallel(|parallel| {
+            parallel.add_chain(|chain| {
+                chain.add_timed_step(step2, 8.5); // 9s
+                chain.add_timed_step(step2_fade_out, 1.0);
+            });
+            parallel.add_chain(|chain| {
+                chain.add_tween::<CutsceneImage<2>>(
+                    Tween::Translation(Vec2::new(0., -50.), Vec2::new(0., -192.5)),
+                    Easing::Linear,
+                    9.5,
+                );
+            });
+        });
+
+        cutscene.add_parallel(|parallel| {
+            parallel.add_chain(|chain| {
+                chain.add_timed_step(step3, 9.); // 9
+                chain.add_timed_step(end, 1.0);
+            });
+            parallel.add_chain(|chain| {
+                chain.add_tween::<CutsceneImage<3>>(
+                    Tween::Translation(Vec2::new(-150., -100.), Vec2::new(-70., -40.)),
+                    Easing::Linear,
+                    10.,
+                );
+            });
+        });
     }
 }
 
@@ -42,9 +75,7 @@ impl Cutscene for OutroCutscene {
 struct CutsceneText;
 
 #[derive(Component)]
-struct CutsceneImage {
-    velocity: Vec2,
-}
+struct CutsceneImage<const INDEX: usize>;
 
 fn init(
     mut cutscene_state: ResMut<OutroCutsceneState>,
@@ -76,10 +107,6 @@ fn init(
         Transform2::from_xy(0., -300.).with_depth((DepthLayer::Front, 1.)),
         CutsceneText,
     ));
-    commands.spawn(
-        AudioPlusSource::new(asset_library.sound_effects.sfx_cutscene_outro_music.clone())
-            .as_looping(),
-    );
 }
 
 fn skip(
@@ -88,7 +115,8 @@ fn skip(
     mouse: Res<Input<MouseButton>>,
     mut screen_fade: ResMut<ScreenFade>,
     mut ev_cutscene_skip: EventWriter<CutsceneSkipEvent<OutroCutscene>>,
-    mut query: Query<&mut AudioPlusSource>,
+    mut query: Query<&mut AudioPlusSource, Without<AudioPlusMusicLayer>>,
+    mut music_director: ResMut<AudioPlusMusicDirector>,
     mut text_query: Query<&mut Text, With<CutsceneText>>,
 ) {
     if input.just_pressed(KeyCode::Space) || mouse.just_pressed(MouseButton::Left) {
@@ -98,6 +126,7 @@ fn skip(
             }
             cutscene_state.proceed = true;
             screen_fade.fade_out(1.);
+            music_director.clear_state();
             for mut source in query.iter_mut() {
                 source.stop();
             }
@@ -108,20 +137,16 @@ fn skip(
     }
 }
 
-fn image_move(mut query: Query<(&mut Transform2, &CutsceneImage)>, time: Res<Time>) {
-    for (mut transform, image) in query.iter_mut() {
-        transform.translation += image.velocity * time.delta_seconds();
-    }
-}
-
 fn end(
     mut screen_fade: ResMut<ScreenFade>,
     state: Res<OutroCutsceneState>,
-    mut query: Query<&mut AudioPlusSource>,
+    mut query: Query<&mut AudioPlusSource, Without<AudioPlusMusicLayer>>,
+    mut music_director: ResMut<AudioPlusMusicDirector>,
 ) {
     if !state.proceed {
         screen_fade.fade_out(1.0);
     }
+    music_director.clear_state();
     for mut source in query.iter_mut() {
         source.stop();
     }
@@ -129,6 +154,7 @@ fn end(
 
 fn step1(
     mut query: Query<&mut Text, With<CutsceneText>>,
+    locale: Res<Locale>,
     mut commands: Commands,
     mut screen_fade: ResMut<ScreenFade>,
     state: Res<OutroCutsceneState>,
@@ -140,7 +166,7 @@ fn step1(
     }
     if !cutscenes.skipping() {
         if let Ok(mut text) = query.get_single_mut() {
-            text.sections[0].value = "And there ya go, laddie! That is when the sea trebled! When a Pirate Lord combined all the magical instruments and became a Pirate King!".to_owned();
+            text.sections[0].value = locale.get("cutscene.outro1").to_owned();
         }
         commands.spawn((
             SpriteBundle {
@@ -151,9 +177,7 @@ fn step1(
                 .with_scale(Vec2::ONE * 0.65)
                 .with_depth((DepthLayer::Entity, 0.0))
                 .without_pixel_perfect(),
-            CutsceneImage {
-                velocity: Vec2::new(-6., -6.),
-            },
+            CutsceneImage::<1>,
             AudioPlusSource::new(asset_library.sound_effects.sfx_cutscene_outro1.clone())
                 .as_playing(),
         ));
@@ -167,15 +191,14 @@ fn step1_fade_out(mut screen_fade: ResMut<ScreenFade>, state: Res<OutroCutsceneS
 
 fn step2_start_audio(
     mut query: Query<&mut Text, With<CutsceneText>>,
+    locale: Res<Locale>,
     cutscenes: Res<Cutscenes>,
     asset_library: Res<AssetLibrary>,
     mut commands: Commands,
 ) {
     if !cutscenes.skipping() {
         if let Ok(mut text) = query.get_single_mut() {
-            text.sections[0].value =
-                "Now he be raiding the coast with the most horrible noise known to mankind..."
-                    .to_owned();
+            text.sections[0].value = locale.get("cutscene.outro2").to_owned();
         }
         commands.spawn(
             AudioPlusSource::new(asset_library.sound_effects.sfx_cutscene_outro2.clone())
@@ -204,9 +227,7 @@ fn step2(
                 .with_scale(Vec2::ONE * 0.42)
                 .with_depth((DepthLayer::Entity, 0.4))
                 .without_pixel_perfect(),
-            CutsceneImage {
-                velocity: Vec2::new(0., -15.),
-            },
+            CutsceneImage::<2>,
         ));
     }
 }
@@ -219,6 +240,7 @@ fn step2_fade_out(mut screen_fade: ResMut<ScreenFade>, state: Res<OutroCutsceneS
 
 fn step3(
     mut query: Query<&mut Text, With<CutsceneText>>,
+    locale: Res<Locale>,
     mut commands: Commands,
     mut screen_fade: ResMut<ScreenFade>,
     state: Res<OutroCutsceneState>,
@@ -230,9 +252,7 @@ fn step3(
     }
     if !cutscenes.skipping() {
         if let Ok(mut text) = query.get_single_mut() {
-            text.sections[0].value =
-            "Buy Ol' Nipper here another jug o' rum and I'll yapper until the sunrise! Har-har!"
-                .to_owned();
+            text.sections[0].value = locale.get("cutscene.outro3").to_owned();
         }
         commands.spawn((
             SpriteBundle {
@@ -243,20 +263,21 @@ fn step3(
                 .with_scale(Vec2::ONE * 0.65)
                 .with_depth((DepthLayer::Entity, 0.5))
                 .without_pixel_perfect(),
-            CutsceneImage {
-                velocity: Vec2::new(8., 6.),
-            },
+            CutsceneImage::<3>,
             AudioPlusSource::new(asset_library.sound_effects.sfx_cutscene_outro3.clone())
                 .as_playing(),
         ));
     }
 }
 
-fn cleanup(
+fn finished(
+    mut ev_finished: EventReader<CutsceneFinishedEvent<OutroCutscene>>,
     mut app_state: ResMut<NextState<AppState>>,
     mut game_state: ResMut<GameState>,
     world_locations: Res<WorldLocations>,
 ) {
-    game_state.town = TownData::build("Republic of Roll", world_locations.as_ref());
-    app_state.set(AppState::TownOutside);
+    if ev_finished.iter().last().is_some() {
+        game_state.town = TownData::build("Republic of Roll", world_locations.as_ref());
+        app_state.set(AppState::TownOutside);
+    }
 }
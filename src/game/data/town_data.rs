@@ -1,5 +1,6 @@
 use crate::common::prelude::*;
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
 pub const TOWN_NAMES: [&str; 15] = [
     "Portallica",
@@ -23,7 +24,11 @@ pub fn town_safe_name(input: &str) -> String {
     input.replace(" ", "_").replace("'", "_")
 }
 
-#[derive(Clone, Debug)]
+pub fn town_locale_id(input: &str) -> String {
+    format!("town.{}", town_safe_name(input).to_lowercase())
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct TownData {
     pub name: String,
     pub position: Vec2,
@@ -44,11 +44,7 @@ fn town_init(
     commands.spawn((
         Text2dBundle {
             text: Text::from_section(
-                if state.can_respawn {
-                    "Yer swimmin' with the fishes now\nLeft click to respawn at last town"
-                } else {
-                    "Yer swimmin' with the fishes now\nLeft click to restart"
-                },
+                "",
                 TextStyle {
                     font: asset_library.font_bold.clone(),
                     font_size: 42.0,
@@ -60,6 +56,11 @@ fn town_init(
             ..Default::default()
         },
         Transform2::from_xy(0., -175.).with_depth(DEPTH_LAYER_DEATH_SCREEN),
+        LocalizedText::new(if state.can_respawn {
+            "dead.respawn"
+        } else {
+            "dead.restart"
+        }),
     ));
 }
 
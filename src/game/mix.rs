@@ -0,0 +1,86 @@
+use crate::common::prelude::*;
+use crate::game::{overworld::ui::pause::Pause, prelude::*};
+use audio_plus::prelude::*;
+use bevy::prelude::*;
+
+const MIX_TRANSITION: f32 = 0.6;
+
+pub struct MixPlugin;
+
+impl Plugin for MixPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_startup_system(mix_init)
+            .add_system(mix_snapshot)
+            .add_system(mix_ducking);
+    }
+}
+
+fn mix_init(mut buses: ResMut<AudioPlusBuses>) {
+    buses.add_snapshot("Overworld", AudioPlusMixSnapshot::new());
+    buses.add_snapshot(
+        "Town",
+        AudioPlusMixSnapshot::new()
+            .with_volume(AudioPlusMixerChannel::Music, 0.8)
+            .with_volume(AudioPlusMixerChannel::Ambience, 0.7)
+            .with_reverb_send(AudioPlusMixerChannel::Voice, 0.3)
+            .with_reverb_send(AudioPlusMixerChannel::Sfx, 0.2),
+    );
+    buses.add_snapshot(
+        "Cutscene",
+        AudioPlusMixSnapshot::new()
+            .with_volume(AudioPlusMixerChannel::Music, 0.8)
+            .with_volume(AudioPlusMixerChannel::Sfx, 0.7)
+            .with_volume(AudioPlusMixerChannel::Ambience, 0.6),
+    );
+    buses.add_snapshot(
+        "Paused",
+        AudioPlusMixSnapshot::new()
+            .with_volume(AudioPlusMixerChannel::Music, 0.5)
+            .with_volume(AudioPlusMixerChannel::Sfx, 0.3)
+            .with_volume(AudioPlusMixerChannel::Ambience, 0.4)
+            .with_volume(AudioPlusMixerChannel::Voice, 0.5),
+    );
+    buses.add_ducker(
+        "dialogue",
+        AudioPlusDucker::new(0.5)
+            .with_channel(AudioPlusMixerChannel::Music)
+            .with_channel(AudioPlusMixerChannel::Ambience)
+            .with_attack(0.3)
+            .with_release(0.8),
+    );
+    // driven by the sidechain on sfx_overworld_player_damage
+    buses.add_ducker(
+        "damage",
+        AudioPlusDucker::new(0.6)
+            .with_channel(AudioPlusMixerChannel::Music)
+            .with_attack(0.05)
+            .with_release(0.6),
+    );
+}
+
+fn mix_snapshot(
+    app_state: Res<State<AppState>>,
+    cutscenes: Res<Cutscenes>,
+    pause_query: Query<(), With<Pause>>,
+    mut buses: ResMut<AudioPlusBuses>,
+) {
+    let snapshot = if !pause_query.is_empty() {
+        Some("Paused")
+    } else {
+        match app_state.0 {
+            AppState::IntroCutscene | AppState::OutroCutscene => Some("Cutscene"),
+            AppState::Overworld if cutscenes.running() => Some("Cutscene"),
+            AppState::Overworld => Some("Overworld"),
+            _ if app_state.0.is_town() => Some("Town"),
+            _ => None,
+        }
+    };
+    match snapshot {
+        Some(snapshot) => buses.set_snapshot(snapshot, MIX_TRANSITION),
+        None => buses.clear_snapshot(MIX_TRANSITION),
+    }
+}
+
+fn mix_ducking(dialogue: Res<Dialogue>, mut buses: ResMut<AudioPlusBuses>) {
+    buses.set_ducking("dialogue", dialogue.visible());
+}
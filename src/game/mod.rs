@@ -9,16 +9,21 @@ impl Plugin for GamePlugin {
             .add_plugin(overworld::OverworldPlugin)
             .add_plugin(town::TownPlugin)
             .add_plugin(quests::QuestsPlugin)
-            .add_plugin(dead::DeadPlugin);
+            .add_plugin(save::SavePlugin)
+            .add_plugin(dead::DeadPlugin)
+            .add_plugin(music::MusicPlugin)
+            .add_plugin(mix::MixPlugin);
     }
 }
 
-pub mod all_dialogue;
 pub mod cutscenes;
 pub mod data;
 pub mod dead;
+pub mod mix;
+pub mod music;
 pub mod overworld;
 pub mod prelude;
 pub mod quests;
+pub mod save;
 pub mod state;
 pub mod town;
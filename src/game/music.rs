@@ -0,0 +1,148 @@
+use crate::common::{label::Label, prelude::*};
+use crate::game::prelude::*;
+use audio_plus::prelude::*;
+use bevy::prelude::*;
+
+const MUSIC_BPM: f32 = 120.;
+
+pub struct MusicPlugin;
+
+impl Plugin for MusicPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_startup_system(music_spawn)
+            .add_system(music_tracks)
+            .add_system(music_state.after(music_tracks))
+            .add_system(music_intensity)
+            .add_system(music_quest_stinger);
+    }
+}
+
+fn music_spawn(mut commands: Commands) {
+    commands.spawn((AudioPlusMusic, Persistent, Label("Music".to_owned())));
+}
+
+// the sound effects are recreated whenever their definitions reload
+fn music_tracks(
+    asset_library: Res<AssetLibrary>,
+    mut music_director: ResMut<AudioPlusMusicDirector>,
+) {
+    if !asset_library.is_changed() {
+        return;
+    }
+    let sound_effects = &asset_library.sound_effects;
+    let tracks = [
+        (
+            "menu",
+            AudioPlusMusicTrack::new(MUSIC_BPM)
+                .with_layer(sound_effects.sfx_menu_music.clone(), 0.),
+        ),
+        (
+            "intro",
+            AudioPlusMusicTrack::new(MUSIC_BPM)
+                .with_layer(sound_effects.sfx_cutscene_intro_music.clone(), 0.),
+        ),
+        (
+            "outro",
+            AudioPlusMusicTrack::new(MUSIC_BPM)
+                .with_layer(sound_effects.sfx_cutscene_outro_music.clone(), 0.),
+        ),
+        (
+            "overworld",
+            AudioPlusMusicTrack::new(MUSIC_BPM)
+                .with_layer(sound_effects.sfx_overworld_music.clone(), 0.)
+                .with_layer(sound_effects.sfx_overworld_music_threat.clone(), 0.5),
+        ),
+        (
+            "boss",
+            AudioPlusMusicTrack::new(MUSIC_BPM)
+                .with_layer(sound_effects.sfx_overworld_music_boss.clone(), 0.),
+        ),
+        (
+            "town",
+            AudioPlusMusicTrack::new(MUSIC_BPM)
+                .with_layer(sound_effects.sfx_town_music.clone(), 0.),
+        ),
+    ];
+    for (name, track) in tracks {
+        music_director.add_track(name, track);
+        music_director.add_state(name, name);
+    }
+}
+
+// only pushes the state when it changes so cutscenes can clear the music while they fade out
+fn music_state(
+    app_state: Res<State<AppState>>,
+    game_state: Res<GameState>,
+    mut music_director: ResMut<AudioPlusMusicDirector>,
+    mut last_state: Local<Option<Option<&'static str>>>,
+) {
+    let state = match app_state.0 {
+        AppState::Loading => None,
+        AppState::MainMenu => Some("menu"),
+        AppState::IntroCutscene => Some("intro"),
+        AppState::OutroCutscene => Some("outro"),
+        AppState::Overworld => {
+            if game_state.quests.fighting() {
+                Some("boss")
+            } else {
+                Some("overworld")
+            }
+        }
+        AppState::TownOutside
+        | AppState::TownTavern
+        | AppState::TownMayor
+        | AppState::TownConcertHall => Some("town"),
+        AppState::Dead => None,
+    };
+    if *last_state == Some(state) {
+        return;
+    }
+    *last_state = Some(state);
+    match state {
+        Some(state) => music_director.set_state(state),
+        None => music_director.clear_state(),
+    }
+}
+
+fn music_intensity(
+    threat_level: Res<ThreatLevel>,
+    mut music_director: ResMut<AudioPlusMusicDirector>,
+) {
+    let intensity = match *threat_level {
+        ThreatLevel::None => 0.,
+        ThreatLevel::Easy => 0.25,
+        ThreatLevel::Medium => 0.5,
+        ThreatLevel::Hard => 0.75,
+        ThreatLevel::Midnight | ThreatLevel::Davy => 1.,
+    };
+    if music_director.intensity() != intensity {
+        music_director.set_intensity(intensity);
+    }
+}
+
+fn music_quest_stinger(
+    app_state: Res<State<AppState>>,
+    game_state: Res<GameState>,
+    asset_library: Res<AssetLibrary>,
+    mut music_director: ResMut<AudioPlusMusicDirector>,
+    mut last_completed: Local<Option<usize>>,
+) {
+    // loading a save shouldn't count as completing its quests
+    if !matches!(app_state.0, AppState::Overworld) && !app_state.0.is_town() {
+        *last_completed = None;
+        return;
+    }
+    let quests = &game_state.quests;
+    let completed = quests.completed_quests().len() + quests.completed_side_quests().count();
+    if let Some(last_completed) = *last_completed {
+        if completed > last_completed {
+            music_director.play_stinger(
+                asset_library
+                    .sound_effects
+                    .sfx_music_stinger_quest_complete
+                    .clone(),
+            );
+        }
+    }
+    *last_completed = Some(completed);
+}
@@ -33,6 +33,7 @@ impl BombsLevel {
                 spawn_amount: 3,
                 velocity_min: 50.,
                 velocity_max: 1500.,
+                crit: DamageCrit::NONE,
             }
         } else {
             BombsStats {
@@ -41,6 +42,7 @@ impl BombsLevel {
                 spawn_amount: self.0,
                 velocity_min: 200.,
                 velocity_max: 500.,
+                crit: DamageCrit::new(0.05 + self.0 as f32 * 0.02, 2.),
             }
         }
     }
@@ -53,6 +55,7 @@ struct BombsStats {
     spawn_amount: u32,
     velocity_min: f32,
     velocity_max: f32,
+    crit: DamageCrit,
 }
 
 #[derive(Component)]
@@ -72,7 +75,8 @@ fn bombs_fire(
 ) {
     for (boat_entity, mut bombs, boat, global_transform) in query.iter_mut() {
         if bombs.shoot {
-            let stats = bombs.level.stats();
+            let mut stats = bombs.level.stats();
+            stats.crit = stats.crit.for_hurt_flags(bombs.hurt_flags);
             commands.spawn((
                 Transform2Bundle {
                     transform2: Transform2::from_translation(
@@ -158,9 +162,7 @@ fn bomb_move(
                 Transform2::from_translation(global_transform.translation().truncate())
                     .with_depth((DepthLayer::Entity, 0.)),
                 Hurtbox {
-                    shape: CollisionShape::Rect {
-                        size: Vec2::new(180., 180.),
-                    },
+                    shape: CollisionShape::Circle { radius: 90. },
                     for_entity: Some(bomb.parent),
                     auto_despawn: false,
                     flags: bomb.hurt_flags,
@@ -168,6 +170,9 @@ fn bomb_move(
                         bomb.stats.knockback_intensity,
                     ),
                     damage: bomb.stats.damage,
+                    damage_type: DamageType::Explosion,
+                    status: Some(StatusEffect::burning(3., bomb.stats.damage * 0.2)),
+                    crit: bomb.stats.crit,
                 },
                 YDepth::default(),
                 TimeToLive { seconds: 0.05 },
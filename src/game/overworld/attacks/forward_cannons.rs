@@ -31,6 +31,7 @@ impl ForwardCannonsLevel {
             speed: 1200. + level * 100.,
             hit_multiple: self.0 >= 5,
             knockback_intensity: if self.0 >= 5 { 0.004 } else { 0.0075 },
+            crit: DamageCrit::new(0.05 + level * 0.03, 2.),
         }
     }
 }
@@ -42,6 +43,7 @@ struct ForwardCannonsStats {
     speed: f32,
     hit_multiple: bool,
     knockback_intensity: f32,
+    crit: DamageCrit,
 }
 
 #[derive(Component)]
@@ -56,7 +58,8 @@ fn forward_cannons_fire(
 ) {
     for (boat_entity, mut forward_cannons, boat, global_transform) in query.iter_mut() {
         if forward_cannons.shoot {
-            let stats = forward_cannons.level.stats();
+            let mut stats = forward_cannons.level.stats();
+            stats.crit = stats.crit.for_hurt_flags(forward_cannons.hurt_flags);
             let audio_entity = commands
                 .spawn((
                     Transform2Bundle {
@@ -102,6 +105,9 @@ fn forward_cannons_fire(
                         velocity * stats.knockback_intensity,
                     ),
                     damage: stats.damage,
+                    damage_type: DamageType::Cannon,
+                    status: None,
+                    crit: stats.crit,
                 },
                 YDepth::default(),
                 ForwardCannonBall { velocity },
@@ -34,6 +34,7 @@ impl KrakenLevel {
                 far_tentacle_distance_min: 150.,
                 far_tentacle_distance_max: 1650.,
                 knockback_intensity: 6.,
+                crit: DamageCrit::NONE,
             }
         } else {
             KrakenStats {
@@ -43,6 +44,7 @@ impl KrakenLevel {
                 far_tentacle_distance_min: 150.,
                 far_tentacle_distance_max: 500.,
                 knockback_intensity: 5.,
+                crit: DamageCrit::new(0.05 + self.0 as f32 * 0.03, 2.5),
             }
         }
     }
@@ -56,6 +58,7 @@ struct KrakenStats {
     far_tentacle_distance_min: f32,
     far_tentacle_distance_max: f32,
     knockback_intensity: f32,
+    crit: DamageCrit,
 }
 
 #[derive(Component)]
@@ -76,7 +79,8 @@ fn kraken_fire(
 ) {
     for (boat_entity, mut kraken, global_transform) in query.iter_mut() {
         if kraken.shoot {
-            let stats = kraken.level.stats();
+            let mut stats = kraken.level.stats();
+            stats.crit = stats.crit.for_hurt_flags(kraken.hurt_flags);
             commands.spawn((
                 Transform2Bundle {
                     transform2: Transform2::from_translation(
@@ -162,8 +166,10 @@ fn tentacle_update(
                 ));
             }
             commands.entity(entity).insert(Hurtbox {
-                shape: CollisionShape::Rect {
-                    size: Vec2::new(32., 48.),
+                shape: CollisionShape::Capsule {
+                    length: 16.,
+                    radius: 16.,
+                    rotation: std::f32::consts::FRAC_PI_2,
                 },
                 for_entity: Some(tentacle.parent),
                 auto_despawn: false,
@@ -172,6 +178,9 @@ fn tentacle_update(
                     tentacle.stats.knockback_intensity,
                 ),
                 damage: tentacle.stats.damage,
+                damage_type: DamageType::Tentacle,
+                status: Some(StatusEffect::slowed(2., 0.5)),
+                crit: tentacle.stats.crit,
             });
             tentacle.spawned_hurtbox = true;
         }
@@ -29,6 +29,7 @@ impl ShockwaveLevel {
                 damage: 1.,
                 knockback_intensity: 8.,
                 scale: 0.9,
+                crit: DamageCrit::NONE,
             }
         } else {
             let level = self.0 as f32;
@@ -36,6 +37,7 @@ impl ShockwaveLevel {
                 damage: 0.25 * level,
                 knockback_intensity: 3. + ((level - 1.) / 5.) * 2.,
                 scale: 0.5 + level * 0.15,
+                crit: DamageCrit::new(level * 0.02, 1.5),
             }
         }
     }
@@ -46,6 +48,7 @@ struct ShockwaveStats {
     damage: f32,
     knockback_intensity: f32,
     scale: f32,
+    crit: DamageCrit,
 }
 
 #[derive(Component)]
@@ -64,7 +67,8 @@ fn shockwave_fire(
 ) {
     for (mut shockwave, entity, global_transform) in query.iter_mut() {
         if shockwave.shoot {
-            let stats = shockwave.level.stats();
+            let mut stats = shockwave.level.stats();
+            stats.crit = stats.crit.for_hurt_flags(shockwave.hurt_flags);
             commands.spawn((
                 Transform2Bundle {
                     transform2: Transform2::from_translation(
@@ -113,8 +117,8 @@ fn shockwave_fire(
                         },
                         Transform2::new().with_depth((DepthLayer::Front, 0.98)),
                         Hurtbox {
-                            shape: CollisionShape::Rect {
-                                size: Vec2::new(400., 400.) * stats.scale,
+                            shape: CollisionShape::Circle {
+                                radius: 200. * stats.scale,
                             },
                             for_entity: Some(entity),
                             auto_despawn: false,
@@ -123,6 +127,9 @@ fn shockwave_fire(
                                 stats.knockback_intensity,
                             ),
                             damage: stats.damage,
+                            damage_type: DamageType::Shockwave,
+                            status: Some(StatusEffect::stunned(0.5)),
+                            crit: stats.crit,
                         },
                         TimeToLive { seconds: 0.05 },
                     ));
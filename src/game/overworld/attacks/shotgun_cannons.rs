@@ -32,6 +32,7 @@ impl ShotgunCannonsLevel {
                 scale: 1.,
                 angle: 0.1,
                 count: 1,
+                crit: DamageCrit::NONE,
             }
         } else {
             let level = self.0 as f32;
@@ -41,6 +42,7 @@ impl ShotgunCannonsLevel {
                 scale: 1. + level / 5.,
                 angle: if self.0 == 5 { 0.1 } else { 0.1 + level / 30. },
                 count: if self.0 == 5 { 2 } else { 1 },
+                crit: DamageCrit::new(0.04 + level * 0.02, 1.75),
             }
         }
     }
@@ -53,6 +55,7 @@ struct ShotgunCannonsStats {
     scale: f32,
     angle: f32,
     count: i32,
+    crit: DamageCrit,
 }
 
 #[derive(Component)]
@@ -67,7 +70,8 @@ fn shotgun_cannons_fire(
 ) {
     for (boat_entity, mut shotgun_cannons, boat, global_transform) in query.iter_mut() {
         if shotgun_cannons.shoot {
-            let stats = shotgun_cannons.level.stats();
+            let mut stats = shotgun_cannons.level.stats();
+            stats.crit = stats.crit.for_hurt_flags(shotgun_cannons.hurt_flags);
             commands.spawn((
                 Transform2Bundle {
                     transform2: Transform2::from_translation(
@@ -115,6 +119,9 @@ fn shotgun_cannons_fire(
                             flags: shotgun_cannons.hurt_flags,
                             knockback_type: HurtboxKnockbackType::None,
                             damage: stats.damage,
+                            damage_type: DamageType::Cannon,
+                            status: None,
+                            crit: stats.crit,
                         },
                         Transform2::from_translation(position)
                             .with_depth((DepthLayer::Entity, 0.5))
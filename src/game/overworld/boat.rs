@@ -5,6 +5,9 @@ use bevy_egui::{egui, EguiContext};
 
 const RING_SPAWN_INTEVAL: f32 = 0.15;
 
+// length along the facing direction by width
+const BOAT_HITBOX_SIZE: Vec2 = Vec2::new(140., 100.);
+
 #[derive(Debug, Hash, PartialEq, Eq, Clone, SystemSet)]
 pub enum BoatSystem {
     Spawn,
@@ -153,8 +156,9 @@ fn boat_spawn(
                 },
                 Health::new_with_max(event.health, event.health_max),
                 Hitbox {
-                    shape: CollisionShape::Rect {
-                        size: Vec2::new(120., 120.),
+                    shape: CollisionShape::OrientedRect {
+                        size: BOAT_HITBOX_SIZE,
+                        rotation: Vec2::X.angle_between(Facing::South.to_vec()),
                     },
                     for_entity: None,
                     flags: if event.player {
@@ -182,12 +186,13 @@ fn boat_update(
         &GlobalTransform,
         &mut Boat,
         &Children,
+        Option<&mut Hitbox>,
     )>,
     mut children_query: Query<&mut TextureAtlasSprite, With<BoatSprite>>,
     time: Res<Time>,
     mut ev_water_ring_spawn: EventWriter<WaterRingSpawnEvent>,
 ) {
-    for (transform, mut character_controller, global_transform, mut boat, children) in
+    for (transform, mut character_controller, global_transform, mut boat, children, hitbox) in
         query.iter_mut()
     {
         boat.movement = boat.movement.clamp(Vec2::NEG_ONE, Vec2::ONE);
@@ -199,6 +204,12 @@ fn boat_update(
         if let Some(facing) = character_controller.force_facing {
             boat.facing = facing;
         }
+        if let Some(mut hitbox) = hitbox {
+            hitbox.shape = CollisionShape::OrientedRect {
+                size: BOAT_HITBOX_SIZE,
+                rotation: Vec2::X.angle_between(boat.facing.to_vec()),
+            };
+        }
         for child in children.iter() {
             if let Ok(mut atlas) = children_query.get_mut(*child) {
                 atlas.color.set_a(boat.opacity);
@@ -265,6 +276,7 @@ fn boat_attack(
         &mut Bombs,
         &mut Kraken,
         &mut DashAttack,
+        Option<&Health>,
     )>,
     time: Res<Time>,
     cutscenes: Res<Cutscenes>,
@@ -280,10 +292,14 @@ fn boat_attack(
         mut bombs,
         mut kraken,
         mut dash,
+        health,
     ) in query.iter_mut()
     {
+        let stunned = health.map_or(false, |health| {
+            health.status_effects.has(StatusEffectKind::Stunned)
+        });
         boat.shoot_cooldown += time.delta_seconds();
-        if boat.shoot && boat.shoot_cooldown > boat.shoot_cooldown_threshold {
+        if boat.shoot && !stunned && boat.shoot_cooldown > boat.shoot_cooldown_threshold {
             boat.shoot_cooldown = 0.;
             if boat.attacks.forward_cannons > 0 {
                 forward_cannons.level = ForwardCannonsLevel(boat.attacks.forward_cannons);
@@ -307,7 +323,7 @@ fn boat_attack(
             }
         }
         boat.dash_cooldown -= time.delta_seconds();
-        if boat.dash && boat.dash_cooldown <= 0. {
+        if boat.dash && !stunned && boat.dash_cooldown <= 0. {
             boat.dash_cooldown = 0.6;
             dash.shoot = true
         }
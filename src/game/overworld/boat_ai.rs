@@ -0,0 +1,161 @@
+use crate::common::prelude::*;
+use crate::game::prelude::*;
+use bevy::prelude::*;
+
+const BOAT_AI_PATROL_RADIUS: f32 = 400.;
+const BOAT_AI_PATROL_SPEED: f32 = 0.5;
+
+#[derive(Debug, Hash, PartialEq, Eq, Clone, SystemSet)]
+pub enum BoatAiSystem {
+    Update,
+}
+
+pub struct BoatAiPlugin;
+
+impl Plugin for BoatAiPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system(
+            boat_ai_update
+                .in_set(BoatAiSystem::Update)
+                .before(BoatSystem::Update),
+        );
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum BoatAiState {
+    #[default]
+    Patrol,
+    Chase,
+    Broadside,
+    Flee,
+}
+
+// drives a Boat that isn't the player, boss captains still use their own movement
+#[derive(Component)]
+pub struct BoatAi {
+    pub state: BoatAiState,
+    // notices the player within this distance and loses them past twice it
+    pub sight_distance: f32,
+    // holds this far from the player, circling if the boat has side cannons
+    pub broadside_distance: f32,
+    // fraction of health to turn tail at
+    pub flee_health: f32,
+    home: Option<Vec2>,
+    patrol_target: Option<Vec2>,
+    patrol_chance: TimedChance,
+    orbit: f32,
+}
+
+impl BoatAi {
+    pub fn new(sight_distance: f32, broadside_distance: f32, flee_health: f32) -> Self {
+        Self {
+            state: BoatAiState::Patrol,
+            sight_distance,
+            broadside_distance,
+            flee_health,
+            home: None,
+            patrol_target: None,
+            patrol_chance: TimedChance::new(),
+            orbit: if rand::random() { 1. } else { -1. },
+        }
+    }
+}
+
+fn boat_ai_update(
+    mut queries: ParamSet<(
+        Query<(&mut Boat, &GlobalTransform, &mut BoatAi, &Health)>,
+        Query<&GlobalTransform, With<Player>>,
+    )>,
+    cutscenes: Res<Cutscenes>,
+    time: Res<Time>,
+) {
+    let player_position = queries
+        .p1()
+        .get_single()
+        .ok()
+        .map(|player_transform| player_transform.translation().truncate());
+    for (mut boat, global_transform, mut ai, health) in queries.p0().iter_mut() {
+        let position = global_transform.translation().truncate();
+        let home = *ai.home.get_or_insert(position);
+        if cutscenes.running() {
+            boat.movement = Vec2::ZERO;
+            boat.shoot = false;
+            continue;
+        }
+        let difference = player_position.map_or(Vec2::ZERO, |player| player - position);
+        let distance = if player_position.is_some() {
+            difference.length()
+        } else {
+            f32::INFINITY
+        };
+        let previous_state = ai.state;
+        ai.state = if health.value / health.max < ai.flee_health {
+            BoatAiState::Flee
+        } else {
+            match ai.state {
+                BoatAiState::Patrol if distance < ai.sight_distance => BoatAiState::Chase,
+                BoatAiState::Chase | BoatAiState::Broadside
+                    if distance > ai.sight_distance * 2. =>
+                {
+                    BoatAiState::Patrol
+                }
+                BoatAiState::Chase
+                    if boat.attacks.shotgun_cannons > 0
+                        && distance < ai.broadside_distance * 1.25 =>
+                {
+                    BoatAiState::Broadside
+                }
+                BoatAiState::Broadside if distance > ai.broadside_distance * 2. => {
+                    BoatAiState::Chase
+                }
+                state => state,
+            }
+        };
+        let toward_player = difference.normalize_or_zero();
+        match ai.state {
+            BoatAiState::Patrol => {
+                let reached = ai
+                    .patrol_target
+                    .map_or(true, |target| target.distance(position) < 50.);
+                if reached || ai.patrol_chance.check(5., 2., time.delta_seconds()) {
+                    ai.patrol_target = Some(
+                        home + Vec2::from_angle(rand::random::<f32>() * std::f32::consts::TAU)
+                            * rand::random::<f32>()
+                            * BOAT_AI_PATROL_RADIUS,
+                    );
+                }
+                let target = ai.patrol_target.unwrap_or(home);
+                boat.movement = (target - position).normalize_or_zero() * BOAT_AI_PATROL_SPEED;
+                boat.shoot = false;
+            }
+            BoatAiState::Chase => {
+                // ease off once in range so it doesn't ram the player
+                let approach = ((distance - ai.broadside_distance) / 100.).clamp(0., 1.);
+                boat.movement = toward_player * approach;
+                if boat.movement.length_squared() == 0. && toward_player != Vec2::ZERO {
+                    boat.direction = Vec2::X.angle_between(toward_player);
+                }
+                boat.shoot = distance < ai.sight_distance;
+            }
+            BoatAiState::Broadside => {
+                // circle the player so the side cannons face them, drifting back to the right range
+                let tangent = toward_player.perp() * ai.orbit;
+                let correction =
+                    ((distance - ai.broadside_distance) / ai.broadside_distance).clamp(-1., 1.);
+                boat.movement = (tangent + toward_player * correction).normalize_or_zero();
+                boat.shoot = true;
+            }
+            BoatAiState::Flee => {
+                boat.movement = -toward_player;
+                boat.shoot = false;
+                if previous_state != BoatAiState::Flee {
+                    boat.dash = true;
+                }
+            }
+        }
+        if boat.movement.length_squared() > 0. {
+            boat.direction = Vec2::X.angle_between(boat.movement);
+        }
+    }
+}
@@ -44,17 +44,15 @@ pub struct CharacterControllerDestination {
 
 fn character_controller_update(
     query: Query<Entity, (With<CharacterController>, With<Transform2>, With<Collision>)>,
-    mut queries: ParamSet<(
-        Query<(
-            Entity,
-            &mut CharacterController,
-            &mut Transform2,
-            &Collision,
-            &GlobalTransform,
-            Option<&Dash>,
-            Option<&CharacterControllerDestination>,
-        )>,
-        Query<(Entity, &GlobalTransform, &Collision)>,
+    mut controller_query: Query<(
+        Entity,
+        &mut CharacterController,
+        &mut Transform2,
+        &Collision,
+        &GlobalTransform,
+        Option<&Dash>,
+        Option<&CharacterControllerDestination>,
+        Option<&Health>,
     )>,
     mut collision_query: ResMut<CollisionQuery>,
     time: Res<Time>,
@@ -62,7 +60,6 @@ fn character_controller_update(
     overworld_camera: Res<OverworldCamera>,
 ) {
     for entity in query.iter() {
-        collision_query.update(&queries.p1());
         if let Ok((
             entity,
             mut character_controller,
@@ -71,12 +68,14 @@ fn character_controller_update(
             global_transform,
             dash,
             destination,
-        )) = queries.p0().get_mut(entity)
+            health,
+        )) = controller_query.get_mut(entity)
         {
             let collision_filters = CollisionFilter {
                 exclude_entity: entity,
                 flags: 1,
             };
+            let start = transform.translation;
             if let Some((_, diff)) = collision_query.check(
                 transform.translation,
                 collision.shape,
@@ -100,7 +99,10 @@ fn character_controller_update(
             if velocity.length_squared() > 1. {
                 velocity = velocity.normalize();
             }
-            velocity *= character_controller.speed * time.delta_seconds();
+            let speed_multiplier = health
+                .map(|health| health.status_effects.speed_multiplier())
+                .unwrap_or(1.);
+            velocity *= character_controller.speed * speed_multiplier * time.delta_seconds();
             if let Some(dash) = dash {
                 velocity += dash.velocity * time.delta_seconds();
             }
@@ -137,6 +139,8 @@ fn character_controller_update(
             {
                 transform.translation += velocity_y;
             }
+            // controllers later in the loop collide with where this one is now, not last frame
+            collision_query.translate(entity, transform.translation - start);
         }
     }
 }
@@ -2,18 +2,33 @@ use crate::common::prelude::*;
 use crate::game::prelude::*;
 use audio_plus::prelude::*;
 use bevy::prelude::*;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
 
 pub const DAMAGE_FLAG_PLAYER: u32 = 1;
 pub const DAMAGE_FLAG_ENEMY: u32 = 2;
 pub const DAMAGE_FLAG_ENVIRONMENT: u32 = 4;
 
+#[derive(Debug, Hash, PartialEq, Eq, Clone, SystemSet)]
+pub enum DamageSystem {
+    Check,
+    AutoDie,
+}
+
 pub struct DamagePlugin;
 
 impl Plugin for DamagePlugin {
     fn build(&self, app: &mut App) {
         app.add_event::<DamageEvent>()
-            .add_system(damage_check)
-            .add_system(damage_auto_die);
+            .add_event::<DamageKillEvent>()
+            .configure_sets((DamageSystem::Check, DamageSystem::AutoDie).chain())
+            .add_system(
+                damage_hitboxes_update
+                    .in_base_set(CoreSet::PostUpdate)
+                    .after(CollisionSystem::Update),
+            )
+            .add_system(damage_check.in_set(DamageSystem::Check))
+            .add_system(damage_auto_die.in_set(DamageSystem::AutoDie));
     }
 }
 
@@ -22,6 +37,19 @@ pub struct DamageEvent {
     pub hit: Entity,
     pub hurt: Entity,
     pub damage: f32,
+    pub damage_type: DamageType,
+    pub status: Option<StatusEffect>,
+    pub crit: bool,
+    // ticks of burning and the like, taken through invincibility without any hit feedback
+    pub over_time: bool,
+}
+
+// sent when an AutoDamage entity dies, before it is despawned
+#[derive(Clone, Copy)]
+pub struct DamageKillEvent {
+    pub entity: Entity,
+    pub position: Vec2,
+    pub kind: AutoDamageKind,
 }
 
 #[derive(Component)]
@@ -39,6 +67,92 @@ pub struct Hurtbox {
     pub flags: u32,
     pub knockback_type: HurtboxKnockbackType,
     pub damage: f32,
+    pub damage_type: DamageType,
+    pub status: Option<StatusEffect>,
+    pub crit: DamageCrit,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct DamageCrit {
+    pub chance: f32,
+    pub multiplier: f32,
+}
+
+impl DamageCrit {
+    pub const NONE: Self = Self {
+        chance: 0.,
+        multiplier: 1.,
+    };
+
+    pub fn new(chance: f32, multiplier: f32) -> Self {
+        Self { chance, multiplier }
+    }
+
+    // only the player's attacks crit, anything that can hurt the player never does
+    pub fn for_hurt_flags(self, hurt_flags: u32) -> Self {
+        if hurt_flags & DAMAGE_FLAG_PLAYER != 0 {
+            Self::NONE
+        } else {
+            self
+        }
+    }
+}
+
+impl Default for DamageCrit {
+    fn default() -> Self {
+        Self::NONE
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DamageType {
+    #[default]
+    Cannon,
+    Explosion,
+    Shockwave,
+    Tentacle,
+    Ram,
+}
+
+// fraction of each damage type that's ignored, negative values take extra damage
+#[derive(Debug, Default, Clone, Copy)]
+pub struct DamageResistances {
+    pub cannon: f32,
+    pub explosion: f32,
+    pub shockwave: f32,
+    pub tentacle: f32,
+    pub ram: f32,
+}
+
+impl DamageResistances {
+    pub fn with(mut self, damage_type: DamageType, resistance: f32) -> Self {
+        *self.get_mut(damage_type) = resistance;
+        self
+    }
+
+    pub fn get(&self, damage_type: DamageType) -> f32 {
+        match damage_type {
+            DamageType::Cannon => self.cannon,
+            DamageType::Explosion => self.explosion,
+            DamageType::Shockwave => self.shockwave,
+            DamageType::Tentacle => self.tentacle,
+            DamageType::Ram => self.ram,
+        }
+    }
+
+    fn get_mut(&mut self, damage_type: DamageType) -> &mut f32 {
+        match damage_type {
+            DamageType::Cannon => &mut self.cannon,
+            DamageType::Explosion => &mut self.explosion,
+            DamageType::Shockwave => &mut self.shockwave,
+            DamageType::Tentacle => &mut self.tentacle,
+            DamageType::Ram => &mut self.ram,
+        }
+    }
+
+    pub fn multiplier(&self, damage_type: DamageType) -> f32 {
+        (1. - self.get(damage_type)).max(0.)
+    }
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -48,9 +162,19 @@ pub enum HurtboxKnockbackType {
     Difference(f32),
 }
 
+// what died, the entity is already gone by the time most kill events are read
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AutoDamageKind {
+    #[default]
+    Other,
+    Octopus,
+    Turtle,
+}
+
 #[derive(Component, Default)]
 pub struct AutoDamage {
     pub despawn: bool,
+    pub kind: AutoDamageKind,
     pub invincibility: f32,
     pub invincibility_amount: f32,
     pub already_despawned: bool,
@@ -59,10 +183,32 @@ pub struct AutoDamage {
     pub experience_infinite_distance: bool,
 }
 
+// hitboxes share the collision query's grid and are rebuilt along with it
+fn damage_hitboxes_update(
+    mut collision_query: ResMut<CollisionQuery>,
+    hitbox_query: Query<(Entity, &GlobalTransform, &Hitbox)>,
+) {
+    for (entity, transform, hitbox) in hitbox_query.iter() {
+        if transform.translation().is_finite() {
+            collision_query.insert_layer(
+                CollisionLayer::Hitbox,
+                CollisionQueryEntry {
+                    entity,
+                    position: transform.translation().truncate(),
+                    shape: hitbox.shape,
+                    flags: hitbox.flags,
+                },
+            );
+        }
+    }
+}
+
 fn damage_check(
-    hitbox_query: Query<(Entity, &Hitbox)>,
+    hitbox_query: Query<&Hitbox>,
     hurtbox_query: Query<(Entity, &Hurtbox)>,
     transform_query: Query<&GlobalTransform>,
+    health_query: Query<&Health>,
+    collision_query: Res<CollisionQuery>,
     mut ev_damage: EventWriter<DamageEvent>,
     mut commands: Commands,
     cutscenes: Res<Cutscenes>,
@@ -82,8 +228,11 @@ fn damage_check(
         } else {
             hurtbox_entity
         };
+        let Some((min, max)) = hurtbox.shape.bounds(hurtbox_translation) else { continue };
         let mut despawn = false;
-        for (hitbox_entity, hitbox) in hitbox_query.iter() {
+        for entry in collision_query.layer_candidates(CollisionLayer::Hitbox, min, max) {
+            let Ok(hitbox) = hitbox_query.get(entry.entity) else { continue };
+            let (hitbox_entity, hitbox_translation) = (entry.entity, entry.position);
             let hit = if let Some(proxy_entity) = hitbox.for_entity {
                 proxy_entity
             } else {
@@ -95,11 +244,6 @@ fn damage_check(
             if hurtbox.flags & hitbox.flags == 0 {
                 continue;
             }
-            let hitbox_translation = if let Ok(transform) = transform_query.get(hitbox_entity) {
-                transform.translation().truncate()
-            } else {
-                continue;
-            };
             if hitbox
                 .shape
                 .overlaps(hitbox_translation, hurtbox.shape, hurtbox_translation)
@@ -121,10 +265,24 @@ fn damage_check(
                     }
                     _ => {}
                 }
+                // resistances scale both the hit and how long its status lasts
+                let resistance = health_query
+                    .get(hit)
+                    .map(|health| health.resistances.multiplier(hurtbox.damage_type))
+                    .unwrap_or(1.);
+                let crit = damage_crit_roll(hurtbox_entity, hit) < hurtbox.crit.chance;
+                let crit_multiplier = if crit { hurtbox.crit.multiplier } else { 1. };
                 ev_damage.send(DamageEvent {
                     hit,
                     hurt,
-                    damage: hurtbox.damage,
+                    damage: hurtbox.damage * resistance * crit_multiplier,
+                    damage_type: hurtbox.damage_type,
+                    status: hurtbox.status.map(|status| StatusEffect {
+                        seconds: status.seconds * resistance,
+                        ..status
+                    }),
+                    crit,
+                    over_time: false,
                 });
                 if hurtbox.auto_despawn {
                     despawn = true;
@@ -138,6 +296,13 @@ fn damage_check(
     }
 }
 
+// stable for each pair so hurtboxes that linger don't get a fresh roll every frame
+fn damage_crit_roll(hurtbox: Entity, hit: Entity) -> f32 {
+    let mut hasher = DefaultHasher::new();
+    (hurtbox, hit).hash(&mut hasher);
+    (hasher.finish() as f64 / u64::MAX as f64) as f32
+}
+
 fn damage_auto_die(
     mut ev_damage: EventReader<DamageEvent>,
     mut crate_query: Query<(Entity, &mut Health, &mut AutoDamage, &GlobalTransform)>,
@@ -145,6 +310,8 @@ fn damage_auto_die(
     time: Res<Time>,
     cutscenes: Res<Cutscenes>,
     mut ev_experience_spawn: EventWriter<ExperienceSpawnEvent>,
+    mut ev_kill: EventWriter<DamageKillEvent>,
+    mut ev_damage_number_spawn: EventWriter<DamageNumberSpawnEvent>,
     asset_library: Res<AssetLibrary>,
 ) {
     for (_, _, mut auto_damage, _) in crate_query.iter_mut() {
@@ -157,7 +324,18 @@ fn damage_auto_die(
             if auto_damage.invincibility == 0. {
                 auto_damage.invincibility_amount = 0.
             }
-            if event.damage > auto_damage.invincibility_amount {
+            if event.over_time {
+                if !cutscenes.running() {
+                    health.damage(event.damage);
+                    ev_damage_number_spawn.send(DamageNumberSpawnEvent {
+                        target: entity,
+                        position: transform.translation().truncate(),
+                        amount: event.damage,
+                        crit: false,
+                        player: false,
+                    });
+                }
+            } else if event.damage > auto_damage.invincibility_amount {
                 if auto_damage.invincibility_amount == 0. {
                     commands.spawn((
                         TransformBundle::default(),
@@ -173,13 +351,26 @@ fn damage_auto_die(
                     ));
                 }
                 if !cutscenes.running() {
-                    health.damage(event.damage - auto_damage.invincibility_amount);
+                    let amount = event.damage - auto_damage.invincibility_amount;
+                    health.damage(amount);
+                    ev_damage_number_spawn.send(DamageNumberSpawnEvent {
+                        target: entity,
+                        position: transform.translation().truncate(),
+                        amount,
+                        crit: event.crit,
+                        player: false,
+                    });
                 }
                 auto_damage.invincibility = 0.1;
                 auto_damage.invincibility_amount = event.damage;
             }
             if health.dead() && !auto_damage.already_despawned {
                 commands.entity(entity).despawn_recursive();
+                ev_kill.send(DamageKillEvent {
+                    entity,
+                    position: transform.translation().truncate(),
+                    kind: auto_damage.kind,
+                });
                 if auto_damage.experience > 0. {
                     ev_experience_spawn.send(ExperienceSpawnEvent {
                         amount: auto_damage.experience,
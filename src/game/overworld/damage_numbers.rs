@@ -0,0 +1,146 @@
+use crate::common::prelude::*;
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+
+// hits on the same target this close together add onto one number instead of stacking
+const DAMAGE_NUMBER_COALESCE: f32 = 0.3;
+const DAMAGE_NUMBER_LIFETIME: f32 = 0.9;
+const DAMAGE_NUMBER_FADE: f32 = 0.3;
+const DAMAGE_NUMBER_POP: f32 = 0.15;
+
+pub struct DamageNumbersPlugin;
+
+impl Plugin for DamageNumbersPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<DamageNumberSpawnEvent>()
+            .add_system(damage_numbers_spawn)
+            .add_system(damage_numbers_update);
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct DamageNumberSpawnEvent {
+    pub target: Entity,
+    pub position: Vec2,
+    pub amount: f32,
+    pub crit: bool,
+    pub player: bool,
+}
+
+#[derive(Component)]
+struct DamageNumber {
+    target: Entity,
+    amount: f32,
+    crit: bool,
+    player: bool,
+    age: f32,
+    velocity: Vec2,
+}
+
+fn damage_numbers_spawn(
+    mut ev_spawn: EventReader<DamageNumberSpawnEvent>,
+    mut query: Query<(&mut DamageNumber, &mut Text)>,
+    mut commands: Commands,
+    asset_library: Res<AssetLibrary>,
+    mut pending: Local<HashMap<Entity, DamageNumberSpawnEvent>>,
+) {
+    pending.clear();
+    for event in ev_spawn.iter() {
+        if event.amount <= 0. {
+            continue;
+        }
+        pending
+            .entry(event.target)
+            .and_modify(|pending| {
+                pending.amount += event.amount;
+                pending.crit |= event.crit;
+            })
+            .or_insert(*event);
+    }
+    for (mut number, mut text) in query.iter_mut() {
+        if number.age > DAMAGE_NUMBER_COALESCE {
+            continue;
+        }
+        let Some(event) = pending.remove(&number.target) else { continue };
+        number.amount += event.amount;
+        number.crit |= event.crit;
+        number.age = 0.;
+        *text = damage_number_text(&number, asset_library.as_ref());
+    }
+    for (_, event) in pending.drain() {
+        let number = DamageNumber {
+            target: event.target,
+            amount: event.amount,
+            crit: event.crit,
+            player: event.player,
+            age: 0.,
+            velocity: Vec2::new((rand::random::<f32>() * 2. - 1.) * 80., 180.),
+        };
+        commands.spawn((
+            Text2dBundle {
+                text: damage_number_text(&number, asset_library.as_ref()),
+                ..Default::default()
+            },
+            Transform2::from_translation(event.position + Vec2::new(0., 60.))
+                .with_depth(DEPTH_LAYER_DAMAGE_NUMBER)
+                .with_scale(Vec2::ZERO),
+            number,
+        ));
+    }
+}
+
+fn damage_numbers_update(
+    mut query: Query<(Entity, &mut DamageNumber, &mut Transform2, &mut Text)>,
+    mut commands: Commands,
+    time: Res<Time>,
+) {
+    for (entity, mut number, mut transform, mut text) in query.iter_mut() {
+        number.age += time.delta_seconds();
+        transform.translation += number.velocity * time.delta_seconds();
+        number.velocity *= 0.05_f32.powf(time.delta_seconds());
+        // pops in again whenever another hit gets added on
+        transform.scale = Vec2::ONE
+            * ease(
+                Easing::BackOut,
+                (number.age / DAMAGE_NUMBER_POP).clamp(0., 1.),
+            );
+        let opacity = 1.
+            - ((number.age - (DAMAGE_NUMBER_LIFETIME - DAMAGE_NUMBER_FADE)) / DAMAGE_NUMBER_FADE)
+                .clamp(0., 1.);
+        for section in text.sections.iter_mut() {
+            section.style.color.set_a(opacity);
+        }
+        if number.age >= DAMAGE_NUMBER_LIFETIME {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+fn damage_number_text(number: &DamageNumber, asset_library: &AssetLibrary) -> Text {
+    let color = if number.player {
+        Color::rgb(1., 0.3, 0.3)
+    } else if number.crit {
+        Color::rgb(1., 0.85, 0.2)
+    } else {
+        Color::WHITE
+    };
+    // most attacks deal fractions of a point, so keep a decimal until it'd just be noise
+    let amount = if number.amount >= 10. {
+        format!("{:.0}", number.amount)
+    } else {
+        format!("{:.1}", number.amount)
+    };
+    Text::from_section(
+        if number.crit {
+            format!("{}!", amount)
+        } else {
+            amount
+        },
+        TextStyle {
+            font: asset_library.font_bold.clone(),
+            font_size: if number.crit { 44. } else { 30. },
+            color,
+        },
+    )
+    .with_alignment(TextAlignment::Center)
+}
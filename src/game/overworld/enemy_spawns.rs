@@ -43,6 +43,8 @@ impl Default for EnemySpawnsState {
             },
             hard_level: EnemySpawnLevel {
                 spawn_chances: vec![
+                    (0.005, EnemySpawn::Pirate(PirateLevel::Medium)),
+                    (0.02, EnemySpawn::Pirate(PirateLevel::Easy)),
                     (0.1, EnemySpawn::Octopus(OctopusLevel::Hard)),
                     (0.01, EnemySpawn::Octopus(OctopusLevel::Medium)),
                     (0.005, EnemySpawn::Turtle(TurtleLevel::Hard)),
@@ -55,6 +57,9 @@ impl Default for EnemySpawnsState {
             },
             midnight_level: EnemySpawnLevel {
                 spawn_chances: vec![
+                    (0.01, EnemySpawn::Pirate(PirateLevel::Hard)),
+                    (0.03, EnemySpawn::Pirate(PirateLevel::Medium)),
+                    (0.03, EnemySpawn::Pirate(PirateLevel::Easy)),
                     (0.1, EnemySpawn::Octopus(OctopusLevel::Hard)),
                     (0.1, EnemySpawn::Octopus(OctopusLevel::Medium)),
                     (0.005, EnemySpawn::Turtle(TurtleLevel::Hard)),
@@ -80,6 +85,7 @@ impl Default for EnemySpawnsState {
 enum EnemySpawn {
     Octopus(OctopusLevel),
     Turtle(TurtleLevel),
+    Pirate(PirateLevel),
 }
 
 struct EnemySpawnLevel {
@@ -97,7 +103,8 @@ impl Plugin for EnemySpawnsPlugin {
             .add_system(
                 enemy_spawns
                     .before(OctopusSystem::Spawn)
-                    .before(TurtleSystem::Spawn),
+                    .before(TurtleSystem::Spawn)
+                    .before(PirateSystem::Spawn),
             )
             .add_system(enemy_spawns_despawn);
     }
@@ -146,6 +153,7 @@ fn enemy_spawns(
     )>,
     mut ev_octopus_spawn: EventWriter<OctopusSpawnEvent>,
     mut ev_turtle_spawn: EventWriter<TurtleSpawnEvent>,
+    mut ev_pirate_spawn: EventWriter<PirateSpawnEvent>,
     state_time: Res<StateTime<AppState>>,
     game_state: Res<GameState>,
     screen_fade: Res<ScreenFade>,
@@ -225,6 +233,14 @@ fn enemy_spawns(
                                 level,
                             });
                         }
+                        EnemySpawn::Pirate(level) => {
+                            let entity = commands.spawn(SpawnedEntity::default()).id();
+                            ev_pirate_spawn.send(PirateSpawnEvent {
+                                entity: Some(entity),
+                                position,
+                                level,
+                            });
+                        }
                     }
                 }
                 break;
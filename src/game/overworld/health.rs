@@ -1,18 +1,31 @@
+use crate::game::prelude::*;
 use bevy::prelude::*;
 
 #[derive(Component)]
 pub struct Health {
     pub max: f32,
     pub value: f32,
+    pub resistances: DamageResistances,
+    pub status_effects: StatusEffects,
 }
 
 impl Health {
     pub fn new(max: f32) -> Self {
-        Self { max, value: max }
+        Self::new_with_max(max, max)
     }
 
     pub fn new_with_max(value: f32, max: f32) -> Self {
-        Self { max, value }
+        Self {
+            max,
+            value,
+            resistances: DamageResistances::default(),
+            status_effects: StatusEffects::default(),
+        }
+    }
+
+    pub fn with_resistances(mut self, resistances: DamageResistances) -> Self {
+        self.resistances = resistances;
+        self
     }
 
     pub fn damage(&mut self, amt: f32) {
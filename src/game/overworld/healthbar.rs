@@ -3,6 +3,12 @@ use crate::game::prelude::*;
 use bevy::prelude::*;
 
 const HEALTHBAR_BORDER: Vec2 = Vec2::new(8., 8.);
+const HEALTHBAR_STATUS_SIZE: f32 = 10.;
+const HEALTHBAR_STATUSES: [(StatusEffectKind, Color); 3] = [
+    (StatusEffectKind::Burning, Color::rgb(1., 0.5, 0.)),
+    (StatusEffectKind::Slowed, Color::rgb(0.3, 0.6, 1.)),
+    (StatusEffectKind::Stunned, Color::rgb(1., 0.9, 0.2)),
+];
 
 #[derive(Debug, Hash, PartialEq, Eq, Clone, SystemSet)]
 pub enum HealthbarSystem {
@@ -36,6 +42,11 @@ struct HealthbarValue {
     offset: Vec2,
 }
 
+#[derive(Component)]
+struct HealthbarStatus {
+    kind: StatusEffectKind,
+}
+
 fn healthbar_spawn(mut ev_spawn: EventReader<HealthbarSpawnEvent>, mut commands: Commands) {
     for event in ev_spawn.iter() {
         let mut entity = if let Some(entity) = event.entity {
@@ -72,6 +83,29 @@ fn healthbar_spawn(mut ev_spawn: EventReader<HealthbarSpawnEvent>, mut commands:
                         offset: event.offset,
                     },
                 ));
+                // one pip per status above the right end of the bar, hidden until it applies
+                for (index, (kind, color)) in HEALTHBAR_STATUSES.iter().enumerate() {
+                    let position = event.offset
+                        + Vec2::new(
+                            event.size.x * 0.5
+                                - HEALTHBAR_STATUS_SIZE * 0.5
+                                - index as f32 * (HEALTHBAR_STATUS_SIZE + 4.),
+                            event.size.y * 0.5 + HEALTHBAR_STATUS_SIZE,
+                        );
+                    parent.spawn((
+                        SpriteBundle {
+                            sprite: Sprite {
+                                custom_size: Some(Vec2::splat(HEALTHBAR_STATUS_SIZE)),
+                                color: *color,
+                                ..Default::default()
+                            },
+                            visibility: Visibility::Hidden,
+                            ..Default::default()
+                        },
+                        Transform2::from_translation(position).with_depth(DEPTH_LAYER_HEALTHBAR),
+                        HealthbarStatus { kind: *kind },
+                    ));
+                }
             });
     }
 }
@@ -79,6 +113,7 @@ fn healthbar_spawn(mut ev_spawn: EventReader<HealthbarSpawnEvent>, mut commands:
 fn healthbar_update(
     healthbar_query: Query<(&Healthbar, &Health, &Children)>,
     mut healthbar_value_query: Query<(&mut Transform2, &HealthbarValue)>,
+    mut healthbar_status_query: Query<(&mut Visibility, &HealthbarStatus)>,
 ) {
     for (healthbar, health, children) in healthbar_query.iter() {
         for child in children.iter() {
@@ -90,6 +125,13 @@ fn healthbar_update(
                     healthbar_value.offset - Vec2::new(healthbar.size.x * 0.5 * (1. - amount), 0.);
                 healthbar_value_transform.scale = Vec2::new(amount, 1.);
             }
+            if let Ok((mut visibility, healthbar_status)) = healthbar_status_query.get_mut(*child) {
+                *visibility = if health.status_effects.has(healthbar_status.kind) {
+                    Visibility::Inherited
+                } else {
+                    Visibility::Hidden
+                };
+            }
         }
     }
 }
@@ -12,6 +12,7 @@ impl Plugin for OverworldPlugin {
             .add_plugin(world::WorldPlugin)
             .add_plugin(town::TownPlugin)
             .add_plugin(boat::BoatPlugin)
+            .add_plugin(boat_ai::BoatAiPlugin)
             .add_plugin(water_ring::WaterRingPlugin)
             .add_plugin(ocean::OceanPlugin)
             .add_plugin(healthbar::HealthbarPlugin)
@@ -27,9 +28,12 @@ impl Plugin for OverworldPlugin {
             .add_plugin(enemy_spawns::EnemySpawnsPlugin)
             .add_plugin(threat_level::ThreatLevelPlugin)
             .add_plugin(turtle::TurtlePlugin)
+            .add_plugin(pirate::PiratePlugin)
             .add_plugin(experience::ExperiencePlugin)
             .add_plugin(damage_flash::DamageFlashPlugin)
+            .add_plugin(damage_numbers::DamageNumbersPlugin)
             .add_plugin(damage_rum::DamageRumPlugin)
+            .add_plugin(status_effects::StatusEffectsPlugin)
             .add_event::<OverworldEnterEvent>()
             .add_event::<WorldAmbienceSoundStopEvent>()
             .add_system(overworld_init.in_schedule(OnEnter(AppState::Overworld)))
@@ -71,10 +75,6 @@ fn overworld_init(
             .as_looping(),
         WorldAmbienceSound,
     ));
-    commands.spawn((
-        AudioPlusSource::new(asset_library.sound_effects.sfx_overworld_music.clone()).as_looping(),
-        WorldAmbienceSound,
-    ));
 }
 
 fn overworld_init_after_ldtk(
@@ -108,11 +108,13 @@ fn overworld_sound_stop(
 
 pub mod attacks;
 pub mod boat;
+pub mod boat_ai;
 pub mod camera;
 pub mod character_controller;
 pub mod cutscenes;
 pub mod damage;
 pub mod damage_flash;
+pub mod damage_numbers;
 pub mod damage_rum;
 pub mod enemy_spawns;
 pub mod entities;
@@ -121,7 +123,9 @@ pub mod health;
 pub mod healthbar;
 pub mod ocean;
 pub mod octopus;
+pub mod pirate;
 pub mod player;
+pub mod status_effects;
 pub mod threat_level;
 pub mod town;
 pub mod trigger;
@@ -139,7 +139,11 @@ fn octopus_spawn(
                     wander_direction: Vec2::X,
                 },
                 YDepth::default(),
-                Health::new(health),
+                Health::new(health).with_resistances(
+                    DamageResistances::default()
+                        .with(DamageType::Tentacle, 0.75)
+                        .with(DamageType::Shockwave, -0.25),
+                ),
                 Hitbox {
                     shape: CollisionShape::Rect {
                         size: Vec2::new(60., 60.) * scale,
@@ -156,6 +160,9 @@ fn octopus_spawn(
                     flags: DAMAGE_FLAG_PLAYER,
                     knockback_type: HurtboxKnockbackType::None,
                     damage: 1.,
+                    damage_type: DamageType::Ram,
+                    status: None,
+                    crit: DamageCrit::NONE,
                 },
                 Collision {
                     shape: CollisionShape::Rect {
@@ -171,6 +178,7 @@ fn octopus_spawn(
                 },
                 AutoDamage {
                     despawn: true,
+                    kind: AutoDamageKind::Octopus,
                     experience,
                     experience_count,
                     ..Default::default()
@@ -0,0 +1,164 @@
+use crate::common::prelude::*;
+use crate::game::prelude::*;
+use bevy::prelude::*;
+
+const PIRATE_COLLISION_SIZE: Vec2 = Vec2::new(100., 100.);
+
+#[derive(Debug, Hash, PartialEq, Eq, Clone, SystemSet)]
+pub enum PirateSystem {
+    Spawn,
+}
+
+pub struct PiratePlugin;
+
+impl Plugin for PiratePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<PirateSpawnEvent>().add_system(
+            pirate_spawn
+                .in_set(PirateSystem::Spawn)
+                .before(BoatSystem::Spawn),
+        );
+    }
+}
+
+#[derive(Default, Clone, Copy)]
+pub struct PirateSpawnEvent {
+    pub entity: Option<Entity>,
+    pub position: Vec2,
+    pub level: PirateLevel,
+}
+
+#[derive(Default, Clone, Copy)]
+pub enum PirateLevel {
+    #[default]
+    Easy,
+    Medium,
+    Hard,
+}
+
+impl PirateLevel {
+    fn info(&self, asset_library: &AssetLibrary) -> PirateInfo {
+        match *self {
+            Self::Easy => PirateInfo {
+                atlas: asset_library.sprite_ship_green_atlas.clone(),
+                attacks: Attacks {
+                    forward_cannons: 1,
+                    ..Default::default()
+                },
+                attack_cooldown: 1.5,
+                health: 6.,
+                speed: 180.,
+                knockback_resistance: 0.3,
+                experience: 1.,
+                experience_count: 4,
+                ai: BoatAi::new(700., 350., 0.2),
+            },
+            Self::Medium => PirateInfo {
+                atlas: asset_library.sprite_ship_blue_atlas.clone(),
+                attacks: Attacks {
+                    shotgun_cannons: 2,
+                    ..Default::default()
+                },
+                attack_cooldown: 1.2,
+                health: 10.,
+                speed: 220.,
+                knockback_resistance: 0.5,
+                experience: 1.,
+                experience_count: 6,
+                ai: BoatAi::new(800., 300., 0.25),
+            },
+            Self::Hard => PirateInfo {
+                atlas: asset_library.sprite_ship_red_atlas.clone(),
+                attacks: Attacks {
+                    shotgun_cannons: 3,
+                    bombs: 1,
+                    ..Default::default()
+                },
+                attack_cooldown: 1.,
+                health: 16.,
+                speed: 240.,
+                knockback_resistance: 0.7,
+                experience: 2.,
+                experience_count: 5,
+                ai: BoatAi::new(900., 300., 0.15),
+            },
+        }
+    }
+}
+
+struct PirateInfo {
+    atlas: Handle<TextureAtlas>,
+    attacks: Attacks,
+    attack_cooldown: f32,
+    health: f32,
+    speed: f32,
+    knockback_resistance: f32,
+    experience: f32,
+    experience_count: u32,
+    ai: BoatAi,
+}
+
+#[derive(Component)]
+pub struct Pirate;
+
+fn pirate_spawn(
+    mut ev_spawn: EventReader<PirateSpawnEvent>,
+    mut ev_boat_spawn: EventWriter<BoatSpawnEvent>,
+    mut commands: Commands,
+    asset_library: Res<AssetLibrary>,
+    collision_query: Res<CollisionQuery>,
+) {
+    for event in ev_spawn.iter() {
+        if collision_query
+            .check(
+                event.position,
+                CollisionShape::Rect {
+                    size: PIRATE_COLLISION_SIZE * 1.5,
+                },
+                None,
+            )
+            .is_some()
+        {
+            continue;
+        }
+        let PirateInfo {
+            atlas,
+            attacks,
+            attack_cooldown,
+            health,
+            speed,
+            knockback_resistance,
+            experience,
+            experience_count,
+            ai,
+        } = event.level.info(asset_library.as_ref());
+        let mut entity = if let Some(entity) = event.entity {
+            commands.entity(entity)
+        } else {
+            commands.spawn_empty()
+        };
+        entity.insert((
+            Pirate,
+            ai,
+            AutoDamage {
+                despawn: true,
+                experience,
+                experience_count,
+                ..Default::default()
+            },
+        ));
+        ev_boat_spawn.send(BoatSpawnEvent {
+            entity: Some(entity.id()),
+            position: event.position,
+            attack: attacks,
+            attack_cooldown,
+            healthbar: true,
+            player: false,
+            health,
+            health_max: health,
+            speed,
+            knockback_resistance,
+            texture_atlas: atlas,
+        });
+    }
+}
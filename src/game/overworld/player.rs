@@ -5,6 +5,7 @@ use audio_plus::prelude::*;
 use bevy::prelude::*;
 
 pub const PLAYER_ATTACK_COOLDOWN: f32 = 0.48;
+pub const PLAYER_INVINCIBILITY: f32 = 0.7;
 
 pub struct PlayerPlugin;
 
@@ -77,9 +78,7 @@ fn player_spawn(
 
 fn player_controls(
     mut query: Query<(&mut Boat, &GlobalTransform, &Player)>,
-    mouse: Res<Mouse>,
-    input: Res<Input<MouseButton>>,
-    keys: Res<Input<KeyCode>>,
+    player_input: Res<PlayerInput>,
     cutscenes: Res<Cutscenes>,
     game_state: Res<GameState>,
 ) {
@@ -91,17 +90,25 @@ fn player_controls(
             boat.movement = Vec2::ZERO;
             continue;
         }
-        let mut mouse_aim = (mouse.position - global_transform.translation().truncate()) / 200.;
-        if mouse_aim.length_squared() == 0. {
-            mouse_aim = Vec2::new(0.1, 0.);
-        }
-        boat.direction = Vec2::X.angle_between(mouse_aim);
-        boat.movement = mouse_aim;
-        if !input.pressed(MouseButton::Left) {
-            boat.movement *= 0.0001;
+        if player_input.device == PlayerInputDevice::Mouse {
+            let mut mouse_aim =
+                (player_input.cursor - global_transform.translation().truncate()) / 200.;
+            if mouse_aim.length_squared() == 0. {
+                mouse_aim = Vec2::new(0.1, 0.);
+            }
+            boat.direction = Vec2::X.angle_between(mouse_aim);
+            boat.movement = mouse_aim;
+            if !player_input.cursor_move {
+                boat.movement *= 0.0001;
+            }
+        } else {
+            if let Some(aim) = player_input.aim_direction() {
+                boat.direction = Vec2::X.angle_between(aim);
+            }
+            boat.movement = player_input.movement;
         }
-        boat.dash = keys.pressed(KeyCode::Space);
-        if keys.just_pressed(KeyCode::F) {
+        boat.dash = player_input.dash;
+        if player_input.toggle_shoot {
             boat.shoot = !boat.shoot;
         }
         boat.attacks = game_state.attacks;
@@ -146,6 +153,7 @@ fn player_enter_town(
                     to: town_position + Vec2::new(-10., -100.),
                 }));
                 game_state.town = town.town.clone();
+                game_state.quests.side_quests_town_visited(&town.town.name);
                 break 'outer;
             }
         }
@@ -199,43 +207,137 @@ fn player_damage(
     mut overworld_camera: ResMut<OverworldCamera>,
     mut ev_damage_flash_spawn: EventWriter<DamageFlashSpawnEvent>,
     mut ev_damage_rum_spawn: EventWriter<DamageRumSpawnEvent>,
+    mut ev_damage_number_spawn: EventWriter<DamageNumberSpawnEvent>,
     mut commands: Commands,
     asset_library: Res<AssetLibrary>,
 ) {
+    if cutscenes.running() {
+        return;
+    }
     for event in ev_damage.iter() {
         if let Ok((entity, mut health, mut player, global_transform)) =
             crate_query.get_mut(event.hit)
         {
-            if player.invincibility <= 0. {
-                if !cutscenes.running() {
-                    ev_damage_flash_spawn.send_default();
-                    ev_damage_rum_spawn.send(DamageRumSpawnEvent {
-                        position: global_transform.translation().truncate(),
-                    });
-                    overworld_camera.screen_shake(1.);
-                    health.damage(event.damage);
-                    game_state.health = health.value;
-                    let sound = commands
-                        .spawn((
-                            Transform2Bundle::default(),
-                            AudioPlusSource::new(
-                                asset_library
-                                    .sound_effects
-                                    .sfx_overworld_player_damage
-                                    .clone(),
-                            )
-                            .as_playing(),
-                            TimeToLive { seconds: 3. },
-                        ))
-                        .id();
-                    commands.entity(entity).add_child(sound);
-                }
-                if !player.dead && health.dead() {
-                    player.dead = true;
-                    ev_death_cutscene.send_default();
-                }
-                player.invincibility = 0.7;
+            let damage = player_take_damage(&mut player, &mut health, event);
+            if damage == PlayerDamage::Blocked {
+                continue;
+            }
+            ev_damage_number_spawn.send(DamageNumberSpawnEvent {
+                target: entity,
+                position: global_transform.translation().truncate(),
+                amount: event.damage,
+                crit: event.crit,
+                player: true,
+            });
+            game_state.health = health.value;
+            if damage == PlayerDamage::Hit {
+                ev_damage_flash_spawn.send_default();
+                ev_damage_rum_spawn.send(DamageRumSpawnEvent {
+                    position: global_transform.translation().truncate(),
+                });
+                overworld_camera.screen_shake(1.);
+                let sound = commands
+                    .spawn((
+                        Transform2Bundle::default(),
+                        AudioPlusSource::new(
+                            asset_library
+                                .sound_effects
+                                .sfx_overworld_player_damage
+                                .clone(),
+                        )
+                        .as_playing(),
+                        TimeToLive { seconds: 3. },
+                    ))
+                    .id();
+                commands.entity(entity).add_child(sound);
+            }
+            if !player.dead && health.dead() {
+                player.dead = true;
+                ev_death_cutscene.send_default();
             }
         }
     }
 }
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PlayerDamage {
+    Blocked,
+    // damage over time, taken quietly and without restarting invincibility
+    Tick,
+    Hit,
+}
+
+fn player_take_damage(
+    player: &mut Player,
+    health: &mut Health,
+    event: &DamageEvent,
+) -> PlayerDamage {
+    if event.over_time {
+        health.damage(event.damage);
+        PlayerDamage::Tick
+    } else if player.invincibility <= 0. {
+        health.damage(event.damage);
+        player.invincibility = PLAYER_INVINCIBILITY;
+        PlayerDamage::Hit
+    } else {
+        PlayerDamage::Blocked
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn player(invincibility: f32) -> Player {
+        Player {
+            disabled: false,
+            invincibility,
+            dead: false,
+        }
+    }
+
+    fn damage(amount: f32, over_time: bool) -> DamageEvent {
+        DamageEvent {
+            hit: Entity::from_raw(0),
+            hurt: Entity::from_raw(0),
+            damage: amount,
+            damage_type: DamageType::Explosion,
+            status: None,
+            crit: false,
+            over_time,
+        }
+    }
+
+    #[test]
+    fn ticks_go_through_invincibility_without_restarting_it() {
+        let mut player = player(0.3);
+        let mut health = Health::new(10.);
+        let result = player_take_damage(&mut player, &mut health, &damage(1., true));
+        assert_eq!(result, PlayerDamage::Tick);
+        assert_eq!(health.value, 9.);
+        assert_eq!(player.invincibility, 0.3);
+    }
+
+    #[test]
+    fn ticks_never_start_invincibility() {
+        let mut player = player(0.);
+        let mut health = Health::new(10.);
+        player_take_damage(&mut player, &mut health, &damage(1., true));
+        assert_eq!(player.invincibility, 0.);
+        let result = player_take_damage(&mut player, &mut health, &damage(2., false));
+        assert_eq!(result, PlayerDamage::Hit);
+        assert_eq!(health.value, 7.);
+    }
+
+    #[test]
+    fn hits_are_blocked_while_invincible() {
+        let mut player = player(0.);
+        let mut health = Health::new(10.);
+        let result = player_take_damage(&mut player, &mut health, &damage(2., false));
+        assert_eq!(result, PlayerDamage::Hit);
+        assert_eq!(player.invincibility, PLAYER_INVINCIBILITY);
+        let result = player_take_damage(&mut player, &mut health, &damage(2., false));
+        assert_eq!(result, PlayerDamage::Blocked);
+        assert_eq!(health.value, 8.);
+    }
+}
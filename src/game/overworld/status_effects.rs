@@ -0,0 +1,142 @@
+use crate::common::prelude::*;
+use crate::game::prelude::*;
+use bevy::prelude::*;
+
+const BURNING_TICK: f32 = 0.5;
+
+pub struct StatusEffectsPlugin;
+
+impl Plugin for StatusEffectsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system(status_effects_update.in_set(DamageSystem::Check))
+            .add_system(status_effects_apply.after(DamageSystem::Check));
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StatusEffectKind {
+    Burning,
+    Slowed,
+    Stunned,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct StatusEffect {
+    pub kind: StatusEffectKind,
+    pub seconds: f32,
+    // damage per second while burning, fraction of speed lost while slowed
+    pub strength: f32,
+}
+
+impl StatusEffect {
+    pub fn burning(seconds: f32, damage_per_second: f32) -> Self {
+        Self {
+            kind: StatusEffectKind::Burning,
+            seconds,
+            strength: damage_per_second,
+        }
+    }
+
+    pub fn slowed(seconds: f32, amount: f32) -> Self {
+        Self {
+            kind: StatusEffectKind::Slowed,
+            seconds,
+            strength: amount,
+        }
+    }
+
+    pub fn stunned(seconds: f32) -> Self {
+        Self {
+            kind: StatusEffectKind::Stunned,
+            seconds,
+            strength: 1.,
+        }
+    }
+}
+
+#[derive(Default, Clone)]
+pub struct StatusEffects {
+    effects: Vec<StatusEffect>,
+    burning_tick: f32,
+}
+
+impl StatusEffects {
+    pub fn apply(&mut self, effect: StatusEffect) {
+        if effect.seconds <= 0. {
+            return;
+        }
+        if let Some(existing) = self.effects.iter_mut().find(|e| e.kind == effect.kind) {
+            existing.seconds = existing.seconds.max(effect.seconds);
+            existing.strength = existing.strength.max(effect.strength);
+        } else {
+            if effect.kind == StatusEffectKind::Burning {
+                self.burning_tick = BURNING_TICK;
+            }
+            self.effects.push(effect);
+        }
+    }
+
+    pub fn get(&self, kind: StatusEffectKind) -> Option<&StatusEffect> {
+        self.effects.iter().find(|effect| effect.kind == kind)
+    }
+
+    pub fn has(&self, kind: StatusEffectKind) -> bool {
+        self.get(kind).is_some()
+    }
+
+    pub fn speed_multiplier(&self) -> f32 {
+        if self.has(StatusEffectKind::Stunned) {
+            return 0.;
+        }
+        self.get(StatusEffectKind::Slowed)
+            .map(|slowed| (1. - slowed.strength).clamp(0., 1.))
+            .unwrap_or(1.)
+    }
+}
+
+fn status_effects_update(
+    mut query: Query<(Entity, &mut Health)>,
+    mut ev_damage: EventWriter<DamageEvent>,
+    time: Res<Time>,
+    cutscenes: Res<Cutscenes>,
+) {
+    if cutscenes.running() {
+        return;
+    }
+    for (entity, mut health) in query.iter_mut() {
+        if health.status_effects.effects.is_empty() {
+            continue;
+        }
+        let status_effects = &mut health.status_effects;
+        if let Some(burning) = status_effects.get(StatusEffectKind::Burning).copied() {
+            status_effects.burning_tick -= time.delta_seconds();
+            if status_effects.burning_tick <= 0. {
+                status_effects.burning_tick += BURNING_TICK;
+                ev_damage.send(DamageEvent {
+                    hit: entity,
+                    hurt: entity,
+                    damage: burning.strength * BURNING_TICK,
+                    damage_type: DamageType::Explosion,
+                    status: None,
+                    crit: false,
+                    over_time: true,
+                });
+            }
+        }
+        for effect in status_effects.effects.iter_mut() {
+            effect.seconds -= time.delta_seconds();
+        }
+        status_effects.effects.retain(|effect| effect.seconds > 0.);
+    }
+}
+
+fn status_effects_apply(mut ev_damage: EventReader<DamageEvent>, mut query: Query<&mut Health>) {
+    for event in ev_damage.iter() {
+        let Some(status) = event.status else { continue };
+        if let Ok(mut health) = query.get_mut(event.hit) {
+            if !health.dead() {
+                health.status_effects.apply(status);
+            }
+        }
+    }
+}
@@ -38,25 +38,16 @@ fn threat_level_update(
     };
     *threat_level = ThreatLevel::None;
 
-    macro_rules! disable_threat_level_near_position {
-        ($str:literal, $condition:expr) => {
-            if $condition
-                && player_position.distance(world_locations.get_single_position($str)) < 500.
-            {
-                return;
-            }
-        };
-    }
-
-    if game_state.quests.davy() && game_state.quests.fighting() {
-        *threat_level = ThreatLevel::Davy;
+    if let Some(level) = game_state.quests.fight_threat_level() {
+        *threat_level = level;
         return;
     }
 
-    disable_threat_level_near_position!("JagerossaTrigger", game_state.quests.jagerossa());
-    disable_threat_level_near_position!("RingoTrigger", game_state.quests.ringo());
-    disable_threat_level_near_position!("PlankTrigger", game_state.quests.plank());
-    disable_threat_level_near_position!("DavyTrigger", game_state.quests.davy());
+    if let Some(trigger) = game_state.quests.trigger() {
+        if player_position.distance(world_locations.get_single_position(trigger)) < 500. {
+            return;
+        }
+    }
 
     macro_rules! threat_level {
         ($str:literal, $value:expr) => {
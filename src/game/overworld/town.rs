@@ -68,6 +68,7 @@ fn town_spawn(
                         ..Default::default()
                     },
                     Transform2::from_xy(0., 135.).with_depth(DEPTH_LAYER_TOWN_NAME),
+                    LocalizedText::new(&town_locale_id(&event.town.name)),
                 ));
             });
     }
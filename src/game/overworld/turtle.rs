@@ -129,7 +129,12 @@ fn turtle_spawn(
                     sprite_angle: 0.,
                 },
                 YDepth::default(),
-                Health::new(info.health),
+                // the shell shrugs off cannonballs but not being blown up
+                Health::new(info.health).with_resistances(
+                    DamageResistances::default()
+                        .with(DamageType::Cannon, 0.5)
+                        .with(DamageType::Explosion, -0.25),
+                ),
                 Hitbox {
                     shape: CollisionShape::Rect {
                         size: Vec2::new(80., 80.) * info.scale,
@@ -146,6 +151,9 @@ fn turtle_spawn(
                     flags: DAMAGE_FLAG_PLAYER,
                     knockback_type: HurtboxKnockbackType::None,
                     damage: 1.,
+                    damage_type: DamageType::Ram,
+                    status: None,
+                    crit: DamageCrit::NONE,
                 },
                 Collision {
                     shape: CollisionShape::Rect {
@@ -161,6 +169,7 @@ fn turtle_spawn(
                 },
                 AutoDamage {
                     despawn: true,
+                    kind: AutoDamageKind::Turtle,
                     experience: info.experience,
                     experience_count: info.experience_count,
                     ..Default::default()
@@ -20,6 +20,7 @@ impl Plugin for BossHealthbarPlugin {
 
 #[derive(Clone)]
 pub struct BossHealthbarSpawnEvent {
+    // locale string id
     pub name: String,
     pub entity: Entity,
 }
@@ -88,6 +89,7 @@ fn boss_healthbar_spawn(
                     },
                     Transform2::from_translation(Vec2::new(0., 332.) + OFFSET)
                         .with_depth(DEPTH_LAYER_UI_BOSS_HEALTHBAR_NAME),
+                    LocalizedText::new(&event.name),
                 ));
                 parent.spawn((
                     SpriteBundle {
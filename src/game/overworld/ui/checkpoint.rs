@@ -46,7 +46,7 @@ fn checkpoint_spawn(
                 parent.spawn((
                     Text2dBundle {
                         text: Text::from_section(
-                            "Checkpoint",
+                            "",
                             TextStyle {
                                 font: asset_library.font_bold.clone(),
                                 font_size: 62.0,
@@ -59,6 +59,7 @@ fn checkpoint_spawn(
                     },
                     Transform2::from_translation(CHECKPOINT_POSITION)
                         .with_depth(DEPTH_LAYER_CHECKPOINT_TEXT),
+                    LocalizedText::new("hud.checkpoint"),
                 ));
             });
     }
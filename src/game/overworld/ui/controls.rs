@@ -1,5 +1,5 @@
 use crate::{common::prelude::*, game::prelude::*};
-use bevy::prelude::*;
+use bevy::{prelude::*, sprite::Anchor};
 
 const CONTROLS_UI_POSITION: Vec2 = Vec2::new(-260., -315.);
 const CONTROLS_UI_SCALE: f32 = 0.55;
@@ -12,7 +12,8 @@ impl Plugin for ControlsUiPlugin {
             .add_system(controls_ui_spawn)
             .add_system(controls_ui_update_dash)
             .add_system(controls_ui_update_jam)
-            .add_system(controls_ui_update_map);
+            .add_system(controls_ui_update_map)
+            .add_system(controls_ui_update_bindings);
     }
 }
 
@@ -28,6 +29,35 @@ pub struct ControlsUiJam;
 #[derive(Component)]
 pub struct ControlsUiMap;
 
+#[derive(Component)]
+pub struct ControlsUiBinding {
+    action: InputAction,
+}
+
+fn controls_ui_binding_text(
+    action: InputAction,
+    position: Vec2,
+    asset_library: &AssetLibrary,
+) -> impl Bundle {
+    (
+        Text2dBundle {
+            text: Text::from_section(
+                "",
+                TextStyle {
+                    font: asset_library.font_bold.clone(),
+                    font_size: 40.,
+                    color: Color::WHITE,
+                },
+            )
+            .with_alignment(TextAlignment::Center),
+            text_anchor: Anchor::Center,
+            ..Default::default()
+        },
+        Transform2::from_translation(position).with_depth(DEPTH_LAYER_UI_CONTROLS_BINDING),
+        ControlsUiBinding { action },
+    )
+}
+
 fn controls_ui_spawn(
     mut ev_spawn: EventReader<ControlsUiSpawnEvent>,
     mut commands: Commands,
@@ -62,6 +92,11 @@ fn controls_ui_spawn(
                                 .with_depth(DEPTH_LAYER_UI_CONTROLS),
                             ControlsUiDash,
                         ));
+                        parent.spawn(controls_ui_binding_text(
+                            InputAction::Dash,
+                            Vec2::new(-50., 72.),
+                            asset_library.as_ref(),
+                        ));
                         parent
                             .spawn((
                                 SpriteSheetBundle {
@@ -83,6 +118,11 @@ fn controls_ui_spawn(
                                         .with_depth(DEPTH_LAYER_UI_CONTROLS_KEY),
                                 ));
                             });
+                        parent.spawn(controls_ui_binding_text(
+                            InputAction::ToggleShoot,
+                            Vec2::new(190., 72.),
+                            asset_library.as_ref(),
+                        ));
                         parent.spawn((
                             SpriteBundle {
                                 texture: asset_library.sprite_controls_map.clone(),
@@ -94,6 +134,11 @@ fn controls_ui_spawn(
                                 .with_depth(DEPTH_LAYER_UI_CONTROLS),
                             ControlsUiMap,
                         ));
+                        parent.spawn(controls_ui_binding_text(
+                            InputAction::Map,
+                            Vec2::new(430., 72.),
+                            asset_library.as_ref(),
+                        ));
                     });
             });
     }
@@ -153,3 +198,26 @@ pub fn controls_ui_update_map(
         };
     }
 }
+
+pub fn controls_ui_update_bindings(
+    mut query: Query<(&mut Text, &ControlsUiBinding)>,
+    mapping: Res<PlayerInputMapping>,
+    player_input: Res<PlayerInput>,
+) {
+    for (mut text, binding) in query.iter_mut() {
+        let label = if player_input.device == PlayerInputDevice::Gamepad {
+            mapping
+                .gamepad_button(binding.action)
+                .map(|button| format!("{:?}", button))
+                .unwrap_or_default()
+        } else {
+            mapping
+                .binding(binding.action)
+                .map(|binding| binding.label())
+                .unwrap_or_default()
+        };
+        if text.sections[0].value != label {
+            text.sections[0].value = label;
+        }
+    }
+}
@@ -0,0 +1,254 @@
+use crate::common::prelude::*;
+use crate::game::prelude::*;
+use audio_plus::prelude::*;
+use bevy::input::mouse::MouseWheel;
+use bevy::prelude::*;
+use bevy::sprite::Anchor;
+use std::collections::VecDeque;
+
+const DIALOGUE_LOG_LEFT: f32 = -260.;
+const DIALOGUE_LOG_TOP: f32 = 250.;
+const DIALOGUE_LOG_LINE_HEIGHT: f32 = 30.;
+const DIALOGUE_LOG_VISIBLE_LINES: usize = 17;
+const DIALOGUE_LOG_WRAP: usize = 48;
+
+pub struct DialogueLogPlugin;
+
+impl Plugin for DialogueLogPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_cutscene::<DialogueLogCutscene>()
+            .add_system(dialogue_log_record)
+            .add_system(dialogue_log_input.in_set(OnUpdate(AppState::Overworld)));
+    }
+}
+
+#[derive(Default, Debug, Clone, Resource)]
+pub struct DialogueLogCutscene;
+
+impl Cutscene for DialogueLogCutscene {
+    fn build(cutscene: &mut CutsceneBuilder) {
+        cutscene.add_step(dialogue_log_open, dialogue_log_wait_for_close);
+        cutscene.add_quick_step(dialogue_log_close);
+    }
+}
+
+#[derive(Component)]
+pub struct DialogueLog {
+    scroll: usize,
+    lines: usize,
+}
+
+#[derive(Component)]
+pub struct DialogueLogLine {
+    index: usize,
+}
+
+fn dialogue_log_record(
+    mut ev_log: EventReader<DialogueLogEvent>,
+    mut game_state: ResMut<GameState>,
+) {
+    for event in ev_log.iter() {
+        game_state.record_dialogue(event.entry.clone());
+    }
+}
+
+fn dialogue_log_row(index: usize, scroll: usize) -> Option<usize> {
+    let row = index.checked_sub(scroll)?;
+    if row < DIALOGUE_LOG_VISIBLE_LINES {
+        Some(row)
+    } else {
+        None
+    }
+}
+
+// splits on words so each line fits the parchment
+fn dialogue_log_wrap(text: &str) -> Vec<String> {
+    let mut lines = vec![];
+    let mut line = String::new();
+    for word in text.split_whitespace() {
+        if !line.is_empty() && line.chars().count() + word.chars().count() + 1 > DIALOGUE_LOG_WRAP {
+            lines.push(std::mem::take(&mut line));
+        }
+        if !line.is_empty() {
+            line.push(' ');
+        }
+        line.push_str(word);
+    }
+    if !line.is_empty() {
+        lines.push(line);
+    }
+    lines
+}
+
+fn dialogue_log_lines(
+    history: &VecDeque<DialogueHistoryEntry>,
+    locale: &Locale,
+) -> Vec<(String, bool)> {
+    let mut lines = vec![];
+    lines.push((locale.get("dialogue_log.title").to_owned(), true));
+    if history.is_empty() {
+        lines.push((locale.get("dialogue_log.empty").to_owned(), false));
+    }
+    for entry in history.iter() {
+        lines.push(("".to_owned(), false));
+        lines.push((entry.name.clone(), true));
+        for line in dialogue_log_wrap(&entry.text) {
+            lines.push((format!("    {}", line), false));
+        }
+    }
+    lines
+}
+
+fn dialogue_log_open(
+    mut commands: Commands,
+    asset_library: Res<AssetLibrary>,
+    game_state: Res<GameState>,
+    locale: Res<Locale>,
+) {
+    let lines = dialogue_log_lines(&game_state.dialogue_history, &locale);
+    let scroll = lines.len().saturating_sub(DIALOGUE_LOG_VISIBLE_LINES);
+    commands
+        .spawn((
+            VisibilityBundle::default(),
+            TransformBundle::default(),
+            Transform2::from_xy(0., 0.).without_pixel_perfect(),
+            FollowCamera { offset: Vec2::ZERO },
+            DialogueLog {
+                scroll,
+                lines: lines.len(),
+            },
+            AudioPlusSource::new(asset_library.sound_effects.sfx_overworld_map_open.clone())
+                .as_playing(),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                SpriteBundle {
+                    texture: asset_library.sprite_map_bg.clone(),
+                    ..Default::default()
+                },
+                Transform2::new().with_depth(DEPTH_LAYER_DIALOGUE_LOG_BACK),
+            ));
+            for (index, (line, header)) in lines.into_iter().enumerate() {
+                let row = dialogue_log_row(index, scroll);
+                parent.spawn((
+                    Text2dBundle {
+                        text: Text::from_section(
+                            line,
+                            TextStyle {
+                                font: if header {
+                                    asset_library.font_bold.clone()
+                                } else {
+                                    asset_library.font_default.clone()
+                                },
+                                font_size: if header { 26. } else { 22. },
+                                color: Color::BLACK,
+                            },
+                        )
+                        .with_alignment(TextAlignment::Left),
+                        text_anchor: Anchor::CenterRight,
+                        visibility: if row.is_some() {
+                            Visibility::Inherited
+                        } else {
+                            Visibility::Hidden
+                        },
+                        ..Default::default()
+                    },
+                    Transform2::from_xy(
+                        DIALOGUE_LOG_LEFT,
+                        DIALOGUE_LOG_TOP - row.unwrap_or(0) as f32 * DIALOGUE_LOG_LINE_HEIGHT,
+                    )
+                    .with_depth(DEPTH_LAYER_DIALOGUE_LOG_TEXT),
+                    DialogueLogLine { index },
+                ));
+            }
+        });
+}
+
+fn dialogue_log_wait_for_close(
+    input: Res<Input<KeyCode>>,
+    mouse: Res<Input<MouseButton>>,
+    mut ev_mouse_wheel: EventReader<MouseWheel>,
+    gamepads: Res<Gamepads>,
+    gamepad_buttons: Res<Input<GamepadButton>>,
+    mapping: Res<PlayerInputMapping>,
+    mut ev_continue: EventWriter<CutsceneContinueEvent<DialogueLogCutscene>>,
+    mut log_query: Query<&mut DialogueLog>,
+    mut line_query: Query<(&mut Transform2, &mut Visibility, &DialogueLogLine)>,
+    asset_library: Res<AssetLibrary>,
+    mut commands: Commands,
+) {
+    let mut scroll = 0;
+    for event in ev_mouse_wheel.iter() {
+        scroll -= event.y.signum() as i32;
+    }
+    for (action, direction) in [
+        (InputAction::MoveUp, -1),
+        (InputAction::AimUp, -1),
+        (InputAction::MoveDown, 1),
+        (InputAction::AimDown, 1),
+    ] {
+        if mapping.just_pressed(action, &input, &mouse) {
+            scroll += direction;
+        }
+    }
+    for mut log in log_query.iter_mut() {
+        if scroll == 0 {
+            continue;
+        }
+        let max_scroll = log.lines.saturating_sub(DIALOGUE_LOG_VISIBLE_LINES) as i32;
+        log.scroll = (log.scroll as i32 + scroll).clamp(0, max_scroll) as usize;
+        for (mut transform, mut visibility, line) in line_query.iter_mut() {
+            let row = dialogue_log_row(line.index, log.scroll);
+            *visibility = if row.is_some() {
+                Visibility::Inherited
+            } else {
+                Visibility::Hidden
+            };
+            if let Some(row) = row {
+                transform.translation.y = DIALOGUE_LOG_TOP - row as f32 * DIALOGUE_LOG_LINE_HEIGHT;
+            }
+        }
+    }
+
+    if input.just_pressed(KeyCode::Space)
+        || mouse.just_pressed(MouseButton::Left)
+        || mapping.just_pressed(InputAction::DialogueLog, &input, &mouse)
+        || mapping.gamepad_just_pressed(InputAction::DialogueLog, &gamepads, &gamepad_buttons)
+    {
+        ev_continue.send_default();
+
+        commands.spawn((
+            Transform2Bundle::default(),
+            AudioPlusSource::new(asset_library.sound_effects.sfx_overworld_map_close.clone())
+                .as_playing(),
+            TimeToLive { seconds: 3. },
+        ));
+    }
+}
+
+fn dialogue_log_close(query: Query<Entity, With<DialogueLog>>, mut commands: Commands) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+fn dialogue_log_input(
+    input: Res<Input<KeyCode>>,
+    mouse: Res<Input<MouseButton>>,
+    gamepads: Res<Gamepads>,
+    gamepad_buttons: Res<Input<GamepadButton>>,
+    mapping: Res<PlayerInputMapping>,
+    cutscenes: Res<Cutscenes>,
+    state_time: Res<StateTime<AppState>>,
+    mut ev_cutscene: EventWriter<CutsceneStartEvent<DialogueLogCutscene>>,
+    game_state: Res<GameState>,
+) {
+    if state_time.time > 1.
+        && (mapping.just_pressed(InputAction::DialogueLog, &input, &mouse)
+            || mapping.gamepad_just_pressed(InputAction::DialogueLog, &gamepads, &gamepad_buttons))
+        && !cutscenes.running()
+        && !game_state.quests.fighting()
+    {
+        ev_cutscene.send_default();
+    }
+}
@@ -90,7 +90,7 @@ fn experience_ui_spawn(
                         parent.spawn((
                             Text2dBundle {
                                 text: Text::from_section(
-                                    "Lvl",
+                                    "",
                                     TextStyle {
                                         font: asset_library.font_bold.clone(),
                                         font_size: EXPERIENCE_UI_LEVEL_LABEL_FONT_SIZE,
@@ -103,6 +103,7 @@ fn experience_ui_spawn(
                             },
                             Transform2::from_translation(EXPERIENCE_UI_LEVEL_LABEL_POSITION)
                                 .with_depth(DEPTH_LAYER_UI_EXPERIENCE_LEVEL),
+                            LocalizedText::new("hud.level"),
                         ));
                         parent.spawn((
                             Text2dBundle {
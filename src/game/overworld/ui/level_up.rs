@@ -28,10 +28,12 @@ fn level_up_spawn(
     asset_library: Res<AssetLibrary>,
     time: Res<Time>,
     mut state: ResMut<LevelUpState>,
+    mut music_director: ResMut<AudioPlusMusicDirector>,
 ) {
     for _ in ev_spawn.iter() {
         if time.elapsed_seconds() > state.last_spawn_time + 4.5 {
             state.last_spawn_time = time.elapsed_seconds();
+            music_director.play_stinger(asset_library.sound_effects.sfx_overworld_level_up.clone());
             commands
                 .spawn((
                     VisibilityBundle::default(),
@@ -39,10 +41,6 @@ fn level_up_spawn(
                     FollowCamera { offset: Vec2::ZERO },
                     Transform2::new().without_pixel_perfect(),
                     TimeToLive { seconds: 4.5 },
-                    AudioPlusSource::new(
-                        asset_library.sound_effects.sfx_overworld_level_up.clone(),
-                    )
-                    .as_playing(),
                 ))
                 .with_children(|parent| {
                     parent.spawn((
@@ -61,7 +59,7 @@ fn level_up_spawn(
                     parent.spawn((
                         Text2dBundle {
                             text: Text::from_section(
-                                "Level Up",
+                                "",
                                 TextStyle {
                                     font: asset_library.font_bold.clone(),
                                     font_size: 62.0,
@@ -74,11 +72,12 @@ fn level_up_spawn(
                         },
                         Transform2::from_translation(LEVEL_UP_POSITION + Vec2::new(0., 11.))
                             .with_depth(DEPTH_LAYER_LEVEL_UP_TEXT),
+                        LocalizedText::new("hud.level_up"),
                     ));
                     parent.spawn((
                         Text2dBundle {
                             text: Text::from_section(
-                                "Spend skill points at town",
+                                "",
                                 TextStyle {
                                     font: asset_library.font_bold.clone(),
                                     font_size: 22.0,
@@ -91,6 +90,7 @@ fn level_up_spawn(
                         },
                         Transform2::from_translation(LEVEL_UP_POSITION + Vec2::new(0., -21.))
                             .with_depth(DEPTH_LAYER_LEVEL_UP_TEXT),
+                        LocalizedText::new("hud.spend_skill_points"),
                     ));
                 });
         }
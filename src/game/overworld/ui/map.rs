@@ -178,13 +178,17 @@ fn map_open(
 fn map_wait_for_close(
     input: Res<Input<KeyCode>>,
     mouse: Res<Input<MouseButton>>,
+    gamepads: Res<Gamepads>,
+    gamepad_buttons: Res<Input<GamepadButton>>,
+    mapping: Res<PlayerInputMapping>,
     mut ev_continue: EventWriter<CutsceneContinueEvent<MapCutscene>>,
     asset_library: Res<AssetLibrary>,
     mut commands: Commands,
 ) {
     if input.just_pressed(KeyCode::Space)
         || mouse.just_pressed(MouseButton::Left)
-        || input.just_pressed(KeyCode::M)
+        || mapping.just_pressed(InputAction::Map, &input, &mouse)
+        || mapping.gamepad_just_pressed(InputAction::Map, &gamepads, &gamepad_buttons)
     {
         ev_continue.send_default();
 
@@ -216,13 +220,18 @@ pub struct MapObjective {
 
 fn map_input(
     input: Res<Input<KeyCode>>,
+    mouse: Res<Input<MouseButton>>,
+    gamepads: Res<Gamepads>,
+    gamepad_buttons: Res<Input<GamepadButton>>,
+    mapping: Res<PlayerInputMapping>,
     cutscenes: Res<Cutscenes>,
     state_time: Res<StateTime<AppState>>,
     mut ev_cutscene: EventWriter<CutsceneStartEvent<MapCutscene>>,
     game_state: Res<GameState>,
 ) {
     if state_time.time > 1.
-        && input.just_pressed(KeyCode::M)
+        && (mapping.just_pressed(InputAction::Map, &input, &mouse)
+            || mapping.gamepad_just_pressed(InputAction::Map, &gamepads, &gamepad_buttons))
         && !cutscenes.running()
         && game_state.dangerous_seas
         && !game_state.quests.fighting()
@@ -16,6 +16,9 @@ impl Plugin for OverworldUiPlugin {
     fn build(&self, app: &mut App) {
         app.add_event::<OverworldUiSpawnEvent>()
             .add_plugin(map::MapPlugin)
+            .add_plugin(quest_log::QuestLogPlugin)
+            .add_plugin(dialogue_log::DialogueLogPlugin)
+            .add_plugin(pause::PausePlugin)
             .add_plugin(marker::MarkerPlugin)
             .add_plugin(town_marker::TownMarkerPlugin)
             .add_plugin(objective::ObjectivePlugin)
@@ -65,6 +68,7 @@ fn overworld_ui_spawn(
 pub mod boss_healthbar;
 pub mod checkpoint;
 pub mod controls;
+pub mod dialogue_log;
 pub mod experience;
 pub mod health;
 pub mod health_aura;
@@ -72,5 +76,7 @@ pub mod level_up;
 pub mod map;
 pub mod marker;
 pub mod objective;
+pub mod pause;
+pub mod quest_log;
 pub mod town_marker;
 pub mod vignette;
@@ -2,6 +2,11 @@ use crate::common::prelude::*;
 use crate::game::prelude::*;
 use bevy::prelude::*;
 use bevy::sprite::Anchor;
+use bevy::text::TextLayoutInfo;
+use bevy::window::PrimaryWindow;
+
+// room left around the measured text for the objective icon
+const OBJECTIVE_PADDING: f32 = 24.;
 
 pub struct ObjectivePlugin;
 
@@ -91,10 +96,24 @@ fn objective_spawn(
 fn objective_update(
     mut background_query: Query<&mut Transform2, With<ObjectiveBackground>>,
     mut hud_query: Query<&mut Visibility, With<ObjectiveHud>>,
-    mut text_query: Query<&mut Text, With<ObjectiveText>>,
+    mut text_query: Query<(&mut Text, &TextLayoutInfo), With<ObjectiveText>>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
     game_state: Res<GameState>,
+    locale: Res<Locale>,
 ) {
-    if let Some((width, str)) = game_state.quests.objective() {
+    if let Some(id) = game_state.quests.objective() {
+        // layout sizes are in physical pixels
+        let scale_factor = window_query
+            .get_single()
+            .map_or(1., |window| window.scale_factor() as f32);
+        let mut width = 0.;
+        for (mut text, layout) in text_query.iter_mut() {
+            let value = locale.get(id);
+            if text.sections[0].value != value {
+                text.sections[0].value = value.to_owned();
+            }
+            width = layout.size.x / scale_factor + OBJECTIVE_PADDING;
+        }
         for mut background_transform in background_query.iter_mut() {
             background_transform.scale.x = width;
             background_transform.translation.x = 625. - width * 0.5;
@@ -102,11 +121,6 @@ fn objective_update(
         for mut hud_visibility in hud_query.iter_mut() {
             *hud_visibility = Visibility::Inherited;
         }
-        for mut text in text_query.iter_mut() {
-            if text.sections[0].value != str {
-                text.sections[0].value = str.to_owned();
-            }
-        }
     } else {
         for mut background_transform in background_query.iter_mut() {
             background_transform.scale.x = 0.;
@@ -114,7 +128,7 @@ fn objective_update(
         for mut hud_visibility in hud_query.iter_mut() {
             *hud_visibility = Visibility::Hidden;
         }
-        for mut text in text_query.iter_mut() {
+        for (mut text, _) in text_query.iter_mut() {
             if text.sections[0].value != "" {
                 text.sections[0].value = "".to_owned();
             }
@@ -0,0 +1,121 @@
+use crate::common::{audio_settings::spawn_audio_settings_panel, prelude::*};
+use crate::game::prelude::*;
+use audio_plus::prelude::*;
+use bevy::prelude::*;
+use bevy::sprite::Anchor;
+
+pub struct PausePlugin;
+
+impl Plugin for PausePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_cutscene::<PauseCutscene>()
+            .add_system(pause_input.in_set(OnUpdate(AppState::Overworld)));
+    }
+}
+
+#[derive(Default, Debug, Clone, Resource)]
+pub struct PauseCutscene;
+
+impl Cutscene for PauseCutscene {
+    fn build(cutscene: &mut CutsceneBuilder) {
+        cutscene.add_step(pause_open, pause_wait_for_close);
+        cutscene.add_quick_step(pause_close);
+    }
+}
+
+#[derive(Component)]
+pub struct Pause;
+
+fn pause_open(mut commands: Commands, asset_library: Res<AssetLibrary>) {
+    commands
+        .spawn((
+            VisibilityBundle::default(),
+            TransformBundle::default(),
+            Transform2::from_xy(0., 0.).without_pixel_perfect(),
+            FollowCamera { offset: Vec2::ZERO },
+            Pause,
+            AudioPlusSource::new(asset_library.sound_effects.sfx_overworld_map_open.clone())
+                .as_playing(),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                SpriteBundle {
+                    texture: asset_library.sprite_map_bg.clone(),
+                    ..Default::default()
+                },
+                Transform2::new().with_depth(DEPTH_LAYER_PAUSE_BACK),
+            ));
+            parent.spawn((
+                Text2dBundle {
+                    text: Text::from_section(
+                        "",
+                        TextStyle {
+                            font: asset_library.font_bold.clone(),
+                            font_size: 48.,
+                            color: Color::BLACK,
+                        },
+                    )
+                    .with_alignment(TextAlignment::Center),
+                    text_anchor: Anchor::Center,
+                    ..Default::default()
+                },
+                Transform2::from_xy(0., 230.).with_depth(DEPTH_LAYER_PAUSE_PANEL),
+                LocalizedText::new("hud.paused"),
+            ));
+            spawn_audio_settings_panel(
+                parent,
+                &asset_library,
+                Vec2::new(90., 150.),
+                DEPTH_LAYER_PAUSE_PANEL,
+            );
+        });
+}
+
+fn pause_wait_for_close(
+    input: Res<Input<KeyCode>>,
+    mouse: Res<Input<MouseButton>>,
+    gamepads: Res<Gamepads>,
+    gamepad_buttons: Res<Input<GamepadButton>>,
+    mapping: Res<PlayerInputMapping>,
+    mut ev_continue: EventWriter<CutsceneContinueEvent<PauseCutscene>>,
+    asset_library: Res<AssetLibrary>,
+    mut commands: Commands,
+) {
+    if mapping.just_pressed(InputAction::Pause, &input, &mouse)
+        || mapping.gamepad_just_pressed(InputAction::Pause, &gamepads, &gamepad_buttons)
+    {
+        ev_continue.send_default();
+
+        commands.spawn((
+            Transform2Bundle::default(),
+            AudioPlusSource::new(asset_library.sound_effects.sfx_overworld_map_close.clone())
+                .as_playing(),
+            TimeToLive { seconds: 3. },
+        ));
+    }
+}
+
+fn pause_close(query: Query<Entity, With<Pause>>, mut commands: Commands) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+fn pause_input(
+    input: Res<Input<KeyCode>>,
+    mouse: Res<Input<MouseButton>>,
+    gamepads: Res<Gamepads>,
+    gamepad_buttons: Res<Input<GamepadButton>>,
+    mapping: Res<PlayerInputMapping>,
+    cutscenes: Res<Cutscenes>,
+    state_time: Res<StateTime<AppState>>,
+    mut ev_cutscene: EventWriter<CutsceneStartEvent<PauseCutscene>>,
+) {
+    if state_time.time > 1.
+        && (mapping.just_pressed(InputAction::Pause, &input, &mouse)
+            || mapping.gamepad_just_pressed(InputAction::Pause, &gamepads, &gamepad_buttons))
+        && !cutscenes.running()
+    {
+        ev_cutscene.send_default();
+    }
+}
@@ -0,0 +1,199 @@
+use crate::common::prelude::*;
+use crate::game::prelude::*;
+use audio_plus::prelude::*;
+use bevy::prelude::*;
+use bevy::sprite::Anchor;
+
+const QUEST_LOG_LEFT: f32 = -260.;
+const QUEST_LOG_TOP: f32 = 250.;
+const QUEST_LOG_LINE_HEIGHT: f32 = 30.;
+
+pub struct QuestLogPlugin;
+
+impl Plugin for QuestLogPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_cutscene::<QuestLogCutscene>()
+            .add_system(quest_log_input.in_set(OnUpdate(AppState::Overworld)));
+    }
+}
+
+#[derive(Default, Debug, Clone, Resource)]
+pub struct QuestLogCutscene;
+
+impl Cutscene for QuestLogCutscene {
+    fn build(cutscene: &mut CutsceneBuilder) {
+        cutscene.add_step(quest_log_open, quest_log_wait_for_close);
+        cutscene.add_quick_step(quest_log_close);
+    }
+}
+
+#[derive(Component)]
+pub struct QuestLog;
+
+fn quest_log_lines(quests: &Quests, locale: &Locale) -> Vec<(String, bool)> {
+    let mut lines = vec![];
+    lines.push((locale.get("quest_log.title").to_owned(), true));
+    if let Some(definition) = quests.definition() {
+        lines.push((locale.get(definition.name).to_owned(), false));
+        lines.push((
+            format!(
+                "    {}",
+                locale.format("quest_log.reward", &[definition.reward.label()])
+            ),
+            false,
+        ));
+    }
+    for side_quest in quests.active_side_quests() {
+        let Some(definition) = side_quest.definition() else { continue };
+        lines.push((
+            locale.format(
+                "quest_log.side_quest",
+                &[
+                    definition.name,
+                    definition.description,
+                    &side_quest.progress.to_string(),
+                    &definition.objective.goal().to_string(),
+                ],
+            ),
+            false,
+        ));
+        lines.push((
+            format!(
+                "    {}",
+                locale.format("quest_log.reward", &[&definition.reward.label(locale)])
+            ),
+            false,
+        ));
+    }
+    lines.push(("".to_owned(), false));
+    lines.push((locale.get("quest_log.completed").to_owned(), true));
+    for definition in quests.completed_quests() {
+        lines.push((
+            locale.format(
+                "quest_log.completed_quest",
+                &[definition.name, definition.reward.label()],
+            ),
+            false,
+        ));
+    }
+    for side_quest in quests.completed_side_quests() {
+        let Some(definition) = side_quest.definition() else { continue };
+        lines.push((
+            locale.format(
+                "quest_log.completed_quest",
+                &[definition.name, &definition.reward.label(locale)],
+            ),
+            false,
+        ));
+    }
+    lines
+}
+
+fn quest_log_open(
+    mut commands: Commands,
+    asset_library: Res<AssetLibrary>,
+    game_state: Res<GameState>,
+    locale: Res<Locale>,
+) {
+    commands
+        .spawn((
+            VisibilityBundle::default(),
+            TransformBundle::default(),
+            Transform2::from_xy(0., 0.).without_pixel_perfect(),
+            FollowCamera { offset: Vec2::ZERO },
+            QuestLog,
+            AudioPlusSource::new(asset_library.sound_effects.sfx_overworld_map_open.clone())
+                .as_playing(),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                SpriteBundle {
+                    texture: asset_library.sprite_map_bg.clone(),
+                    ..Default::default()
+                },
+                Transform2::new().with_depth(DEPTH_LAYER_QUEST_LOG_BACK),
+            ));
+            for (index, (line, header)) in quest_log_lines(&game_state.quests, &locale)
+                .into_iter()
+                .enumerate()
+            {
+                parent.spawn((
+                    Text2dBundle {
+                        text: Text::from_section(
+                            line,
+                            TextStyle {
+                                font: if header {
+                                    asset_library.font_bold.clone()
+                                } else {
+                                    asset_library.font_default.clone()
+                                },
+                                font_size: if header { 32. } else { 22. },
+                                color: Color::BLACK,
+                            },
+                        )
+                        .with_alignment(TextAlignment::Left),
+                        text_anchor: Anchor::CenterRight,
+                        ..Default::default()
+                    },
+                    Transform2::from_xy(
+                        QUEST_LOG_LEFT,
+                        QUEST_LOG_TOP - index as f32 * QUEST_LOG_LINE_HEIGHT,
+                    )
+                    .with_depth(DEPTH_LAYER_QUEST_LOG_TEXT),
+                ));
+            }
+        });
+}
+
+fn quest_log_wait_for_close(
+    input: Res<Input<KeyCode>>,
+    mouse: Res<Input<MouseButton>>,
+    gamepads: Res<Gamepads>,
+    gamepad_buttons: Res<Input<GamepadButton>>,
+    mapping: Res<PlayerInputMapping>,
+    mut ev_continue: EventWriter<CutsceneContinueEvent<QuestLogCutscene>>,
+    asset_library: Res<AssetLibrary>,
+    mut commands: Commands,
+) {
+    if input.just_pressed(KeyCode::Space)
+        || mouse.just_pressed(MouseButton::Left)
+        || mapping.just_pressed(InputAction::QuestLog, &input, &mouse)
+        || mapping.gamepad_just_pressed(InputAction::QuestLog, &gamepads, &gamepad_buttons)
+    {
+        ev_continue.send_default();
+
+        commands.spawn((
+            Transform2Bundle::default(),
+            AudioPlusSource::new(asset_library.sound_effects.sfx_overworld_map_close.clone())
+                .as_playing(),
+            TimeToLive { seconds: 3. },
+        ));
+    }
+}
+
+fn quest_log_close(query: Query<Entity, With<QuestLog>>, mut commands: Commands) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+fn quest_log_input(
+    input: Res<Input<KeyCode>>,
+    mouse: Res<Input<MouseButton>>,
+    gamepads: Res<Gamepads>,
+    gamepad_buttons: Res<Input<GamepadButton>>,
+    mapping: Res<PlayerInputMapping>,
+    cutscenes: Res<Cutscenes>,
+    state_time: Res<StateTime<AppState>>,
+    mut ev_cutscene: EventWriter<CutsceneStartEvent<QuestLogCutscene>>,
+    game_state: Res<GameState>,
+) {
+    if state_time.time > 1.
+        && (mapping.just_pressed(InputAction::QuestLog, &input, &mouse)
+            || mapping.gamepad_just_pressed(InputAction::QuestLog, &gamepads, &gamepad_buttons))
+        && !cutscenes.running()
+        && !game_state.quests.fighting()
+    {
+        ev_cutscene.send_default();
+    }
+}
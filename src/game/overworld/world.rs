@@ -35,7 +35,7 @@ fn world_spawn(
         commands.spawn((
             Text2dBundle {
                 text: Text::from_section(
-                    "Hold left mouse button to move!\nPress space to dash!\nPress F to start jamming!",
+                    "",
                     TextStyle {
                         font: asset_library.font_bold.clone(),
                         font_size: 48.0,
@@ -47,6 +47,7 @@ fn world_spawn(
                 ..Default::default()
             },
             Transform2::from_xy(700., -350.).with_depth(DEPTH_LAYER_CONTROLS),
+            LocalizedText::new("overworld.tutorial"),
         ));
     }
 }
@@ -1,6 +1,8 @@
 pub use super::{
-    all_dialogue::*,
-    data::{band_members::BandMember, town_data::TownData},
+    data::{
+        band_members::BandMember,
+        town_data::{town_locale_id, TownData},
+    },
     overworld::{
         attacks::{
             bombs::{Bombs, BombsLevel},
@@ -12,6 +14,7 @@ pub use super::{
             Attacks,
         },
         boat::{Boat, BoatSpawnEvent, BoatSystem},
+        boat_ai::{BoatAi, BoatAiState, BoatAiSystem},
         camera::{OverworldCamera, OverworldCameraSystem},
         character_controller::{
             CharacterController, CharacterControllerDestination, CharacterControllerSystem,
@@ -23,10 +26,12 @@ pub use super::{
             exit_town::ExitTownCutscene,
         },
         damage::{
-            AutoDamage, DamageEvent, Hitbox, Hurtbox, HurtboxKnockbackType, DAMAGE_FLAG_ENEMY,
-            DAMAGE_FLAG_ENVIRONMENT, DAMAGE_FLAG_PLAYER,
+            AutoDamage, AutoDamageKind, DamageCrit, DamageEvent, DamageKillEvent,
+            DamageResistances, DamageSystem, DamageType, Hitbox, Hurtbox, HurtboxKnockbackType,
+            DAMAGE_FLAG_ENEMY, DAMAGE_FLAG_ENVIRONMENT, DAMAGE_FLAG_PLAYER,
         },
         damage_flash::DamageFlashSpawnEvent,
+        damage_numbers::DamageNumberSpawnEvent,
         damage_rum::DamageRumSpawnEvent,
         enemy_spawns::DespawnSpawnedEntitiesEvent,
         entities::rubble::{Rubble, RubbleSpawnEvent},
@@ -35,7 +40,9 @@ pub use super::{
         healthbar::{Healthbar, HealthbarSpawnEvent, HealthbarSystem},
         ocean::{Ocean, OceanSpawnEvent},
         octopus::{Octopus, OctopusLevel, OctopusSpawnEvent, OctopusSystem},
+        pirate::{Pirate, PirateLevel, PirateSpawnEvent, PirateSystem},
         player::{Player, PlayerSpawnEvent},
+        status_effects::{StatusEffect, StatusEffectKind, StatusEffects},
         threat_level::ThreatLevel,
         town::{Town, TownSpawnEvent},
         trigger::Trigger,
@@ -48,6 +55,11 @@ pub use super::{
         world::{World, WorldLoadEvent},
         OverworldEnterEvent, OverworldPlugin, WorldAmbienceSoundStopEvent,
     },
-    quests::{Quest, QuestBarkeepEvent, QuestMayorEvent, Quests},
+    quests::{
+        definitions::{Captain, QuestStage},
+        side_quests::SideQuest,
+        Quest, QuestBarkeepEvent, QuestBoss, QuestBossSpawnEvent, QuestMayorEvent, Quests,
+    },
+    save::{GameSaveEvent, SaveSlots, SAVE_SLOTS},
     state::GameState,
 };
@@ -0,0 +1,148 @@
+use super::definitions::{QuestCompletion, QuestStage};
+use crate::common::prelude::*;
+use crate::game::prelude::*;
+use bevy::prelude::*;
+
+pub struct QuestCutscenesPlugin;
+
+impl Plugin for QuestCutscenesPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_cutscene::<QuestIntroCutscene>()
+            .add_cutscene::<QuestOutroCutscene>();
+    }
+}
+
+#[derive(Default, Debug, Clone, Resource)]
+pub struct QuestIntroCutscene;
+
+impl Cutscene for QuestIntroCutscene {
+    fn build(cutscene: &mut CutsceneBuilder) {
+        cutscene.add_dialogue_step(quest_intro_init);
+        cutscene.add_quick_step(quest_intro_cleanup);
+    }
+}
+
+fn quest_intro_init(
+    mut dialogue: ResMut<Dialogue>,
+    mut ev_boss_spawn: EventWriter<QuestBossSpawnEvent>,
+    mut overworld_camera: ResMut<OverworldCamera>,
+    world_locations: Res<WorldLocations>,
+    game_state: Res<GameState>,
+) {
+    let Some(definition) = game_state.quests.definition() else { return };
+    ev_boss_spawn.send(QuestBossSpawnEvent {
+        captain: definition.captain,
+    });
+
+    dialogue.add_script(definition.intro_dialogue);
+
+    let rect = world_locations.get_single_rect(definition.arena);
+    overworld_camera.arena_enable(rect.position, rect.size);
+}
+
+fn quest_intro_cleanup(mut game_state: ResMut<GameState>) {
+    game_state.quests.set_stage(QuestStage::Fight);
+}
+
+#[derive(Default, Debug, Clone, Resource)]
+pub struct QuestOutroCutscene {
+    time: f32,
+}
+
+impl Cutscene for QuestOutroCutscene {
+    fn build(cutscene: &mut CutsceneBuilder) {
+        cutscene.add_step(quest_outro_timer_reset, quest_outro_delay);
+        cutscene.add_dialogue_step(quest_outro_init);
+        cutscene.add_step(quest_outro_fade_out, quest_outro_fade_wait);
+        cutscene.add_quick_step(quest_outro_cleanup);
+    }
+}
+
+fn quest_outro_timer_reset(mut cutscene: ResMut<QuestOutroCutscene>) {
+    cutscene.time = 0.;
+}
+
+fn quest_outro_delay(
+    mut cutscene: ResMut<QuestOutroCutscene>,
+    mut ev_continue: EventWriter<CutsceneContinueEvent<QuestOutroCutscene>>,
+    game_state: Res<GameState>,
+    time: Res<Time>,
+) {
+    let delay = game_state
+        .quests
+        .definition()
+        .map(|definition| definition.outro_delay)
+        .unwrap_or(0.);
+    cutscene.time += time.delta_seconds();
+    if cutscene.time >= delay {
+        ev_continue.send_default();
+    }
+}
+
+fn quest_outro_init(mut dialogue: ResMut<Dialogue>, mut game_state: ResMut<GameState>) {
+    let Some(definition) = game_state.quests.definition() else { return };
+    definition.reward.apply(&mut game_state.attacks);
+    dialogue.add_script(definition.outro_dialogue);
+}
+
+fn quest_outro_fade_out(
+    mut cutscene: ResMut<QuestOutroCutscene>,
+    mut screen_fade: ResMut<ScreenFade>,
+    game_state: Res<GameState>,
+) {
+    cutscene.time = 0.;
+    let Some(definition) = game_state.quests.definition() else { return };
+    if definition.outro_fade > 0. {
+        screen_fade.fade_out(definition.outro_fade);
+    }
+}
+
+fn quest_outro_fade_wait(
+    mut cutscene: ResMut<QuestOutroCutscene>,
+    mut ev_continue: EventWriter<CutsceneContinueEvent<QuestOutroCutscene>>,
+    game_state: Res<GameState>,
+    time: Res<Time>,
+) {
+    let fade = game_state
+        .quests
+        .definition()
+        .map(|definition| definition.outro_fade)
+        .unwrap_or(0.);
+    cutscene.time += time.delta_seconds();
+    if cutscene.time >= fade {
+        ev_continue.send_default();
+    }
+}
+
+fn quest_outro_cleanup(
+    mut game_state: ResMut<GameState>,
+    mut overworld_camera: ResMut<OverworldCamera>,
+    mut app_state: ResMut<NextState<AppState>>,
+    mut player_query: Query<(Entity, &mut Boat), With<Player>>,
+    mut commands: Commands,
+    world_locations: Res<WorldLocations>,
+) {
+    let Some(definition) = game_state.quests.definition() else { return };
+    overworld_camera.reset();
+    game_state.quests.next();
+    match definition.completion {
+        QuestCompletion::SailTo(location) => {
+            if let Ok((player_entity, mut player_boat)) = player_query.get_single_mut() {
+                player_boat.shoot = false;
+                commands
+                    .entity(player_entity)
+                    .insert(CharacterControllerDestination {
+                        target: world_locations.get_single_position(location)
+                            + Vec2::new(0., -100.),
+                    });
+            }
+        }
+        QuestCompletion::Town(town) => {
+            game_state.town = TownData::build(town, world_locations.as_ref());
+            app_state.set(AppState::TownOutside);
+        }
+        QuestCompletion::OutroCutscene => {
+            app_state.set(AppState::OutroCutscene);
+        }
+    }
+}
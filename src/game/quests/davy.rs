@@ -2,23 +2,16 @@ use crate::common::prelude::*;
 use crate::game::prelude::*;
 use bevy::prelude::*;
 
-use super::{Davy2Cutscene, DavyQuestStage};
-
 pub struct DavyPlugin;
 
 impl Plugin for DavyPlugin {
     fn build(&self, app: &mut App) {
-        app.add_event::<DavySpawnEvent>()
-            .add_system(davy_spawn.before(BoatSystem::Spawn))
+        app.add_system(davy_spawn.before(BoatSystem::Spawn))
             .add_system(davy_move)
-            .add_system(davy_invincibility)
-            .add_system(davy_death_check);
+            .add_system(davy_invincibility);
     }
 }
 
-#[derive(Default, Clone, Copy)]
-pub struct DavySpawnEvent;
-
 #[derive(Component)]
 pub struct Davy {
     target: Vec2,
@@ -51,7 +44,7 @@ fn davy_stats_by_health(health_percent: f32) -> DavyStatsByHealth {
 }
 
 fn davy_spawn(
-    mut ev_spawn: EventReader<DavySpawnEvent>,
+    mut ev_spawn: EventReader<QuestBossSpawnEvent>,
     mut ev_boat_spawn: EventWriter<BoatSpawnEvent>,
     mut commands: Commands,
     world_locations: Res<WorldLocations>,
@@ -61,11 +54,15 @@ fn davy_spawn(
     asset_library: Res<AssetLibrary>,
 ) {
     let spawn_position = world_locations.get_single_position("DavySpawn");
-    for _ in ev_spawn.iter() {
+    for _ in ev_spawn
+        .iter()
+        .filter(|event| event.captain == Captain::Davy)
+    {
         let stats = davy_stats_by_health(1.);
         ev_enemies_despawn.send_default();
         let entity = commands
             .spawn((
+                QuestBoss,
                 Davy {
                     target: world_locations.get_single_position("DavyMoveTo"),
                     angle: 0.,
@@ -82,7 +79,7 @@ fn davy_spawn(
             .id();
         overworld_camera.entity_focus(entity);
         ev_boss_healthbar_spawn.send(BossHealthbarSpawnEvent {
-            name: "Captain Davy Bowie".to_owned(),
+            name: "quest.davy".to_owned(),
             entity,
         });
         ev_boat_spawn.send(BoatSpawnEvent {
@@ -158,18 +155,3 @@ fn davy_invincibility(mut query: Query<(&mut Boat, &AutoDamage), With<Davy>>) {
         };
     }
 }
-
-fn davy_death_check(
-    query: Query<Entity, With<Davy>>,
-    mut game_state: ResMut<GameState>,
-    mut ev_cutscene_davy2: EventWriter<CutsceneStartEvent<Davy2Cutscene>>,
-) {
-    if query.is_empty() {
-        if let Quest::Davy(quest) = &mut game_state.quests.active_quest {
-            if matches!(quest.stage, DavyQuestStage::Fight) {
-                ev_cutscene_davy2.send_default();
-                quest.stage = DavyQuestStage::Dialogue2;
-            }
-        }
-    }
-}
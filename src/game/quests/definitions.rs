@@ -0,0 +1,315 @@
+use crate::common::prelude::*;
+use crate::game::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum QuestStage {
+    #[default]
+    TalkToMayor,
+    Sail,
+    Dialogue1,
+    Fight,
+    Dialogue2,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Captain {
+    Jagerossa,
+    Ringo,
+    Plank,
+    Davy,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum QuestReward {
+    ShotgunCannons,
+    Shockwave,
+    Bombs,
+    Kraken,
+}
+
+impl QuestReward {
+    pub fn apply(&self, attacks: &mut Attacks) {
+        match self {
+            QuestReward::ShotgunCannons => attacks.shotgun_cannons = 1,
+            QuestReward::Shockwave => attacks.shockwave = 1,
+            QuestReward::Bombs => attacks.bombs = 1,
+            QuestReward::Kraken => attacks.kraken = 1,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            QuestReward::ShotgunCannons => "reward.shotgun_cannons",
+            QuestReward::Shockwave => "reward.shockwave",
+            QuestReward::Bombs => "reward.bombs",
+            QuestReward::Kraken => "reward.kraken",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum QuestCompletion {
+    // sail the player to a world location and keep playing
+    SailTo(&'static str),
+    // dock at a town
+    Town(&'static str),
+    OutroCutscene,
+}
+
+pub struct QuestDefinition {
+    pub id: &'static str,
+    // locale string ids
+    pub name: &'static str,
+    pub captain: Captain,
+    pub stages: &'static [QuestStage],
+    // world location names from the ldtk map
+    pub trigger: &'static str,
+    pub arena: &'static str,
+    pub mayor_dialogue: Option<&'static str>,
+    pub intro_dialogue: &'static str,
+    pub outro_dialogue: &'static str,
+    pub objective: Option<&'static str>,
+    pub mayor_objective: bool,
+    pub marker: bool,
+    pub block_town_enter: &'static [QuestStage],
+    pub block_town_exit_cutscene: bool,
+    pub block_enemy_spawns: bool,
+    pub block_dangerous_seas: &'static [QuestStage],
+    pub fight_threat_level: Option<ThreatLevel>,
+    pub reward: QuestReward,
+    pub outro_delay: f32,
+    pub outro_fade: f32,
+    pub completion: QuestCompletion,
+}
+
+const STAGES: &[QuestStage] = &[
+    QuestStage::TalkToMayor,
+    QuestStage::Sail,
+    QuestStage::Dialogue1,
+    QuestStage::Fight,
+    QuestStage::Dialogue2,
+];
+
+const STAGES_WITHOUT_MAYOR: &[QuestStage] = &[
+    QuestStage::Sail,
+    QuestStage::Dialogue1,
+    QuestStage::Fight,
+    QuestStage::Dialogue2,
+];
+
+const BLOCK_TOWN_ENTER: &[QuestStage] = &[
+    QuestStage::Dialogue1,
+    QuestStage::Fight,
+    QuestStage::Dialogue2,
+];
+
+pub const QUEST_DEFINITIONS: &[QuestDefinition] = &[
+    QuestDefinition {
+        id: "jagerossa",
+        name: "quest.jagerossa",
+        captain: Captain::Jagerossa,
+        stages: STAGES_WITHOUT_MAYOR,
+        trigger: "JagerossaTrigger",
+        arena: "JagerossaArena",
+        mayor_dialogue: None,
+        intro_dialogue: "jagerossa1",
+        outro_dialogue: "jagerossa2",
+        objective: None,
+        mayor_objective: false,
+        marker: false,
+        block_town_enter: STAGES_WITHOUT_MAYOR,
+        block_town_exit_cutscene: true,
+        block_enemy_spawns: true,
+        block_dangerous_seas: STAGES_WITHOUT_MAYOR,
+        fight_threat_level: None,
+        reward: QuestReward::ShotgunCannons,
+        outro_delay: 1.5,
+        outro_fade: 0.,
+        completion: QuestCompletion::SailTo("Portallica"),
+    },
+    QuestDefinition {
+        id: "ringo",
+        name: "quest.ringo",
+        captain: Captain::Ringo,
+        stages: STAGES,
+        trigger: "RingoTrigger",
+        arena: "RingoArena",
+        mayor_dialogue: Some("ringo_mayor"),
+        intro_dialogue: "ringo1",
+        outro_dialogue: "ringo2",
+        objective: Some("objective.defeat_ringo"),
+        mayor_objective: false,
+        marker: true,
+        block_town_enter: BLOCK_TOWN_ENTER,
+        block_town_exit_cutscene: false,
+        block_enemy_spawns: false,
+        block_dangerous_seas: &[QuestStage::TalkToMayor],
+        fight_threat_level: None,
+        reward: QuestReward::Shockwave,
+        outro_delay: 1.5,
+        outro_fade: 0.5,
+        completion: QuestCompletion::Town("Isla de Dio"),
+    },
+    QuestDefinition {
+        id: "plank",
+        name: "quest.plank",
+        captain: Captain::Plank,
+        stages: STAGES,
+        trigger: "PlankTrigger",
+        arena: "PlankArena",
+        mayor_dialogue: Some("plank_mayor"),
+        intro_dialogue: "plank1",
+        outro_dialogue: "plank2",
+        objective: Some("objective.defeat_plank"),
+        mayor_objective: true,
+        marker: true,
+        block_town_enter: BLOCK_TOWN_ENTER,
+        block_town_exit_cutscene: false,
+        block_enemy_spawns: false,
+        block_dangerous_seas: &[],
+        fight_threat_level: None,
+        reward: QuestReward::Bombs,
+        outro_delay: 1.5,
+        outro_fade: 0.5,
+        completion: QuestCompletion::Town("Iron Maiden's Cove"),
+    },
+    QuestDefinition {
+        id: "davy",
+        name: "quest.davy",
+        captain: Captain::Davy,
+        stages: STAGES,
+        trigger: "DavyTrigger",
+        arena: "DavyArena",
+        mayor_dialogue: Some("davy_mayor"),
+        intro_dialogue: "davy1",
+        outro_dialogue: "davy2",
+        objective: Some("objective.defeat_davy"),
+        mayor_objective: true,
+        marker: true,
+        block_town_enter: BLOCK_TOWN_ENTER,
+        block_town_exit_cutscene: false,
+        block_enemy_spawns: false,
+        block_dangerous_seas: &[],
+        fight_threat_level: Some(ThreatLevel::Davy),
+        reward: QuestReward::Kraken,
+        outro_delay: 0.,
+        outro_fade: 3.,
+        completion: QuestCompletion::OutroCutscene,
+    },
+];
+
+pub fn quest_definition(id: &str) -> Option<&'static QuestDefinition> {
+    QUEST_DEFINITIONS
+        .iter()
+        .find(|definition| definition.id == id)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SideQuestGiver {
+    Mayor,
+    Barkeep,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SideQuestTarget {
+    Octopus,
+    Turtle,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum SideQuestObjective {
+    // sink enemies, optionally only while sailing in waters of a given threat level
+    Sink {
+        target: SideQuestTarget,
+        threat_level: Option<ThreatLevel>,
+        count: u32,
+    },
+    Deliver {
+        town: &'static str,
+    },
+}
+
+impl SideQuestObjective {
+    pub fn goal(&self) -> u32 {
+        match self {
+            SideQuestObjective::Sink { count, .. } => *count,
+            SideQuestObjective::Deliver { .. } => 1,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum SideQuestReward {
+    Experience(f32),
+    SkillPoints(u32),
+}
+
+impl SideQuestReward {
+    pub fn label(&self, locale: &Locale) -> String {
+        match self {
+            SideQuestReward::Experience(amount) => {
+                locale.format("reward.experience", &[&amount.to_string()])
+            }
+            SideQuestReward::SkillPoints(1) => locale.get("reward.skill_point").to_owned(),
+            SideQuestReward::SkillPoints(amount) => {
+                locale.format("reward.skill_points", &[&amount.to_string()])
+            }
+        }
+    }
+}
+
+pub struct SideQuestDefinition {
+    pub id: &'static str,
+    // locale string ids
+    pub name: &'static str,
+    pub description: &'static str,
+    pub giver: SideQuestGiver,
+    pub offer_dialogue: &'static str,
+    pub objective: SideQuestObjective,
+    pub reward: SideQuestReward,
+}
+
+pub const SIDE_QUEST_DEFINITIONS: &[SideQuestDefinition] = &[
+    SideQuestDefinition {
+        id: "octopus_hunt",
+        name: "side_quest.octopus_hunt.name",
+        description: "side_quest.octopus_hunt.description",
+        giver: SideQuestGiver::Mayor,
+        offer_dialogue: "side_quest_octopus_hunt",
+        objective: SideQuestObjective::Sink {
+            target: SideQuestTarget::Octopus,
+            threat_level: Some(ThreatLevel::Medium),
+            count: 30,
+        },
+        reward: SideQuestReward::SkillPoints(1),
+    },
+    SideQuestDefinition {
+        id: "turtle_trouble",
+        name: "side_quest.turtle_trouble.name",
+        description: "side_quest.turtle_trouble.description",
+        giver: SideQuestGiver::Mayor,
+        offer_dialogue: "side_quest_turtle_trouble",
+        objective: SideQuestObjective::Sink {
+            target: SideQuestTarget::Turtle,
+            threat_level: None,
+            count: 15,
+        },
+        reward: SideQuestReward::Experience(25.),
+    },
+    SideQuestDefinition {
+        id: "rum_delivery",
+        name: "side_quest.rum_delivery.name",
+        description: "side_quest.rum_delivery.description",
+        giver: SideQuestGiver::Barkeep,
+        offer_dialogue: "side_quest_rum_delivery",
+        objective: SideQuestObjective::Deliver { town: "Port Floyd" },
+        reward: SideQuestReward::Experience(15.),
+    },
+];
+
+pub fn side_quest_definition(id: &str) -> Option<&'static SideQuestDefinition> {
+    SIDE_QUEST_DEFINITIONS
+        .iter()
+        .find(|definition| definition.id == id)
+}
@@ -2,23 +2,16 @@ use crate::common::prelude::*;
 use crate::game::prelude::*;
 use bevy::prelude::*;
 
-use super::{Jagerossa2Cutscene, JagerossaQuestStage};
-
 pub struct JagerossaPlugin;
 
 impl Plugin for JagerossaPlugin {
     fn build(&self, app: &mut App) {
-        app.add_event::<JagerossaSpawnEvent>()
-            .add_system(jagerossa_spawn.before(BoatSystem::Spawn))
+        app.add_system(jagerossa_spawn.before(BoatSystem::Spawn))
             .add_system(jagerossa_move)
-            .add_system(jagerossa_invincibility)
-            .add_system(jagerossa_death_check);
+            .add_system(jagerossa_invincibility);
     }
 }
 
-#[derive(Default, Clone, Copy)]
-pub struct JagerossaSpawnEvent;
-
 #[derive(Component)]
 pub struct Jagerossa {
     target: Vec2,
@@ -49,7 +42,7 @@ fn jagerossa_stats_by_health(health_percent: f32) -> JagerossaStatsByHealth {
 }
 
 fn jagerossa_spawn(
-    mut ev_spawn: EventReader<JagerossaSpawnEvent>,
+    mut ev_spawn: EventReader<QuestBossSpawnEvent>,
     mut ev_boat_spawn: EventWriter<BoatSpawnEvent>,
     mut commands: Commands,
     world_locations: Res<WorldLocations>,
@@ -58,10 +51,14 @@ fn jagerossa_spawn(
     asset_library: Res<AssetLibrary>,
 ) {
     let spawn_position = world_locations.get_single_position("JagerossaSpawn");
-    for _ in ev_spawn.iter() {
+    for _ in ev_spawn
+        .iter()
+        .filter(|event| event.captain == Captain::Jagerossa)
+    {
         let stats = jagerossa_stats_by_health(1.);
         let entity = commands
             .spawn((
+                QuestBoss,
                 Jagerossa {
                     target: world_locations.get_single_position("JagerossaMoveTo"),
                     angle: 0.,
@@ -81,7 +78,7 @@ fn jagerossa_spawn(
             .id();
         overworld_camera.entity_focus(entity);
         ev_boss_healthbar_spawn.send(BossHealthbarSpawnEvent {
-            name: "Captain Mike Jagerossa".to_owned(),
+            name: "quest.jagerossa".to_owned(),
             entity,
         });
         ev_boat_spawn.send(BoatSpawnEvent {
@@ -177,18 +174,3 @@ fn jagerossa_invincibility(mut query: Query<(&mut Boat, &AutoDamage), With<Jager
         };
     }
 }
-
-fn jagerossa_death_check(
-    query: Query<Entity, With<Jagerossa>>,
-    mut game_state: ResMut<GameState>,
-    mut ev_cutscene_jagerossa2: EventWriter<CutsceneStartEvent<Jagerossa2Cutscene>>,
-) {
-    if query.is_empty() {
-        if let Quest::Jagerossa(quest) = &mut game_state.quests.active_quest {
-            if matches!(quest.stage, JagerossaQuestStage::Fight) {
-                ev_cutscene_jagerossa2.send_default();
-                quest.stage = JagerossaQuestStage::Dialogue2;
-            }
-        }
-    }
-}
@@ -1,13 +1,13 @@
 use self::{
-    davy::{DavyQuest, DavyQuestStage},
-    jagerossa::{JagerossaQuest, JagerossaQuestStage},
-    plank::{PlankQuest, PlankQuestStage},
-    ringo::{RingoQuest, RingoQuestStage},
+    cutscenes::{QuestIntroCutscene, QuestOutroCutscene},
+    definitions::{quest_definition, QuestDefinition, QuestStage, QUEST_DEFINITIONS},
+    side_quests::SideQuest,
 };
 use crate::game::prelude::*;
 use crate::{common::prelude::*, DEV_BUILD};
 use bevy::prelude::*;
 use bevy_egui::{egui, EguiContext};
+use serde::{Deserialize, Serialize};
 
 use super::town::outside::rum_refill::RumRefillCutscene;
 
@@ -17,20 +17,27 @@ impl Plugin for QuestsPlugin {
     fn build(&self, app: &mut App) {
         app.add_event::<QuestMayorEvent>()
             .add_event::<QuestBarkeepEvent>()
-            .add_plugin(jagerossa::JagerossaQuestPlugin)
-            .add_plugin(davy::DavyQuestPlugin)
-            .add_plugin(ringo::RingoQuestPlugin)
-            .add_plugin(plank::PlankQuestPlugin)
+            .add_event::<QuestBossSpawnEvent>()
+            .add_plugin(cutscenes::QuestCutscenesPlugin)
+            .add_plugin(trigger::QuestTriggerPlugin)
+            .add_plugin(side_quests::SideQuestsPlugin)
+            .add_plugin(jagerossa::JagerossaPlugin)
+            .add_plugin(davy::DavyPlugin)
+            .add_plugin(ringo::RingoPlugin)
+            .add_plugin(plank::PlankPlugin)
             .add_system(quests_debug)
             .add_system(quests_mayor)
             .add_system(quests_barkeep)
+            .add_system(quests_dialogue_choices)
+            .add_system(quests_boss_death_check)
             .add_system(quests_skip);
     }
 }
 
-#[derive(Default, Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Quests {
-    pub active_quest: Quest,
+    pub active_quest: Option<Quest>,
+    pub side_quests: Vec<SideQuest>,
     pub mayor_dialogue: u32,
     pub mayor_after_dialogue: u32,
     pub barkeep_dialogue: u32,
@@ -39,206 +46,180 @@ pub struct Quests {
     pub upgrades_dialogue: bool,
 }
 
+impl Default for Quests {
+    fn default() -> Self {
+        Self {
+            active_quest: QUEST_DEFINITIONS.first().map(Quest::new),
+            side_quests: vec![],
+            mayor_dialogue: 0,
+            mayor_after_dialogue: 0,
+            barkeep_dialogue: 0,
+            talked_to_barkeep: false,
+            endgame_town_dialogue: false,
+            upgrades_dialogue: false,
+        }
+    }
+}
+
 #[derive(Default, Clone, Copy)]
 pub struct QuestMayorEvent;
 
 #[derive(Default, Clone, Copy)]
 pub struct QuestBarkeepEvent;
 
+#[derive(Clone, Copy)]
+pub struct QuestBossSpawnEvent {
+    pub captain: Captain,
+}
+
+#[derive(Component)]
+pub struct QuestBoss;
+
 impl Quests {
+    pub fn definition(&self) -> Option<&'static QuestDefinition> {
+        self.active_quest.as_ref()?.definition()
+    }
+
+    pub fn stage(&self) -> Option<QuestStage> {
+        self.active_quest.as_ref().map(|quest| quest.stage)
+    }
+
+    pub fn set_stage(&mut self, stage: QuestStage) {
+        if let Some(quest) = &mut self.active_quest {
+            quest.stage = stage;
+        }
+    }
+
+    fn stage_in(&self, stages: impl FnOnce(&QuestDefinition) -> &[QuestStage]) -> bool {
+        let (Some(stage), Some(definition)) = (self.stage(), self.definition()) else {
+            return false;
+        };
+        stages(definition).contains(&stage)
+    }
+
     pub fn hide_town_marker(&self) -> bool {
         self.block_town_enter()
     }
 
     pub fn block_town_enter(&self) -> bool {
-        match &self.active_quest {
-            Quest::Jagerossa(..) => true,
-            Quest::Ringo(quest) => {
-                matches!(quest.stage, RingoQuestStage::Dialogue1)
-                    || matches!(quest.stage, RingoQuestStage::Fight)
-                    || matches!(quest.stage, RingoQuestStage::Dialogue2)
-            }
-            Quest::Plank(quest) => {
-                matches!(quest.stage, PlankQuestStage::Dialogue1)
-                    || matches!(quest.stage, PlankQuestStage::Fight)
-                    || matches!(quest.stage, PlankQuestStage::Dialogue2)
-            }
-            Quest::Davy(quest) => {
-                matches!(quest.stage, DavyQuestStage::Dialogue1)
-                    || matches!(quest.stage, DavyQuestStage::Fight)
-                    || matches!(quest.stage, DavyQuestStage::Dialogue2)
-            }
-            _ => false,
-        }
+        self.stage_in(|definition| definition.block_town_enter)
     }
 
     pub fn block_town_exit_cutscene(&self) -> bool {
-        match self.active_quest {
-            Quest::Jagerossa(..) => true,
-            _ => false,
-        }
+        self.definition()
+            .map(|definition| definition.block_town_exit_cutscene)
+            .unwrap_or(false)
     }
 
     pub fn block_enemy_spawns(&self) -> bool {
-        if self.fighting() && !self.davy() {
+        if self.fighting() && self.fight_threat_level().is_none() {
             return true;
         }
-        match &self.active_quest {
-            Quest::Jagerossa(..) => true,
-            _ => false,
-        }
+        self.definition()
+            .map(|definition| definition.block_enemy_spawns)
+            .unwrap_or(false)
     }
 
     pub fn block_dangerous_seas(&self) -> bool {
-        match &self.active_quest {
-            Quest::Jagerossa(..) => true,
-            Quest::Ringo(quest) => matches!(quest.stage, RingoQuestStage::TalkToMayor),
-            _ => false,
-        }
+        self.stage_in(|definition| definition.block_dangerous_seas)
     }
 
     pub fn pirate_dialogue(&self) -> bool {
-        match &self.active_quest {
-            Quest::Jagerossa(quest) => {
-                matches!(quest.stage, JagerossaQuestStage::Dialogue1)
-                    || matches!(quest.stage, JagerossaQuestStage::Dialogue2)
-            }
-            Quest::Ringo(quest) => {
-                matches!(quest.stage, RingoQuestStage::Dialogue1)
-                    || matches!(quest.stage, RingoQuestStage::Dialogue2)
-            }
-            Quest::Plank(quest) => {
-                matches!(quest.stage, PlankQuestStage::Dialogue1)
-                    || matches!(quest.stage, PlankQuestStage::Dialogue2)
-            }
-            Quest::Davy(quest) => {
-                matches!(quest.stage, DavyQuestStage::Dialogue1)
-                    || matches!(quest.stage, DavyQuestStage::Dialogue2)
-            }
-            _ => false,
-        }
+        matches!(
+            self.stage(),
+            Some(QuestStage::Dialogue1) | Some(QuestStage::Dialogue2)
+        )
     }
 
     pub fn fighting(&self) -> bool {
-        match &self.active_quest {
-            Quest::Jagerossa(quest) => matches!(quest.stage, JagerossaQuestStage::Fight),
-            Quest::Ringo(quest) => matches!(quest.stage, RingoQuestStage::Fight),
-            Quest::Plank(quest) => matches!(quest.stage, PlankQuestStage::Fight),
-            Quest::Davy(quest) => matches!(quest.stage, DavyQuestStage::Fight),
-            _ => false,
+        matches!(self.stage(), Some(QuestStage::Fight))
+    }
+
+    pub fn fight_threat_level(&self) -> Option<ThreatLevel> {
+        if !self.fighting() {
+            return None;
         }
+        self.definition()?.fight_threat_level
     }
 
     pub fn must_talk_to_mayor(&self) -> bool {
-        match &self.active_quest {
-            Quest::Jagerossa(..) => false,
-            Quest::Ringo(quest) => matches!(quest.stage, RingoQuestStage::TalkToMayor),
-            Quest::Plank(quest) => matches!(quest.stage, PlankQuestStage::TalkToMayor),
-            Quest::Davy(quest) => matches!(quest.stage, DavyQuestStage::TalkToMayor),
-            _ => false,
-        }
+        matches!(self.stage(), Some(QuestStage::TalkToMayor))
+    }
+
+    pub fn trigger(&self) -> Option<&str> {
+        self.definition().map(|definition| definition.trigger)
     }
 
     pub fn marker(&self) -> Option<&str> {
         if self.must_talk_to_mayor() || self.fighting() || self.pirate_dialogue() {
             return None;
         }
-        match self.active_quest {
-            Quest::Jagerossa(..) => None,
-            Quest::Ringo(..) => Some("RingoTrigger"),
-            Quest::Plank(..) => Some("PlankTrigger"),
-            Quest::Davy(..) => Some("DavyTrigger"),
-            Quest::End => None,
+        let definition = self.definition()?;
+        if definition.marker {
+            Some(definition.trigger)
+        } else {
+            None
         }
     }
 
-    pub fn objective(&self) -> Option<(f32, &str)> {
-        if self.jagerossa() {
-            return None;
-        }
-        if self.ringo() && self.must_talk_to_mayor() {
-            return None;
-        }
+    pub fn objective(&self) -> Option<&str> {
+        let definition = self.definition()?;
         if self.fighting() || self.pirate_dialogue() {
             return None;
         }
         if self.must_talk_to_mayor() {
-            return Some((264., "Talk to the governor at town"));
-        }
-        match self.active_quest {
-            Quest::Jagerossa(..) => Some((284., "Defeat Captain Mike Jagerossa")),
-            Quest::Ringo(..) => Some((240., "Defeat Captain Ringo Yarr")),
-            Quest::Plank(..) => Some((268., "Defeat Captain Plank Presley")),
-            Quest::Davy(..) => Some((244., "Defeat Captain Davy Bowie")),
-            Quest::End => None,
-        }
-    }
-
-    pub fn jagerossa(&self) -> bool {
-        match &self.active_quest {
-            Quest::Jagerossa(..) => true,
-            _ => false,
-        }
-    }
-
-    pub fn ringo(&self) -> bool {
-        match &self.active_quest {
-            Quest::Ringo(..) => true,
-            _ => false,
+            if definition.mayor_objective {
+                return Some("objective.talk_to_governor");
+            }
+            return None;
         }
+        definition.objective
     }
 
-    pub fn plank(&self) -> bool {
-        match &self.active_quest {
-            Quest::Plank(..) => true,
-            _ => false,
-        }
+    pub fn completed_quests(&self) -> &'static [QuestDefinition] {
+        let Some(quest) = &self.active_quest else { return QUEST_DEFINITIONS };
+        let index = QUEST_DEFINITIONS
+            .iter()
+            .position(|definition| definition.id == quest.id)
+            .unwrap_or(0);
+        &QUEST_DEFINITIONS[..index]
     }
 
-    pub fn davy(&self) -> bool {
-        match &self.active_quest {
-            Quest::Davy(..) => true,
-            _ => false,
-        }
+    pub fn end(&self) -> bool {
+        self.active_quest.is_none()
     }
 
-    pub fn end(&self) -> bool {
-        match &self.active_quest {
-            Quest::End => true,
-            _ => false,
-        }
+    pub fn start(&mut self, id: &str) {
+        self.active_quest = quest_definition(id).map(Quest::new);
     }
 
     pub fn next(&mut self) {
-        self.active_quest.next();
+        let Some(quest) = &self.active_quest else { return };
+        let index = QUEST_DEFINITIONS
+            .iter()
+            .position(|definition| definition.id == quest.id);
+        self.active_quest = index
+            .and_then(|index| QUEST_DEFINITIONS.get(index + 1))
+            .map(Quest::new);
     }
 }
 
-#[derive(Clone, Debug)]
-pub enum Quest {
-    Jagerossa(JagerossaQuest),
-    Ringo(RingoQuest),
-    Plank(PlankQuest),
-    Davy(DavyQuest),
-    End,
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Quest {
+    pub id: String,
+    pub stage: QuestStage,
 }
 
 impl Quest {
-    pub fn next(&mut self) {
-        *self = match *self {
-            Self::Jagerossa(..) => Self::Ringo(RingoQuest::default()),
-            Self::Ringo(..) => Self::Plank(PlankQuest::default()),
-            Self::Plank(..) => Self::Davy(DavyQuest::default()),
-            Self::Davy(..) => Self::End,
-            Self::End => Self::End,
+    pub fn new(definition: &QuestDefinition) -> Self {
+        Self {
+            id: definition.id.to_owned(),
+            stage: definition.stages.first().copied().unwrap_or_default(),
         }
     }
-}
 
-impl Default for Quest {
-    fn default() -> Self {
-        Self::Jagerossa(JagerossaQuest::default())
-        //Self::End
+    pub fn definition(&self) -> Option<&'static QuestDefinition> {
+        quest_definition(&self.id)
     }
 }
 
@@ -249,101 +230,22 @@ fn quests_mayor(
 ) {
     let mut fallback_dialogue = true;
     for _ in ev_mayor.iter() {
-        match &mut game_state.quests.active_quest {
-            Quest::Ringo(quest) => {
-                if matches!(quest.stage, RingoQuestStage::TalkToMayor) {
-                    for (p, t) in RINGO_MAYOR.iter() {
-                        dialogue.add_text(*p, String::from(*t));
-                    }
-                    quest.stage = RingoQuestStage::TalkedToMayor;
-                    fallback_dialogue = false;
-                }
-            }
-            Quest::Plank(quest) => {
-                if matches!(quest.stage, PlankQuestStage::TalkToMayor) {
-                    for (p, t) in PLANK_MAYOR.iter() {
-                        dialogue.add_text(*p, String::from(*t));
-                    }
-                    quest.stage = PlankQuestStage::TalkedToMayor;
-                    fallback_dialogue = false;
-                }
+        if game_state.quests.must_talk_to_mayor() {
+            if let Some(script) = game_state
+                .quests
+                .definition()
+                .and_then(|definition| definition.mayor_dialogue)
+            {
+                dialogue.add_script(script);
+                game_state.quests.set_stage(QuestStage::Sail);
+                fallback_dialogue = false;
             }
-            Quest::Davy(quest) => {
-                if matches!(quest.stage, DavyQuestStage::TalkToMayor) {
-                    for (p, t) in DAVY_MAYOR.iter() {
-                        dialogue.add_text(*p, String::from(*t));
-                    }
-                    quest.stage = DavyQuestStage::TalkedToMayor;
-                    fallback_dialogue = false;
-                }
-            }
-            _ => {}
         }
         if fallback_dialogue {
             if game_state.quests.end() {
-                match game_state.quests.mayor_after_dialogue % 6 {
-                    0 => {
-                        for (p, t) in MAYOR_AFTER_VICTORY1.iter() {
-                            dialogue.add_text(*p, String::from(*t));
-                        }
-                    }
-                    1 => {
-                        for (p, t) in MAYOR_AFTER_VICTORY2.iter() {
-                            dialogue.add_text(*p, String::from(*t));
-                        }
-                    }
-                    2 => {
-                        for (p, t) in MAYOR_AFTER_VICTORY3.iter() {
-                            dialogue.add_text(*p, String::from(*t));
-                        }
-                    }
-                    3 => {
-                        for (p, t) in MAYOR_AFTER_VICTORY4.iter() {
-                            dialogue.add_text(*p, String::from(*t));
-                        }
-                    }
-                    4 => {
-                        for (p, t) in MAYOR_AFTER_VICTORY5.iter() {
-                            dialogue.add_text(*p, String::from(*t));
-                        }
-                    }
-                    _ => {
-                        for (p, t) in MAYOR_AFTER_VICTORY6.iter() {
-                            dialogue.add_text(*p, String::from(*t));
-                        }
-                    }
-                }
-                game_state.quests.mayor_after_dialogue =
-                    (game_state.quests.mayor_after_dialogue + 1) % 6;
+                dialogue.add_script("mayor_greeting_after_victory");
             } else {
-                match game_state.quests.mayor_dialogue % 5 {
-                    0 => {
-                        for (p, t) in MAYOR_RANDOM1.iter() {
-                            dialogue.add_text(*p, String::from(*t));
-                        }
-                    }
-                    1 => {
-                        for (p, t) in MAYOR_RANDOM2.iter() {
-                            dialogue.add_text(*p, String::from(*t));
-                        }
-                    }
-                    2 => {
-                        for (p, t) in MAYOR_RANDOM3.iter() {
-                            dialogue.add_text(*p, String::from(*t));
-                        }
-                    }
-                    3 => {
-                        for (p, t) in MAYOR_RANDOM4.iter() {
-                            dialogue.add_text(*p, String::from(*t));
-                        }
-                    }
-                    _ => {
-                        for (p, t) in MAYOR_RANDOM5.iter() {
-                            dialogue.add_text(*p, String::from(*t));
-                        }
-                    }
-                }
-                game_state.quests.mayor_dialogue = (game_state.quests.mayor_dialogue + 1) % 5;
+                dialogue.add_script("mayor_greeting");
             }
         }
     }
@@ -360,45 +262,11 @@ fn quests_barkeep(
         let need_rum = game_state.health != game_state.health_max;
         if !game_state.quests.talked_to_barkeep {
             game_state.quests.talked_to_barkeep = true;
-            for (p, t) in BARKEEP1.iter() {
-                dialogue.add_text(*p, String::from(*t));
-            }
+            dialogue.add_script("barkeep1");
             fallback_dialogue = false;
         }
         if fallback_dialogue && !need_rum {
-            match game_state.quests.barkeep_dialogue % 6 {
-                0 => {
-                    for (p, t) in BARKEEP_RANDOM1.iter() {
-                        dialogue.add_text(*p, String::from(*t));
-                    }
-                }
-                1 => {
-                    for (p, t) in BARKEEP_RANDOM2.iter() {
-                        dialogue.add_text(*p, String::from(*t));
-                    }
-                }
-                2 => {
-                    for (p, t) in BARKEEP_RANDOM3.iter() {
-                        dialogue.add_text(*p, String::from(*t));
-                    }
-                }
-                3 => {
-                    for (p, t) in BARKEEP_RANDOM4.iter() {
-                        dialogue.add_text(*p, String::from(*t));
-                    }
-                }
-                4 => {
-                    for (p, t) in BARKEEP_RANDOM5.iter() {
-                        dialogue.add_text(*p, String::from(*t));
-                    }
-                }
-                _ => {
-                    for (p, t) in BARKEEP_RANDOM6.iter() {
-                        dialogue.add_text(*p, String::from(*t));
-                    }
-                }
-            }
-            game_state.quests.barkeep_dialogue = (game_state.quests.barkeep_dialogue + 1) % 6;
+            dialogue.add_script("barkeep_greeting");
         }
         if need_rum {
             ev_rum_refill_cutscene.send_default();
@@ -406,6 +274,52 @@ fn quests_barkeep(
     }
 }
 
+fn quests_dialogue_choices(
+    mut ev_choice: EventReader<DialogueChoiceEvent>,
+    mut dialogue: ResMut<Dialogue>,
+    mut game_state: ResMut<GameState>,
+) {
+    for event in ev_choice.iter() {
+        match event.id.as_str() {
+            "mayor_news" => {
+                if game_state.quests.end() {
+                    dialogue.continue_script(&format!(
+                        "mayor_after_victory{}",
+                        game_state.quests.mayor_after_dialogue % 6 + 1
+                    ));
+                    game_state.quests.mayor_after_dialogue =
+                        (game_state.quests.mayor_after_dialogue + 1) % 6;
+                } else {
+                    dialogue.continue_script(&format!(
+                        "mayor_random{}",
+                        game_state.quests.mayor_dialogue % 5 + 1
+                    ));
+                    game_state.quests.mayor_dialogue = (game_state.quests.mayor_dialogue + 1) % 5;
+                }
+            }
+            "barkeep_rumor" => {
+                dialogue.continue_script(&format!(
+                    "barkeep_random{}",
+                    game_state.quests.barkeep_dialogue % 6 + 1
+                ));
+                game_state.quests.barkeep_dialogue = (game_state.quests.barkeep_dialogue + 1) % 6;
+            }
+            _ => {}
+        }
+    }
+}
+
+fn quests_boss_death_check(
+    query: Query<Entity, With<QuestBoss>>,
+    mut game_state: ResMut<GameState>,
+    mut ev_outro_cutscene: EventWriter<CutsceneStartEvent<QuestOutroCutscene>>,
+) {
+    if query.is_empty() && game_state.quests.fighting() {
+        ev_outro_cutscene.send_default();
+        game_state.quests.set_stage(QuestStage::Dialogue2);
+    }
+}
+
 fn quests_debug(
     mut egui_query: Query<&mut EguiContext>,
     mut menu_bar: ResMut<MenuBar>,
@@ -418,14 +332,28 @@ fn quests_debug(
             .show(egui_context.get_mut(), |ui| {
                 ui.label(format!(
                     "Active Quest: {}",
-                    match game_state.quests.active_quest {
-                        Quest::Jagerossa(..) => "Jagerossa",
-                        Quest::Ringo(..) => "Ringo",
-                        Quest::Plank(..) => "Plank",
-                        Quest::Davy(..) => "Davy",
-                        Quest::End => "End",
-                    }
+                    game_state
+                        .quests
+                        .active_quest
+                        .as_ref()
+                        .map(|quest| quest.id.as_str())
+                        .unwrap_or("End")
                 ));
+                for side_quest in game_state.quests.side_quests.iter() {
+                    ui.label(format!(
+                        "Side Quest: {} {}/{}{}",
+                        side_quest.id,
+                        side_quest.progress,
+                        side_quest
+                            .goal()
+                            .map_or("?".to_owned(), |goal| goal.to_string()),
+                        if side_quest.completed {
+                            " (completed)"
+                        } else {
+                            ""
+                        }
+                    ));
+                }
                 ui.label(format!("{:?}", game_state.quests));
             });
     });
@@ -447,15 +375,13 @@ pub fn quests_skip(
         return;
     }
     if input.just_pressed(KeyCode::F2) {
-        game_state.quests.active_quest = Quest::End;
+        game_state.quests.active_quest = None;
         game_state.quests.talked_to_barkeep = true;
         game_state.dangerous_seas = true;
     }
     if input.just_pressed(KeyCode::Key7) {
-        game_state.quests.active_quest = Quest::Ringo(RingoQuest::default());
-        if let Quest::Ringo(quest) = &mut game_state.quests.active_quest {
-            quest.stage = RingoQuestStage::TalkedToMayor;
-        }
+        game_state.quests.start("ringo");
+        game_state.quests.set_stage(QuestStage::Sail);
         game_state.quests.talked_to_barkeep = true;
         game_state.dangerous_seas = true;
         game_state.attacks.shotgun_cannons = 1;
@@ -465,10 +391,8 @@ pub fn quests_skip(
         };
     }
     if input.just_pressed(KeyCode::Key8) {
-        game_state.quests.active_quest = Quest::Plank(PlankQuest::default());
-        if let Quest::Plank(quest) = &mut game_state.quests.active_quest {
-            quest.stage = PlankQuestStage::TalkedToMayor;
-        }
+        game_state.quests.start("plank");
+        game_state.quests.set_stage(QuestStage::Sail);
         game_state.quests.talked_to_barkeep = true;
         game_state.dangerous_seas = true;
         game_state.attacks.shotgun_cannons = 1;
@@ -479,10 +403,8 @@ pub fn quests_skip(
         };
     }
     if input.just_pressed(KeyCode::Key9) {
-        game_state.quests.active_quest = Quest::Davy(DavyQuest::default());
-        if let Quest::Davy(quest) = &mut game_state.quests.active_quest {
-            quest.stage = DavyQuestStage::TalkedToMayor;
-        }
+        game_state.quests.start("davy");
+        game_state.quests.set_stage(QuestStage::Sail);
         game_state.quests.talked_to_barkeep = true;
         game_state.dangerous_seas = true;
         game_state.attacks.shotgun_cannons = 1;
@@ -495,7 +417,11 @@ pub fn quests_skip(
     }
 }
 
+pub mod cutscenes;
 pub mod davy;
+pub mod definitions;
 pub mod jagerossa;
 pub mod plank;
 pub mod ringo;
+pub mod side_quests;
+pub mod trigger;
@@ -2,23 +2,16 @@ use crate::common::prelude::*;
 use crate::game::prelude::*;
 use bevy::prelude::*;
 
-use super::{Plank2Cutscene, PlankQuestStage};
-
 pub struct PlankPlugin;
 
 impl Plugin for PlankPlugin {
     fn build(&self, app: &mut App) {
-        app.add_event::<PlankSpawnEvent>()
-            .add_system(plank_spawn.before(BoatSystem::Spawn))
+        app.add_system(plank_spawn.before(BoatSystem::Spawn))
             .add_system(plank_move)
-            .add_system(plank_invincibility)
-            .add_system(plank_death_check);
+            .add_system(plank_invincibility);
     }
 }
 
-#[derive(Default, Clone, Copy)]
-pub struct PlankSpawnEvent;
-
 #[derive(Component)]
 pub struct Plank {
     target: Vec2,
@@ -55,7 +48,7 @@ fn plank_stats_by_health(health_percent: f32) -> PlankStatsByHealth {
 }
 
 fn plank_spawn(
-    mut ev_spawn: EventReader<PlankSpawnEvent>,
+    mut ev_spawn: EventReader<QuestBossSpawnEvent>,
     mut ev_boat_spawn: EventWriter<BoatSpawnEvent>,
     mut commands: Commands,
     world_locations: Res<WorldLocations>,
@@ -65,11 +58,15 @@ fn plank_spawn(
     asset_library: Res<AssetLibrary>,
 ) {
     let spawn_position = world_locations.get_single_position("PlankSpawn");
-    for _ in ev_spawn.iter() {
+    for _ in ev_spawn
+        .iter()
+        .filter(|event| event.captain == Captain::Plank)
+    {
         let stats = plank_stats_by_health(1.);
         ev_enemies_despawn.send_default();
         let entity = commands
             .spawn((
+                QuestBoss,
                 Plank {
                     target: world_locations.get_single_position("PlankMoveTo"),
                     angle: 0.,
@@ -89,7 +86,7 @@ fn plank_spawn(
             ))
             .id();
         ev_boss_healthbar_spawn.send(BossHealthbarSpawnEvent {
-            name: "Captain Plank Presley".to_owned(),
+            name: "quest.plank".to_owned(),
             entity,
         });
         overworld_camera.entity_focus(entity);
@@ -188,18 +185,3 @@ fn plank_invincibility(mut query: Query<(&mut Boat, &AutoDamage), With<Plank>>)
         };
     }
 }
-
-fn plank_death_check(
-    query: Query<Entity, With<Plank>>,
-    mut game_state: ResMut<GameState>,
-    mut ev_cutscene_plank2: EventWriter<CutsceneStartEvent<Plank2Cutscene>>,
-) {
-    if query.is_empty() {
-        if let Quest::Plank(quest) = &mut game_state.quests.active_quest {
-            if matches!(quest.stage, PlankQuestStage::Fight) {
-                ev_cutscene_plank2.send_default();
-                quest.stage = PlankQuestStage::Dialogue2;
-            }
-        }
-    }
-}
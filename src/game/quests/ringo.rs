@@ -2,23 +2,16 @@ use crate::common::prelude::*;
 use crate::game::prelude::*;
 use bevy::prelude::*;
 
-use super::{Ringo2Cutscene, RingoQuestStage};
-
 pub struct RingoPlugin;
 
 impl Plugin for RingoPlugin {
     fn build(&self, app: &mut App) {
-        app.add_event::<RingoSpawnEvent>()
-            .add_system(ringo_spawn.before(BoatSystem::Spawn))
+        app.add_system(ringo_spawn.before(BoatSystem::Spawn))
             .add_system(ringo_move)
-            .add_system(ringo_invincibility)
-            .add_system(ringo_death_check);
+            .add_system(ringo_invincibility);
     }
 }
 
-#[derive(Default, Clone, Copy)]
-pub struct RingoSpawnEvent;
-
 #[derive(Component)]
 pub struct Ringo {
     target: Vec2,
@@ -64,7 +57,7 @@ fn ringo_stats_by_health(health_percent: f32) -> RingoStatsByHealth {
 }
 
 fn ringo_spawn(
-    mut ev_spawn: EventReader<RingoSpawnEvent>,
+    mut ev_spawn: EventReader<QuestBossSpawnEvent>,
     mut ev_boat_spawn: EventWriter<BoatSpawnEvent>,
     mut commands: Commands,
     world_locations: Res<WorldLocations>,
@@ -74,11 +67,15 @@ fn ringo_spawn(
     asset_library: Res<AssetLibrary>,
 ) {
     let spawn_position = world_locations.get_single_position("RingoSpawn");
-    for _ in ev_spawn.iter() {
+    for _ in ev_spawn
+        .iter()
+        .filter(|event| event.captain == Captain::Ringo)
+    {
         let stats = ringo_stats_by_health(1.);
         ev_enemies_despawn.send_default();
         let entity = commands
             .spawn((
+                QuestBoss,
                 Ringo {
                     target: world_locations.get_single_position("RingoMoveTo"),
                     angle: 0.,
@@ -95,7 +92,7 @@ fn ringo_spawn(
             ))
             .id();
         ev_boss_healthbar_spawn.send(BossHealthbarSpawnEvent {
-            name: "Captain Ringo Yarr".to_owned(),
+            name: "quest.ringo".to_owned(),
             entity,
         });
         overworld_camera.entity_focus(entity);
@@ -197,18 +194,3 @@ fn ringo_invincibility(mut query: Query<(&mut Boat, &AutoDamage), With<Ringo>>)
         };
     }
 }
-
-fn ringo_death_check(
-    query: Query<Entity, With<Ringo>>,
-    mut game_state: ResMut<GameState>,
-    mut ev_cutscene_ringo2: EventWriter<CutsceneStartEvent<Ringo2Cutscene>>,
-) {
-    if query.is_empty() {
-        if let Quest::Ringo(quest) = &mut game_state.quests.active_quest {
-            if matches!(quest.stage, RingoQuestStage::Fight) {
-                ev_cutscene_ringo2.send_default();
-                quest.stage = RingoQuestStage::Dialogue2;
-            }
-        }
-    }
-}
@@ -0,0 +1,218 @@
+use super::definitions::{
+    side_quest_definition, SideQuestDefinition, SideQuestGiver, SideQuestObjective,
+    SideQuestReward, SideQuestTarget, SIDE_QUEST_DEFINITIONS,
+};
+use crate::common::prelude::*;
+use crate::game::prelude::*;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+pub struct SideQuestsPlugin;
+
+impl Plugin for SideQuestsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system(side_quests_dialogue_choices)
+            .add_system(side_quests_kill_progress.after(DamageSystem::AutoDie))
+            .add_system(side_quests_complete.after(side_quests_kill_progress));
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SideQuest {
+    pub id: String,
+    pub progress: u32,
+    pub completed: bool,
+}
+
+impl SideQuest {
+    pub fn new(definition: &SideQuestDefinition) -> Self {
+        Self {
+            id: definition.id.to_owned(),
+            progress: 0,
+            completed: false,
+        }
+    }
+
+    pub fn definition(&self) -> Option<&'static SideQuestDefinition> {
+        side_quest_definition(&self.id)
+    }
+
+    // side quests without a definition are never finished, they're dropped when a save loads
+    pub fn goal(&self) -> Option<u32> {
+        self.definition()
+            .map(|definition| definition.objective.goal())
+    }
+
+    pub fn finished(&self) -> bool {
+        self.goal().map_or(false, |goal| self.progress >= goal)
+    }
+}
+
+impl Quests {
+    // deliveries are never offered in the town they go to
+    pub fn side_quest_offer(
+        &self,
+        giver: SideQuestGiver,
+        town: &str,
+    ) -> Option<&'static SideQuestDefinition> {
+        SIDE_QUEST_DEFINITIONS.iter().find(|definition| {
+            definition.giver == giver
+                && !matches!(
+                    definition.objective,
+                    SideQuestObjective::Deliver { town: destination } if destination == town
+                )
+                && !self
+                    .side_quests
+                    .iter()
+                    .any(|side_quest| side_quest.id == definition.id)
+        })
+    }
+
+    pub fn accept_side_quest(&mut self, id: &str) {
+        if self
+            .side_quests
+            .iter()
+            .any(|side_quest| side_quest.id == id)
+        {
+            return;
+        }
+        if let Some(definition) = side_quest_definition(id) {
+            self.side_quests.push(SideQuest::new(definition));
+        }
+    }
+
+    pub fn active_side_quests(&self) -> impl Iterator<Item = &SideQuest> {
+        self.side_quests
+            .iter()
+            .filter(|side_quest| !side_quest.completed && side_quest.definition().is_some())
+    }
+
+    pub fn completed_side_quests(&self) -> impl Iterator<Item = &SideQuest> {
+        self.side_quests
+            .iter()
+            .filter(|side_quest| side_quest.completed && side_quest.definition().is_some())
+    }
+
+    // a save can hold side quests that were since removed or renamed
+    pub fn drop_unknown_side_quests(&mut self) {
+        self.side_quests.retain(|side_quest| {
+            let known = side_quest.definition().is_some();
+            if !known {
+                warn!("dropping unknown side quest {}", side_quest.id);
+            }
+            known
+        });
+    }
+
+    pub fn side_quests_town_visited(&mut self, town: &str) {
+        for side_quest in self.side_quests.iter_mut() {
+            if side_quest.completed {
+                continue;
+            }
+            let Some(definition) = side_quest.definition() else { continue };
+            if let SideQuestObjective::Deliver { town: destination } = definition.objective {
+                if destination == town {
+                    side_quest.progress = 1;
+                }
+            }
+        }
+    }
+}
+
+fn side_quests_dialogue_choices(
+    mut ev_choice: EventReader<DialogueChoiceEvent>,
+    mut dialogue: ResMut<Dialogue>,
+    mut game_state: ResMut<GameState>,
+) {
+    for event in ev_choice.iter() {
+        if let Some(id) = event.id.strip_prefix("side_quest_accept_") {
+            game_state.quests.accept_side_quest(id);
+            continue;
+        }
+        let (giver, no_work) = match event.id.as_str() {
+            "mayor_work" => (SideQuestGiver::Mayor, "mayor_no_work"),
+            "barkeep_work" => (SideQuestGiver::Barkeep, "barkeep_no_work"),
+            _ => continue,
+        };
+        if let Some(definition) = game_state
+            .quests
+            .side_quest_offer(giver, &game_state.town.name)
+        {
+            dialogue.continue_script(definition.offer_dialogue);
+        } else {
+            dialogue.continue_script(no_work);
+        }
+    }
+}
+
+fn side_quests_kill_progress(
+    mut ev_kill: EventReader<DamageKillEvent>,
+    mut game_state: ResMut<GameState>,
+    threat_level: Res<ThreatLevel>,
+) {
+    for event in ev_kill.iter() {
+        for side_quest in game_state.quests.side_quests.iter_mut() {
+            if side_quest.completed {
+                continue;
+            }
+            let Some(definition) = side_quest.definition() else { continue };
+            let SideQuestObjective::Sink {
+                target,
+                threat_level: waters,
+                count,
+            } = definition.objective
+            else {
+                continue;
+            };
+            let target_killed = match target {
+                SideQuestTarget::Octopus => event.kind == AutoDamageKind::Octopus,
+                SideQuestTarget::Turtle => event.kind == AutoDamageKind::Turtle,
+            };
+            if !target_killed {
+                continue;
+            }
+            if waters.map_or(false, |level| level != *threat_level) {
+                continue;
+            }
+            side_quest.progress = (side_quest.progress + 1).min(count);
+        }
+    }
+}
+
+fn side_quests_complete(
+    mut game_state: ResMut<GameState>,
+    mut ev_level_up: EventWriter<LevelUpSpawnEvent>,
+) {
+    if !game_state
+        .quests
+        .active_side_quests()
+        .any(|side_quest| side_quest.finished())
+    {
+        return;
+    }
+    let mut rewards = vec![];
+    for side_quest in game_state.quests.side_quests.iter_mut() {
+        if side_quest.completed || !side_quest.finished() {
+            continue;
+        }
+        let Some(definition) = side_quest.definition() else { continue };
+        side_quest.completed = true;
+        rewards.push(definition.reward);
+    }
+    for reward in rewards {
+        match reward {
+            SideQuestReward::Experience(amount) => {
+                // a big reward can be worth several levels, each one gives a skill point
+                let mut level_up = game_state.add_experience(amount);
+                while level_up {
+                    game_state.skill_points += 1;
+                    ev_level_up.send_default();
+                    level_up = game_state.add_experience(0.);
+                }
+            }
+            SideQuestReward::SkillPoints(amount) => {
+                game_state.skill_points += amount;
+            }
+        }
+    }
+}
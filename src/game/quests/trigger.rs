@@ -0,0 +1,60 @@
+use super::{
+    cutscenes::QuestIntroCutscene,
+    definitions::{QuestStage, QUEST_DEFINITIONS},
+};
+use crate::common::prelude::*;
+use crate::game::prelude::*;
+use bevy::prelude::*;
+
+pub struct QuestTriggerPlugin;
+
+impl Plugin for QuestTriggerPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system(quest_trigger_world_spawn)
+            .add_system(quest_trigger_check);
+    }
+}
+
+#[derive(Component)]
+pub struct QuestTrigger {
+    quest: &'static str,
+}
+
+fn quest_trigger_world_spawn(
+    mut ev_spawn: EventReader<WorldLocationsSpawnEvent>,
+    mut commands: Commands,
+    world_locations: Res<WorldLocations>,
+) {
+    for _ in ev_spawn.iter() {
+        for definition in QUEST_DEFINITIONS.iter() {
+            let triggers = world_locations.get_multiple_rect(definition.trigger);
+            for trigger in triggers {
+                commands.spawn((
+                    TransformBundle::default(),
+                    Transform2::from_translation(trigger.position)
+                        .with_depth((DepthLayer::Entity, 0.)),
+                    Trigger::new(CollisionShape::Rect { size: trigger.size }),
+                    QuestTrigger {
+                        quest: definition.id,
+                    },
+                ));
+            }
+        }
+    }
+}
+
+fn quest_trigger_check(
+    query: Query<(&Trigger, &QuestTrigger)>,
+    mut game_state: ResMut<GameState>,
+    mut ev_intro_cutscene: EventWriter<CutsceneStartEvent<QuestIntroCutscene>>,
+) {
+    for (trigger, quest_trigger) in query.iter() {
+        if trigger.triggered() {
+            let Some(quest) = &mut game_state.quests.active_quest else { continue };
+            if quest.id == quest_trigger.quest && quest.stage == QuestStage::Sail {
+                ev_intro_cutscene.send_default();
+                quest.stage = QuestStage::Dialogue1;
+            }
+        }
+    }
+}
@@ -0,0 +1,223 @@
+use crate::common::storage;
+use crate::game::prelude::*;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+pub const SAVE_VERSION: u32 = 4;
+pub const SAVE_SLOTS: usize = 3;
+
+pub struct SavePlugin;
+
+impl Plugin for SavePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SaveSlots>()
+            .add_event::<GameSaveEvent>()
+            .add_system(save_game);
+    }
+}
+
+#[derive(Default, Clone, Copy)]
+pub struct GameSaveEvent;
+
+#[derive(Clone, Debug)]
+pub struct SaveSummary {
+    pub index: u64,
+    pub town: String,
+    pub level: u32,
+}
+
+#[derive(Resource)]
+pub struct SaveSlots {
+    active: usize,
+    summaries: [Option<SaveSummary>; SAVE_SLOTS],
+}
+
+impl Default for SaveSlots {
+    fn default() -> Self {
+        let mut slots = Self {
+            active: 0,
+            summaries: Default::default(),
+        };
+        slots.refresh();
+        slots
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct SaveFile {
+    version: u32,
+    index: u64,
+    game_state: GameState,
+}
+
+impl SaveSlots {
+    pub fn refresh(&mut self) {
+        for slot in 0..SAVE_SLOTS {
+            self.summaries[slot] = read_save(slot).map(|save| SaveSummary {
+                index: save.index,
+                town: save.game_state.town.name.clone(),
+                level: save.game_state.level,
+            });
+        }
+    }
+
+    pub fn summary(&self, slot: usize) -> Option<&SaveSummary> {
+        self.summaries
+            .get(slot)
+            .and_then(|summary| summary.as_ref())
+    }
+
+    pub fn any(&self) -> bool {
+        self.summaries.iter().any(|summary| summary.is_some())
+    }
+
+    pub fn latest(&self) -> Option<usize> {
+        (0..SAVE_SLOTS)
+            .filter_map(|slot| self.summary(slot).map(|summary| (slot, summary.index)))
+            .max_by_key(|(_, index)| *index)
+            .map(|(slot, _)| slot)
+    }
+
+    pub fn active(&self) -> usize {
+        self.active
+    }
+
+    pub fn set_active(&mut self, slot: usize) {
+        self.active = slot.min(SAVE_SLOTS - 1);
+    }
+
+    pub fn start_new_game(&mut self) {
+        // fill empty slots first, then overwrite the oldest save
+        let empty = (0..SAVE_SLOTS).find(|slot| self.summary(*slot).is_none());
+        let oldest = (0..SAVE_SLOTS).min_by_key(|slot| {
+            self.summary(*slot)
+                .map(|summary| summary.index)
+                .unwrap_or(0)
+        });
+        self.active = empty.or(oldest).unwrap_or(0);
+    }
+
+    pub fn load(&mut self, slot: usize) -> Option<GameState> {
+        let save = read_save(slot)?;
+        self.set_active(slot);
+        let mut game_state = save.game_state;
+        // older saves kept every line of dialogue
+        game_state.truncate_dialogue_history();
+        game_state.quests.drop_unknown_side_quests();
+        game_state.checkpoint = Some(Box::new(game_state.clone()));
+        game_state.checkpoint_notification = false;
+        Some(game_state)
+    }
+
+    fn write(&mut self, game_state: &GameState) -> bool {
+        let index = self
+            .summaries
+            .iter()
+            .flatten()
+            .map(|summary| summary.index)
+            .max()
+            .unwrap_or(0)
+            + 1;
+        let save = SaveFile {
+            version: SAVE_VERSION,
+            index,
+            game_state: game_state.clone(),
+        };
+        let Ok(contents) = serde_json::to_string(&save) else { return false };
+        if storage::storage_write(&slot_key(self.active), &contents) {
+            self.summaries[self.active] = Some(SaveSummary {
+                index,
+                town: game_state.town.name.clone(),
+                level: game_state.level,
+            });
+            true
+        } else {
+            false
+        }
+    }
+}
+
+fn slot_key(slot: usize) -> String {
+    format!("save{}.json", slot + 1)
+}
+
+fn read_save(slot: usize) -> Option<SaveFile> {
+    let contents = storage::storage_read(&slot_key(slot))?;
+    let mut value: Value = serde_json::from_str(&contents).ok()?;
+    let version = value.get("version")?.as_u64()? as u32;
+    if version == 0 || version > SAVE_VERSION {
+        warn!("save slot {} has unsupported version {}", slot + 1, version);
+        return None;
+    }
+    for migration in MIGRATIONS.iter().skip(version as usize - 1) {
+        migration(&mut value);
+    }
+    match serde_json::from_value::<SaveFile>(value) {
+        Ok(save) => Some(save),
+        Err(err) => {
+            warn!("failed to read save slot {}: {}", slot + 1, err);
+            None
+        }
+    }
+}
+
+// MIGRATIONS[n] upgrades a save from version n + 1 to version n + 2
+// when the format changes, bump SAVE_VERSION and append a function that rewrites the old json
+const MIGRATIONS: [fn(&mut Value); SAVE_VERSION as usize - 1] = [
+    migrate_quest_definitions,
+    migrate_side_quests,
+    migrate_dialogue_history,
+];
+
+// version 2 replaced the enum of per captain quests with a quest id and a shared stage
+fn migrate_quest_definitions(save: &mut Value) {
+    let Some(quests) = save.pointer_mut("/game_state/quests") else { return };
+    let active_quest = match quests.get("active_quest") {
+        Some(Value::Object(quest)) => quest
+            .iter()
+            .next()
+            .map(|(captain, quest)| {
+                let stage = match quest.get("stage").and_then(Value::as_str) {
+                    Some("ControlsTutorial") | Some("TalkedToMayor") => "Sail",
+                    Some(stage) => stage,
+                    None => "TalkToMayor",
+                };
+                json!({ "id": captain.to_lowercase(), "stage": stage })
+            })
+            .unwrap_or(Value::Null),
+        _ => Value::Null,
+    };
+    quests["active_quest"] = active_quest;
+}
+
+// version 3 added side quests
+fn migrate_side_quests(save: &mut Value) {
+    let Some(quests) = save.pointer_mut("/game_state/quests") else { return };
+    quests["side_quests"] = json!([]);
+}
+
+// version 4 added the dialogue history
+fn migrate_dialogue_history(save: &mut Value) {
+    let Some(game_state) = save.pointer_mut("/game_state") else { return };
+    game_state["dialogue_history"] = json!([]);
+}
+
+fn save_game(
+    mut ev_save: EventReader<GameSaveEvent>,
+    mut save_slots: ResMut<SaveSlots>,
+    game_state: Res<GameState>,
+) {
+    if ev_save.iter().last().is_some() {
+        // the checkpoint is what gets saved, but the dialogue history is always the latest
+        let mut save_state = game_state
+            .checkpoint
+            .as_deref()
+            .unwrap_or(&*game_state)
+            .clone();
+        save_state.dialogue_history = game_state.dialogue_history.clone();
+        if !save_slots.write(&save_state) {
+            warn!("failed to write save slot {}", save_slots.active + 1);
+        }
+    }
+}
@@ -1,8 +1,14 @@
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
 
+use crate::common::prelude::*;
 use crate::game::prelude::*;
 
-#[derive(Clone, Debug, Resource)]
+// oldest entries fall off the front once the dialogue history is full
+pub const DIALOGUE_HISTORY_MAX: usize = 200;
+
+#[derive(Clone, Debug, Resource, Serialize, Deserialize)]
 pub struct GameState {
     pub town: TownData,
     pub band_members: [BandMember; 2],
@@ -18,7 +24,9 @@ pub struct GameState {
     pub experience: f32,
     pub level: u32,
     pub skill_points: u32,
+    pub dialogue_history: VecDeque<DialogueHistoryEntry>,
 
+    #[serde(skip)]
     pub checkpoint: Option<Box<GameState>>,
 }
 
@@ -44,6 +52,7 @@ impl Default for GameState {
             experience: 0.,
             level: 1,
             skill_points: 0,
+            dialogue_history: VecDeque::new(),
             checkpoint_notification: false,
             checkpoint: None,
         }
@@ -65,6 +74,7 @@ impl GameState {
                 dangerous_seas,
                 ..
             } = *self;
+            let dialogue_history = std::mem::take(&mut self.dialogue_history);
             *self = *checkpoint.clone();
             self.checkpoint = Some(checkpoint);
             self.checkpoint_notification = false;
@@ -72,12 +82,26 @@ impl GameState {
             self.level = level;
             self.skill_points = skill_points;
             self.dangerous_seas = dangerous_seas;
+            self.dialogue_history = dialogue_history;
             true
         } else {
             false
         }
     }
 
+    pub fn record_dialogue(&mut self, entry: DialogueHistoryEntry) {
+        self.dialogue_history.push_back(entry);
+        self.truncate_dialogue_history();
+    }
+
+    pub fn truncate_dialogue_history(&mut self) {
+        let excess = self
+            .dialogue_history
+            .len()
+            .saturating_sub(DIALOGUE_HISTORY_MAX);
+        self.dialogue_history.drain(..excess);
+    }
+
     pub fn member_in_band(&self, band_member: BandMember) -> bool {
         for i in 0..2 {
             if self.band_members[i] == band_member {
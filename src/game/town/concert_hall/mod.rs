@@ -61,7 +61,7 @@ fn concert_hall_init(
     commands.spawn((
         Text2dBundle {
             text: Text::from_section(
-                "Back to Town".to_owned(),
+                String::new(),
                 TextStyle {
                     font: asset_library.font_bold.clone(),
                     font_size: 64.0,
@@ -77,6 +77,7 @@ fn concert_hall_init(
         }),
         Transform2::from_xy(0., -320.).with_depth(DEPTH_LAYER_UPGRADES_LEAVE_TEXT),
         Leave,
+        LocalizedText::new("town.back"),
     ));
     commands.spawn((
         AudioPlusSource::new(asset_library.sound_effects.sfx_town_outside_click.clone()),
@@ -87,9 +88,7 @@ fn concert_hall_init(
         HoverSound,
     ));
     if !game_state.quests.upgrades_dialogue {
-        for (p, t) in UPGRADE_MENU.iter() {
-            dialogue.add_text(*p, String::from(*t));
-        }
+        dialogue.add_script("upgrade_menu");
         game_state.quests.upgrades_dialogue = true;
     }
 }
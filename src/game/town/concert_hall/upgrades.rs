@@ -91,32 +91,32 @@ impl UpgradesType {
     fn display_info(&self, asset_library: &AssetLibrary) -> UpgradesDisplayInfo {
         match *self {
             Self::Guitar => UpgradesDisplayInfo {
-                name: "Drums",
+                name: "upgrades.drums",
                 texture: asset_library.sprite_upgrades_ability_drums.clone(),
                 offset: Vec2::new(0., 0.),
             },
             Self::Drums => UpgradesDisplayInfo {
-                name: "Guitar",
+                name: "upgrades.guitar",
                 texture: asset_library.sprite_upgrades_ability_guitar.clone(),
                 offset: Vec2::new(0., 0.),
             },
             Self::Flute => UpgradesDisplayInfo {
-                name: "Flute",
+                name: "upgrades.flute",
                 texture: asset_library.sprite_upgrades_ability_flute.clone(),
                 offset: Vec2::new(0., -20.),
             },
             Self::Harmonica => UpgradesDisplayInfo {
-                name: "Harmonica",
+                name: "upgrades.harmonica",
                 texture: asset_library.sprite_upgrades_ability_harmonica.clone(),
                 offset: Vec2::new(0., -25.),
             },
             Self::Accordion => UpgradesDisplayInfo {
-                name: "Accordion",
+                name: "upgrades.accordion",
                 texture: asset_library.sprite_upgrades_ability_accordion.clone(),
                 offset: Vec2::new(0., -5.),
             },
             Self::Defense => UpgradesDisplayInfo {
-                name: "Defense",
+                name: "upgrades.defense",
                 texture: asset_library.sprite_upgrades_ability_defense.clone(),
                 offset: Vec2::new(0., 0.),
             },
@@ -172,16 +172,16 @@ impl UpgradesType {
     }
     fn upgrade_text(&self) -> Vec<&str> {
         match *self {
-            Self::Guitar => vec!["Increase damage"],
-            Self::Drums => vec!["Increase spread", "Minor damage increase"],
+            Self::Guitar => vec!["upgrades.increase_damage"],
+            Self::Drums => vec!["upgrades.increase_spread", "upgrades.minor_damage_increase"],
             Self::Flute => vec![
-                "Increase size",
-                "Increase knockback",
-                "Minor damage increase",
+                "upgrades.increase_size",
+                "upgrades.increase_knockback",
+                "upgrades.minor_damage_increase",
             ],
-            Self::Harmonica => vec!["Throw additional bomb"],
-            Self::Accordion => vec!["More tentacles"],
-            Self::Defense => vec!["Increase damage resistance"],
+            Self::Harmonica => vec!["upgrades.throw_additional_bomb"],
+            Self::Accordion => vec!["upgrades.more_tentacles"],
+            Self::Defense => vec!["upgrades.increase_damage_resistance"],
         }
     }
 }
@@ -226,7 +226,7 @@ fn upgrades_spawn(
                 parent.spawn((
                     Text2dBundle {
                         text: Text::from_section(
-                            "Level up to unlock additional skill points.",
+                            "",
                             TextStyle {
                                 font: asset_library.font_bold.clone(),
                                 font_size: 90.0,
@@ -238,6 +238,7 @@ fn upgrades_spawn(
                         ..Default::default()
                     },
                     Transform2::from_xy(-10., -730.).with_depth(DEPTH_LAYER_UPGRADES_SKILLPOINT),
+                    LocalizedText::new("upgrades.level_up_hint"),
                 ));
                 parent
                     .spawn((Transform2Bundle::default(), VisibilityBundle::default()))
@@ -290,7 +291,7 @@ fn upgrades_spawn(
                                     parent.spawn((
                                         Text2dBundle {
                                             text: Text::from_section(
-                                                if locked { "Locked" } else { display_info.name },
+                                                "",
                                                 TextStyle {
                                                     font: asset_library.font_bold.clone(),
                                                     font_size: 100.0,
@@ -303,6 +304,11 @@ fn upgrades_spawn(
                                         },
                                         Transform2::from_xy(-210., 68.)
                                             .with_depth(DEPTH_LAYER_UPGRADES_ABILITY_TEXT),
+                                        LocalizedText::new(if locked {
+                                            "upgrades.locked"
+                                        } else {
+                                            display_info.name
+                                        }),
                                     ));
                                     parent.spawn((
                                         SpriteSheetBundle {
@@ -509,6 +515,7 @@ fn upgrades_description(
     mut text_query: Query<(&mut Text, &UpgradesDescriptionText)>,
     upgrades_state: Res<UpgradesState>,
     screen_fade: Res<ScreenFade>,
+    locale: Res<Locale>,
 ) {
     if !screen_fade.faded_in() {
         return;
@@ -524,8 +531,9 @@ fn upgrades_description(
         }
         for (mut text, txt) in text_query.iter_mut() {
             if txt.index < upgrade_text.len() as u32 {
-                if text.sections[0].value != upgrade_text[txt.index as usize] {
-                    text.sections[0].value = upgrade_text[txt.index as usize].to_owned();
+                let value = locale.get(upgrade_text[txt.index as usize]);
+                if text.sections[0].value != value {
+                    text.sections[0].value = value.to_owned();
                 }
             } else {
                 if text.sections[0].value != "" {
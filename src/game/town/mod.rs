@@ -39,12 +39,6 @@ fn town_ambience(
                 Persistent,
                 TownAmbience,
             ));
-            commands.spawn((
-                AudioPlusSource::new(asset_library.sound_effects.sfx_town_music.clone())
-                    .as_looping(),
-                Persistent,
-                TownAmbience,
-            ));
         } else {
             for entity in query.iter() {
                 commands.entity(entity).despawn_recursive();
@@ -182,7 +182,7 @@ fn outside_init(
     commands.spawn((
         Text2dBundle {
             text: Text::from_section(
-                "Exit Town".to_owned(),
+                String::new(),
                 TextStyle {
                     font: asset_library.font_bold.clone(),
                     font_size: 64.0,
@@ -202,6 +202,7 @@ fn outside_init(
             last_hover: false,
         },
         Transform2::from_xy(470., -330.).with_depth(DEPTH_LAYER_TOWN_OUTSIDE_EXIT),
+        LocalizedText::new("town.exit"),
     ));
     commands.spawn((
         Text2dBundle {
@@ -218,6 +219,7 @@ fn outside_init(
             ..Default::default()
         },
         Transform2::from_xy(0., 330.).with_depth(DEPTH_LAYER_TOWN_OUTSIDE_NAME),
+        LocalizedText::new(&town_locale_id(&game_state.town.name)),
     ));
 
     commands.spawn((
@@ -268,9 +270,7 @@ fn outside_init(
         },
     ));
     if game_state.quests.end() && !game_state.quests.endgame_town_dialogue {
-        for (p, t) in JAGEROSSA_AFTER_VICTORY.iter() {
-            dialogue.add_text(*p, String::from(*t));
-        }
+        dialogue.add_script("jagerossa_after_victory");
         game_state.quests.endgame_town_dialogue = true;
     }
 }
@@ -352,15 +352,11 @@ fn outside_click(
                     }
                     ClickableAction::Leave => {
                         if game_state.quests.must_talk_to_mayor() {
-                            for (p, t) in MUST_TALK_TO_MAYOR.iter() {
-                                dialogue.add_text(*p, String::from(*t));
-                            }
+                            dialogue.add_script("must_talk_to_mayor");
                         } else if !game_state.quests.talked_to_barkeep
                             && game_state.health != game_state.health_max
                         {
-                            for (p, t) in MUST_TALK_TO_BARKEEP.iter() {
-                                dialogue.add_text(*p, String::from(*t));
-                            }
+                            dialogue.add_script("must_talk_to_barkeep");
                         } else {
                             screen_fade.fade_out(0.5);
                             state.leave = OutsideLeave::LeaveToOverworld;
@@ -380,10 +376,12 @@ fn outside_leave(
     screen_fade: Res<ScreenFade>,
     mut app_state: ResMut<NextState<AppState>>,
     mut game_state: ResMut<GameState>,
+    mut ev_game_save: EventWriter<GameSaveEvent>,
 ) {
     if screen_fade.faded_out() {
         if matches!(state.leave, OutsideLeave::LeaveToOverworld) {
             game_state.checkpoint();
+            ev_game_save.send_default();
             app_state.set(AppState::Overworld);
         } else if matches!(state.leave, OutsideLeave::LeaveToConcertHall) {
             app_state.set(AppState::TownConcertHall);
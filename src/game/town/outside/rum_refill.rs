@@ -4,11 +4,15 @@ use audio_plus::prelude::*;
 use bevy::prelude::*;
 
 const BACK_FADE: f32 = 0.36;
+const BOTTLE_COUNT: u32 = 10;
+const FADE_SECONDS: f32 = 0.33;
+const DROP_SECONDS: f32 = 0.18;
+// empty bottles drop in from here, relative to their slot
+const DROP_OFFSET: Vec2 = Vec2::new(100., 0.);
 
 #[derive(Default, Resource)]
 struct RumRefillState {
-    opacity: f32,
-    time: f32,
+    // the next bottle to fill
     index: u32,
 }
 
@@ -30,24 +34,86 @@ struct RumRefillParent;
 #[derive(Component)]
 struct RumRefillBottle {
     index: u32,
-    to: Vec2,
-    from: Vec2,
 }
 
+// bottles that were already full before the refill
+#[derive(Component)]
+struct RumRefillFull;
+
+#[derive(Component)]
+struct RumRefillDropping;
+
 #[derive(Default, Debug, Clone, Resource)]
 pub struct RumRefillCutscene;
 
 impl Cutscene for RumRefillCutscene {
     fn build(cutscene: &mut CutsceneBuilder) {
         cutscene.add_dialogue_step(|| {});
-        cutscene.add_step(init1, update1);
-        cutscene.add_step(|| {}, update2);
-        cutscene.add_step(|| {}, update3);
+        cutscene.add_quick_step(init);
+        cutscene.add_parallel(|parallel| {
+            parallel.add_chain(|chain| {
+                chain.add_tween::<RumRefillBg>(
+                    Tween::Opacity(0., BACK_FADE),
+                    Easing::Linear,
+                    FADE_SECONDS,
+                );
+            });
+            parallel.add_chain(|chain| {
+                chain.add_tween::<RumRefillFull>(
+                    Tween::Opacity(0., 1.),
+                    Easing::Linear,
+                    FADE_SECONDS,
+                );
+            });
+        });
+        // a branch per bottle, once every bottle is full the rest do nothing
+        for _ in 0..BOTTLE_COUNT {
+            cutscene.add_branch::<RumRefillState>(
+                |state| state.index < BOTTLE_COUNT,
+                |chain| {
+                    chain.add_quick_step(bottle_drop);
+                    chain.add_parallel(|parallel| {
+                        parallel.add_chain(|chain| {
+                            chain.add_tween::<RumRefillDropping>(
+                                Tween::Translation(DROP_OFFSET, Vec2::ZERO),
+                                Easing::BounceOut,
+                                DROP_SECONDS,
+                            );
+                        });
+                        parallel.add_chain(|chain| {
+                            chain.add_tween::<RumRefillDropping>(
+                                Tween::Opacity(0., 1.),
+                                Easing::Linear,
+                                DROP_SECONDS,
+                            );
+                        });
+                    });
+                    chain.add_quick_step(bottle_land);
+                },
+                |_| {},
+            );
+        }
+        cutscene.add_parallel(|parallel| {
+            parallel.add_chain(|chain| {
+                chain.add_tween::<RumRefillBg>(
+                    Tween::Opacity(BACK_FADE, 0.),
+                    Easing::Linear,
+                    FADE_SECONDS,
+                );
+            });
+            parallel.add_chain(|chain| {
+                chain.add_tween::<RumRefillBottle>(
+                    Tween::Opacity(1., 0.),
+                    Easing::Linear,
+                    FADE_SECONDS,
+                );
+            });
+        });
         cutscene.add_quick_step(cleanup);
     }
 }
 
-fn init1(
+fn init(
     mut commands: Commands,
     asset_library: Res<AssetLibrary>,
     mut state: ResMut<RumRefillState>,
@@ -87,80 +153,71 @@ fn init1(
                 Transform2::new().with_depth(DEPTH_LAYER_TOWN_OUTSIDE_RUM_REFILL_BG),
                 RumRefillBg,
             ));
-            for i in 0..10 {
+            for i in 0..BOTTLE_COUNT {
                 let x = (i as f32 - 4.5) * 40.;
                 let brightness = 0.6 + rand::random::<f32>() * 0.4;
-                let to = Vec2::new(x, 0.);
-                let from = Vec2::new(x + 100., 0.);
-                parent.spawn((
-                    SpriteSheetBundle {
-                        sprite: TextureAtlasSprite {
-                            color: Color::rgba(brightness, brightness, brightness, 0.),
-                            ..Default::default()
-                        },
-                        texture_atlas: asset_library.sprite_health_bottle_atlas.clone(),
-                        ..Default::default()
-                    },
-                    Transform2::from_translation(to)
-                        .with_rotation(rand::random::<f32>() * 0.2 - 0.1)
-                        .with_scale(Vec2::ONE * (1. + rand::random::<f32>() * 0.2 - 0.1))
-                        .with_depth((
-                            DEPTH_LAYER_TOWN_OUTSIDE_RUM_REFILL_BOTTLE.0,
-                            DEPTH_LAYER_TOWN_OUTSIDE_RUM_REFILL_BOTTLE.1 + brightness * 0.001,
-                        )),
-                    RumRefillBottle { index: i, to, from },
-                ));
+                let full = i < state.index;
+                parent
+                    .spawn((
+                        TransformBundle::default(),
+                        VisibilityBundle::default(),
+                        Transform2::from_xy(x, 0.),
+                    ))
+                    .with_children(|parent| {
+                        let mut bottle = parent.spawn((
+                            SpriteSheetBundle {
+                                sprite: TextureAtlasSprite {
+                                    color: Color::rgba(brightness, brightness, brightness, 0.),
+                                    ..Default::default()
+                                },
+                                texture_atlas: asset_library.sprite_health_bottle_atlas.clone(),
+                                ..Default::default()
+                            },
+                            Transform2::from_translation(if full {
+                                Vec2::ZERO
+                            } else {
+                                DROP_OFFSET
+                            })
+                            .with_rotation(rand::random::<f32>() * 0.2 - 0.1)
+                            .with_scale(Vec2::ONE * (1. + rand::random::<f32>() * 0.2 - 0.1))
+                            .with_depth((
+                                DEPTH_LAYER_TOWN_OUTSIDE_RUM_REFILL_BOTTLE.0,
+                                DEPTH_LAYER_TOWN_OUTSIDE_RUM_REFILL_BOTTLE.1 + brightness * 0.001,
+                            )),
+                            RumRefillBottle { index: i },
+                        ));
+                        if full {
+                            bottle.insert(RumRefillFull);
+                        }
+                    });
             }
         });
 }
 
-fn update1(
-    mut bg_query: Query<&mut Sprite, With<RumRefillBg>>,
-    mut bottle_query: Query<(&mut TextureAtlasSprite, &RumRefillBottle)>,
-    time: Res<Time>,
-    mut ev_continue: EventWriter<CutsceneContinueEvent<RumRefillCutscene>>,
-    mut state: ResMut<RumRefillState>,
+fn bottle_drop(
+    mut commands: Commands,
+    query: Query<(Entity, &RumRefillBottle)>,
+    state: Res<RumRefillState>,
 ) {
-    state.opacity += time.delta_seconds() * 3.;
-    state.opacity = state.opacity.clamp(0., 1.);
-    for mut sprite in bg_query.iter_mut() {
-        sprite.color.set_a(state.opacity * BACK_FADE);
-    }
-    for (mut sprite, bottle) in bottle_query.iter_mut() {
-        if state.index > bottle.index {
-            sprite.color.set_a(state.opacity);
+    for (entity, bottle) in query.iter() {
+        if bottle.index == state.index {
+            commands.entity(entity).insert(RumRefillDropping);
         }
     }
-    if state.opacity == 1. {
-        ev_continue.send_default();
-    }
 }
 
-fn update2(
-    mut ev_continue: EventWriter<CutsceneContinueEvent<RumRefillCutscene>>,
-    mut state: ResMut<RumRefillState>,
-    mut bottle_query: Query<(&mut TextureAtlasSprite, &mut Transform2, &RumRefillBottle)>,
-    time: Res<Time>,
+fn bottle_land(
     mut commands: Commands,
+    query: Query<Entity, With<RumRefillDropping>>,
+    mut state: ResMut<RumRefillState>,
+    cutscenes: Res<Cutscenes>,
     asset_library: Res<AssetLibrary>,
 ) {
-    state.time += time.delta_seconds() * 5.5;
-    state.time = state.time.clamp(0., 1.);
-    for (mut sprite, mut transform, bottle) in bottle_query.iter_mut() {
-        if bottle.index == state.index {
-            sprite.color.set_a(state.time);
-            transform.translation = bottle
-                .from
-                .lerp(bottle.to, ease(Easing::BounceOut, state.time));
-        }
+    for entity in query.iter() {
+        commands.entity(entity).remove::<RumRefillDropping>();
     }
-    if state.time == 1. {
-        state.index += 1;
-        if state.index == 10 {
-            ev_continue.send_default();
-        } else {
-            state.time = 0.
-        }
+    state.index += 1;
+    if !cutscenes.skipping() {
         commands.spawn((
             Transform2Bundle::default(),
             AudioPlusSource::new(
@@ -175,26 +232,6 @@ fn update2(
     }
 }
 
-fn update3(
-    mut bg_query: Query<&mut Sprite, With<RumRefillBg>>,
-    mut bottle_query: Query<&mut TextureAtlasSprite, With<RumRefillBottle>>,
-    time: Res<Time>,
-    mut ev_continue: EventWriter<CutsceneContinueEvent<RumRefillCutscene>>,
-    mut state: ResMut<RumRefillState>,
-) {
-    state.opacity -= time.delta_seconds() * 3.;
-    state.opacity = state.opacity.clamp(0., 1.);
-    for mut sprite in bg_query.iter_mut() {
-        sprite.color.set_a(state.opacity * BACK_FADE);
-    }
-    for mut sprite in bottle_query.iter_mut() {
-        sprite.color.set_a(state.opacity);
-    }
-    if state.opacity == 0. {
-        ev_continue.send_default();
-    }
-}
-
 fn cleanup(
     mut commands: Commands,
     query: Query<Entity, With<RumRefillParent>>,
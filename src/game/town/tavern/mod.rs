@@ -24,30 +24,30 @@ fn town_init(mut commands: Commands, asset_library: Res<AssetLibrary>, game_stat
             ..Default::default()
         })
         .with_children(|parent| {
-            parent.spawn(TextBundle {
-                style: Style {
-                    align_self: AlignSelf::Center,
-                    position_type: PositionType::Relative,
-                    position: UiRect {
-                        top: Val::Px(-50.),
+            parent.spawn((
+                TextBundle {
+                    style: Style {
+                        align_self: AlignSelf::Center,
+                        position_type: PositionType::Relative,
+                        position: UiRect {
+                            top: Val::Px(-50.),
+                            ..Default::default()
+                        },
                         ..Default::default()
                     },
+                    text: Text::from_section(
+                        "",
+                        TextStyle {
+                            font: asset_library.font_default.clone(),
+                            font_size: 42.0,
+                            color: Color::WHITE,
+                        },
+                    )
+                    .with_alignment(TextAlignment::Center),
                     ..Default::default()
                 },
-                text: Text::from_section(
-                    format!(
-                        "{}'s Tavern\n\nPress space to exit",
-                        game_state.town.clone().name,
-                    ),
-                    TextStyle {
-                        font: asset_library.font_default.clone(),
-                        font_size: 42.0,
-                        color: Color::WHITE,
-                    },
-                )
-                .with_alignment(TextAlignment::Center),
-                ..Default::default()
-            });
+                LocalizedText::new("town.tavern").with_arg(town_locale_id(&game_state.town.name)),
+            ));
         });
 }
 
@@ -1,5 +1,6 @@
 use crate::common::prelude::*;
 use asset_struct::AssetStruct;
+use audio_plus::prelude::*;
 use bevy::{prelude::*, sprite::Anchor};
 
 #[derive(Default, Resource)]
@@ -31,12 +32,11 @@ fn loading_init(
 ) {
     asset_library.load_assets(&asset_server);
     asset_library.create_texture_atlases(texture_atlas_assets.as_mut());
-    asset_library.create_sound_effects();
     commands.spawn(Camera2dBundle::default());
     commands.spawn((
         Text2dBundle {
             text: Text::from_section(
-                "Loading".to_owned(),
+                String::new(),
                 TextStyle {
                     font: asset_library.font_bold.clone(),
                     font_size: 68.0,
@@ -49,6 +49,7 @@ fn loading_init(
         },
         Transform2::new().with_depth((DepthLayer::Front, 0.)),
         LoadingText,
+        LocalizedText::new("loading.title"),
     ));
     commands.spawn((
         SpriteBundle {
@@ -79,19 +80,29 @@ fn loading_init(
 
 fn loading_update(
     mut app_state: ResMut<NextState<AppState>>,
-    asset_library: Res<AssetLibrary>,
+    mut asset_library: ResMut<AssetLibrary>,
     asset_server: Res<AssetServer>,
+    sound_effect_assets: Res<Assets<AudioPlusSoundEffects>>,
     mut screen_fade: ResMut<ScreenFade>,
     mut ev_dialogue_init: EventWriter<DialogueInitEvent>,
     mut state: ResMut<LoadingState>,
-    mut text_query: Query<&mut Text, With<LoadingText>>,
+    mut text_query: Query<(&mut Text, &mut LocalizedText), With<LoadingText>>,
     mut progress_query: Query<(&mut Transform2, &mut Sprite), With<LoadingProgress>>,
 ) {
     use bevy::asset::LoadState;
-    let failed = match asset_library.load_state(&asset_server) {
+    // the sound effect definitions load their audio as dependencies, wait for those as well
+    let load_state = match asset_library.load_state(&asset_server) {
+        LoadState::Loaded => sound_effect_assets
+            .get(&asset_library.sound_effect_definitions)
+            .map_or(LoadState::Loading, |definitions| {
+                asset_server.get_group_load_state(definitions.audio_sources())
+            }),
+        load_state => load_state,
+    };
+    let failed = match load_state {
         LoadState::Failed => {
-            for mut text in text_query.iter_mut() {
-                text.sections[0].value = "Failed to load assets.".to_owned();
+            for (mut text, mut localized) in text_query.iter_mut() {
+                localized.id = String::from("loading.failed");
                 text.sections[0].style.color = Color::rgb(1., 0.3, 0.3);
             }
             for (mut progress_transform, mut sprite) in progress_query.iter_mut() {
@@ -107,6 +118,7 @@ fn loading_update(
                 ev_dialogue_init.send_default();
             }
             if !state.fading {
+                asset_library.create_sound_effects(&sound_effect_assets);
                 screen_fade.enable();
                 screen_fade.set(0.);
                 screen_fade.fade_out(0.1);
@@ -0,0 +1,245 @@
+use super::{Logo, MenuState, Shine};
+use crate::common::{label::Label, prelude::*};
+use bevy::{prelude::*, sprite::Anchor};
+
+const CONTROLS_POSITION: Vec2 = Vec2::new(-440., 250.);
+const CONTROLS_COLUMN_WIDTH: f32 = 480.;
+const CONTROLS_ROW_HEIGHT: f32 = 46.;
+const CONTROLS_ROWS: usize = 9;
+const CONTROLS_ROW_SIZE: Vec2 = Vec2::new(420., 40.);
+
+pub struct ControlsMenuPlugin;
+
+impl Plugin for ControlsMenuPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<ControlsMenuSpawnEvent>()
+            .add_system(controls_menu_spawn)
+            .add_system(controls_menu_visibility)
+            .add_system(controls_menu_click)
+            .add_system(controls_menu_rebind)
+            .add_system(controls_menu_labels);
+    }
+}
+
+#[derive(Default, Clone, Copy)]
+pub struct ControlsMenuSpawnEvent;
+
+#[derive(Component)]
+struct ControlsMenu;
+
+// a clickable row, rebinds its action or resets all bindings when there is none
+#[derive(Component)]
+struct ControlsMenuRow {
+    action: Option<InputAction>,
+}
+
+#[derive(Component)]
+struct ControlsMenuBinding {
+    action: InputAction,
+}
+
+fn controls_menu_spawn(
+    mut ev_spawn: EventReader<ControlsMenuSpawnEvent>,
+    mut commands: Commands,
+    asset_library: Res<AssetLibrary>,
+) {
+    for _ in ev_spawn.iter() {
+        let text_style = TextStyle {
+            font: asset_library.font_bold.clone(),
+            font_size: 28.,
+            color: Color::BLACK,
+        };
+        commands
+            .spawn((
+                VisibilityBundle {
+                    visibility: Visibility::Hidden,
+                    ..Default::default()
+                },
+                TransformBundle::default(),
+                Transform2::new().without_pixel_perfect(),
+                ControlsMenu,
+                Label("Controls Menu".to_owned()),
+            ))
+            .with_children(|parent| {
+                parent.spawn((
+                    Text2dBundle {
+                        text: Text::from_section(
+                            "",
+                            TextStyle {
+                                font_size: 48.,
+                                ..text_style.clone()
+                            },
+                        )
+                        .with_alignment(TextAlignment::Center),
+                        text_anchor: Anchor::Center,
+                        ..Default::default()
+                    },
+                    Transform2::from_xy(0., CONTROLS_POSITION.y + 60.)
+                        .with_depth((DepthLayer::Front, 0.4)),
+                    LocalizedText::new("controls.title"),
+                ));
+                let rows = InputAction::ALL
+                    .iter()
+                    .map(|action| Some(*action))
+                    .chain(std::iter::once(None));
+                for (index, action) in rows.enumerate() {
+                    let column = index / CONTROLS_ROWS;
+                    let row = index % CONTROLS_ROWS;
+                    let position = CONTROLS_POSITION
+                        + Vec2::new(
+                            column as f32 * CONTROLS_COLUMN_WIDTH,
+                            -(row as f32) * CONTROLS_ROW_HEIGHT,
+                        );
+                    parent
+                        .spawn((
+                            SpriteBundle {
+                                sprite: Sprite {
+                                    custom_size: Some(CONTROLS_ROW_SIZE),
+                                    color: Color::rgba(0., 0., 0., 0.),
+                                    ..Default::default()
+                                },
+                                ..Default::default()
+                            },
+                            Transform2::from_translation(
+                                position + Vec2::new(CONTROLS_ROW_SIZE.x * 0.5, 0.),
+                            )
+                            .with_depth((DepthLayer::Front, 0.3)),
+                            Clickable {
+                                shape: CollisionShape::Rect {
+                                    size: CONTROLS_ROW_SIZE,
+                                },
+                                use_global: true,
+                                ..Default::default()
+                            },
+                            ControlsMenuRow { action },
+                        ))
+                        .with_children(|parent| {
+                            let name = action.map_or("controls.reset", |action| action.name());
+                            parent.spawn((
+                                Text2dBundle {
+                                    text: Text::from_section("", text_style.clone())
+                                        .with_alignment(TextAlignment::Left),
+                                    text_anchor: Anchor::CenterRight,
+                                    ..Default::default()
+                                },
+                                Transform2::from_xy(-CONTROLS_ROW_SIZE.x * 0.5 + 8., 0.)
+                                    .with_depth((DepthLayer::Front, 0.4)),
+                                LocalizedText::new(name),
+                            ));
+                            if let Some(action) = action {
+                                parent.spawn((
+                                    Text2dBundle {
+                                        text: Text::from_section("", text_style.clone())
+                                            .with_alignment(TextAlignment::Right),
+                                        text_anchor: Anchor::CenterLeft,
+                                        ..Default::default()
+                                    },
+                                    Transform2::from_xy(CONTROLS_ROW_SIZE.x * 0.5 - 8., 0.)
+                                        .with_depth((DepthLayer::Front, 0.4)),
+                                    ControlsMenuBinding { action },
+                                ));
+                            }
+                        });
+                }
+            });
+    }
+}
+
+fn controls_menu_visibility(
+    mut menu_query: Query<&mut Visibility, With<ControlsMenu>>,
+    mut logo_query: Query<&mut Visibility, (Or<(With<Logo>, With<Shine>)>, Without<ControlsMenu>)>,
+    menu_state: Res<MenuState>,
+) {
+    for mut visibility in menu_query.iter_mut() {
+        *visibility = if menu_state.show_controls {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+    }
+    for mut visibility in logo_query.iter_mut() {
+        *visibility = if menu_state.show_controls || menu_state.show_options {
+            Visibility::Hidden
+        } else {
+            Visibility::Inherited
+        };
+    }
+}
+
+fn controls_menu_click(
+    mut row_query: Query<(&mut Sprite, &Clickable, &ControlsMenuRow)>,
+    mut menu_state: ResMut<MenuState>,
+    mut mapping: ResMut<PlayerInputMapping>,
+) {
+    for (mut sprite, clickable, row) in row_query.iter_mut() {
+        let active = menu_state.show_controls && menu_state.rebinding.is_none();
+        let highlight = match row.action {
+            Some(action) => menu_state.rebinding == Some(action),
+            None => false,
+        } || (active && clickable.hovered);
+        sprite.color.set_a(if highlight { 0.15 } else { 0. });
+        if !active || !clickable.confirmed {
+            continue;
+        }
+        menu_state.swapped = None;
+        if let Some(action) = row.action {
+            menu_state.rebinding = Some(action);
+        } else {
+            mapping.reset();
+            if !mapping.save() {
+                warn!("failed to save controls");
+            }
+        }
+    }
+}
+
+fn controls_menu_rebind(
+    mut menu_state: ResMut<MenuState>,
+    mut mapping: ResMut<PlayerInputMapping>,
+    mut keys: ResMut<Input<KeyCode>>,
+    mut mouse: ResMut<Input<MouseButton>>,
+    gamepad_buttons: Res<Input<GamepadButton>>,
+) {
+    let Some(action) = menu_state.rebinding else { return };
+    let swapped = if let Some(key) = keys.get_just_pressed().next().copied() {
+        // escape cancels, and the key press must not leak into the rest of the menu
+        keys.reset(key);
+        if key != KeyCode::Escape {
+            mapping.bind(action, InputBinding::Key(key))
+        } else {
+            None
+        }
+    } else if let Some(button) = mouse.get_just_pressed().next().copied() {
+        mouse.reset(button);
+        mapping.bind(action, InputBinding::Mouse(button))
+    } else if let Some(button) = gamepad_buttons.get_just_pressed().next() {
+        mapping.bind_gamepad(action, button.button_type)
+    } else {
+        return;
+    };
+    menu_state.rebinding = None;
+    menu_state.swapped = swapped;
+    if !mapping.save() {
+        warn!("failed to save controls");
+    }
+}
+
+fn controls_menu_labels(
+    mut query: Query<(&mut Text, &ControlsMenuBinding)>,
+    mapping: Res<PlayerInputMapping>,
+    menu_state: Res<MenuState>,
+    locale: Res<Locale>,
+) {
+    for (mut text, binding) in query.iter_mut() {
+        let label = if menu_state.rebinding == Some(binding.action) {
+            locale.get("controls.press_key").to_owned()
+        } else if menu_state.swapped == Some(binding.action) {
+            locale.format("controls.swapped", &[&mapping.label(binding.action)])
+        } else {
+            mapping.label(binding.action)
+        };
+        if text.sections[0].value != label {
+            text.sections[0].value = label;
+        }
+    }
+}
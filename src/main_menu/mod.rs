@@ -1,12 +1,19 @@
 use crate::{
     common::{label::Label, prelude::*},
-    game::state::GameState,
+    game::{
+        data::town_data::town_locale_id,
+        save::{SaveSlots, SAVE_SLOTS},
+        state::GameState,
+    },
     DEV_BUILD,
 };
 use audio_plus::prelude::*;
 use bevy::{prelude::*, sprite::Anchor, window::WindowMode};
 
-use self::slider::VolumeSliderSpawnEvent;
+use self::{
+    controls::ControlsMenuSpawnEvent, options::OptionsMenuSpawnEvent,
+    slider::VolumeSliderSpawnEvent,
+};
 
 const LOGO_POSITION: Vec2 = Vec2::new(0., 115.);
 const LOGO_SCALE: Vec2 = Vec2::new(0.84, 0.84);
@@ -14,10 +21,35 @@ const LOGO_MOVEMENT_GROW: Vec2 = Vec2::new(1., 1.4);
 const BUTTON_SCALE: Vec2 = Vec2::new(0.72, 0.72);
 const BUTTON_POSITION: Vec2 = Vec2::new(80., -200.);
 const BUTTON_TEXT_SCALE: Vec2 = Vec2::new(0.8, 0.8);
+const SMALL_BUTTON_SCALE: Vec2 = Vec2::new(0.5, 0.5);
+const CONTINUE_BUTTON_POSITION: Vec2 = Vec2::new(-250., -200.);
+const LOAD_BUTTON_POSITION: Vec2 = Vec2::new(410., -200.);
+const SLOT_BUTTON_POSITION: Vec2 = Vec2::new(-390., -200.);
+const SLOT_BUTTON_SPACING: f32 = 240.;
+const CONTROLS_BUTTON_POSITION: Vec2 = Vec2::new(-510., -200.);
+const OPTIONS_BUTTON_POSITION: Vec2 = Vec2::new(-510., -290.);
 
 #[derive(Default, Resource)]
 struct MenuState {
     play: bool,
+    load_slot: Option<usize>,
+    show_slots: bool,
+    show_controls: bool,
+    show_options: bool,
+    rebinding: Option<InputAction>,
+    // the action that gave up its binding to the last rebind
+    swapped: Option<InputAction>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum MenuAction {
+    Play,
+    Continue,
+    Load,
+    Slot(usize),
+    Controls,
+    Options,
+    Back,
 }
 
 pub struct MainMenuPlugin;
@@ -25,12 +57,15 @@ pub struct MainMenuPlugin;
 impl Plugin for MainMenuPlugin {
     fn build(&self, app: &mut App) {
         app.add_plugin(slider::VolumeSliderPlugin)
+            .add_plugin(controls::ControlsMenuPlugin)
+            .add_plugin(options::OptionsMenuPlugin)
             .init_resource::<MenuState>()
             .add_system(menu_setup.in_schedule(OnEnter(AppState::MainMenu)))
             .add_system(menu_fade.in_set(OnUpdate(AppState::MainMenu)))
             .add_system(menu_logo)
             .add_system(menu_shine)
             .add_system(menu_button)
+            .add_system(menu_button_visibility.before(menu_button))
             .add_system(menu_background_move)
             .add_system(menu_outro_debug.in_set(OnUpdate(AppState::MainMenu)))
             .add_system(menu_fullscreen);
@@ -39,7 +74,10 @@ impl Plugin for MainMenuPlugin {
 
 #[derive(Component)]
 struct Button {
+    action: MenuAction,
+    position: Vec2,
     shape: CollisionShape,
+    enabled: bool,
     last_hover: bool,
     clicked: bool,
     audio_hover: Entity,
@@ -54,6 +92,14 @@ struct ButtonText {
     press: Handle<Image>,
 }
 
+#[derive(Component)]
+struct ButtonLabel;
+
+enum ButtonContent {
+    Image(ButtonText),
+    Label(LocalizedText),
+}
+
 #[derive(Component)]
 struct Sound;
 
@@ -81,39 +127,23 @@ fn menu_setup(
     mut cutscenes: ResMut<Cutscenes>,
     mut dialogue: ResMut<Dialogue>,
     mut ev_volume_slider_spawn: EventWriter<VolumeSliderSpawnEvent>,
+    mut ev_controls_menu_spawn: EventWriter<ControlsMenuSpawnEvent>,
+    mut ev_options_menu_spawn: EventWriter<OptionsMenuSpawnEvent>,
+    mut save_slots: ResMut<SaveSlots>,
 ) {
     *menu_state = MenuState::default();
+    save_slots.refresh();
     cutscenes.clear();
     dialogue.clear();
     screen_fade.fade_in(1.);
     ev_volume_slider_spawn.send_default();
+    ev_controls_menu_spawn.send_default();
+    ev_options_menu_spawn.send_default();
     commands.spawn(Camera2dBundle::default());
     commands.spawn((
         AudioPlusSource::new(asset_library.sound_effects.sfx_menu_ambient.clone()).as_looping(),
         Sound,
     ));
-    commands.spawn((
-        AudioPlusSource::new(asset_library.sound_effects.sfx_menu_music.clone()).as_looping(),
-        Sound,
-    ));
-    let hover_audio = commands
-        .spawn(AudioPlusSource::new(
-            asset_library.sound_effects.sfx_menu_button_hover.clone(),
-        ))
-        .id();
-    let click_audio = commands
-        .spawn(AudioPlusSource::new(
-            asset_library.sound_effects.sfx_menu_button_click.clone(),
-        ))
-        .id();
-    let click_confirm_audio = commands
-        .spawn(AudioPlusSource::new(
-            asset_library
-                .sound_effects
-                .sfx_menu_button_click_confirm
-                .clone(),
-        ))
-        .id();
     commands.spawn((
         SpriteBundle {
             texture: asset_library.menu_sprite_back.clone(),
@@ -153,52 +183,75 @@ fn menu_setup(
         Shine { x: 0. },
         Label("Shine".to_owned()),
     ));
-    commands
-        .spawn((
-            SpriteBundle {
-                texture: asset_library.menu_sprite_button_back.clone(),
-                ..Default::default()
-            },
-            Button {
-                shape: CollisionShape::Rect {
-                    size: Vec2::new(406., 159.) * BUTTON_SCALE,
-                },
-                last_hover: false,
-                clicked: false,
-                audio_hover: hover_audio,
-                audio_click: click_audio,
-                audio_click_confirm: click_confirm_audio,
-            },
-            Transform2::from_translation(BUTTON_POSITION)
-                .with_scale(Vec2::ONE * BUTTON_SCALE)
-                .with_depth((DepthLayer::Front, 0.3)),
-            Label("Play Button".to_owned()),
-        ))
-        .with_children(|parent| {
-            parent.spawn((
-                SpriteBundle {
-                    texture: asset_library.menu_sprite_button_play_normal.clone(),
-                    ..Default::default()
-                },
-                Transform2::new()
-                    .with_scale(BUTTON_TEXT_SCALE)
-                    .with_depth((DepthLayer::Front, 0.4)),
-                ButtonText {
-                    normal: asset_library.menu_sprite_button_play_normal.clone(),
-                    hover: asset_library.menu_sprite_button_play_hover.clone(),
-                    press: asset_library.menu_sprite_button_play_press.clone(),
-                },
-            ));
-            parent.spawn((
-                SpriteBundle {
-                    texture: asset_library.menu_sprite_skull.clone(),
-                    ..Default::default()
-                },
-                Transform2::from_xy(-210., 12.)
-                    .with_scale(Vec2::ONE * 1.2)
-                    .with_depth((DepthLayer::Front, 0.4)),
-            ));
-        });
+    spawn_button(
+        &mut commands,
+        &asset_library,
+        MenuAction::Play,
+        BUTTON_POSITION,
+        BUTTON_SCALE,
+        ButtonContent::Image(ButtonText {
+            normal: asset_library.menu_sprite_button_play_normal.clone(),
+            hover: asset_library.menu_sprite_button_play_hover.clone(),
+            press: asset_library.menu_sprite_button_play_press.clone(),
+        }),
+    );
+    spawn_button(
+        &mut commands,
+        &asset_library,
+        MenuAction::Continue,
+        CONTINUE_BUTTON_POSITION,
+        SMALL_BUTTON_SCALE,
+        ButtonContent::Label(LocalizedText::new("menu.continue")),
+    );
+    spawn_button(
+        &mut commands,
+        &asset_library,
+        MenuAction::Load,
+        LOAD_BUTTON_POSITION,
+        SMALL_BUTTON_SCALE,
+        ButtonContent::Label(LocalizedText::new("menu.load")),
+    );
+    spawn_button(
+        &mut commands,
+        &asset_library,
+        MenuAction::Controls,
+        CONTROLS_BUTTON_POSITION,
+        SMALL_BUTTON_SCALE,
+        ButtonContent::Label(LocalizedText::new("menu.controls")),
+    );
+    spawn_button(
+        &mut commands,
+        &asset_library,
+        MenuAction::Options,
+        OPTIONS_BUTTON_POSITION,
+        SMALL_BUTTON_SCALE,
+        ButtonContent::Label(LocalizedText::new("menu.options")),
+    );
+    for slot in 0..SAVE_SLOTS {
+        let label = if let Some(summary) = save_slots.summary(slot) {
+            LocalizedText::new("menu.slot")
+                .with_arg(town_locale_id(&summary.town))
+                .with_arg(summary.level)
+        } else {
+            LocalizedText::new("menu.slot_empty")
+        };
+        spawn_button(
+            &mut commands,
+            &asset_library,
+            MenuAction::Slot(slot),
+            SLOT_BUTTON_POSITION + Vec2::new(SLOT_BUTTON_SPACING * slot as f32, 0.),
+            SMALL_BUTTON_SCALE,
+            ButtonContent::Label(label),
+        );
+    }
+    spawn_button(
+        &mut commands,
+        &asset_library,
+        MenuAction::Back,
+        SLOT_BUTTON_POSITION + Vec2::new(SLOT_BUTTON_SPACING * SAVE_SLOTS as f32, 0.),
+        SMALL_BUTTON_SCALE,
+        ButtonContent::Label(LocalizedText::new("menu.back")),
+    );
 
     #[cfg(not(target_arch = "wasm32"))]
     commands.spawn((
@@ -235,7 +288,7 @@ fn menu_setup(
     commands.spawn((
         Text2dBundle {
             text: Text::from_section(
-                "A game for Bevy Jam #2",
+                "",
                 TextStyle {
                     font: asset_library.font_bold.clone(),
                     font_size: 48.0,
@@ -249,6 +302,7 @@ fn menu_setup(
         Transform2::from_xy(-632., -378.)
             .with_depth((DepthLayer::Front, 0.2))
             .with_scale(Vec2::ONE * 0.5),
+        LocalizedText::new("menu.credits"),
     ));
 
     commands.spawn((
@@ -271,6 +325,101 @@ fn menu_setup(
     ));
 }
 
+fn spawn_button(
+    commands: &mut Commands,
+    asset_library: &AssetLibrary,
+    action: MenuAction,
+    position: Vec2,
+    scale: Vec2,
+    content: ButtonContent,
+) {
+    let hover_audio = commands
+        .spawn(AudioPlusSource::new(
+            asset_library.sound_effects.sfx_menu_button_hover.clone(),
+        ))
+        .id();
+    let click_audio = commands
+        .spawn(AudioPlusSource::new(
+            asset_library.sound_effects.sfx_menu_button_click.clone(),
+        ))
+        .id();
+    let click_confirm_audio = commands
+        .spawn(AudioPlusSource::new(
+            asset_library
+                .sound_effects
+                .sfx_menu_button_click_confirm
+                .clone(),
+        ))
+        .id();
+    commands
+        .spawn((
+            SpriteBundle {
+                texture: asset_library.menu_sprite_button_back.clone(),
+                ..Default::default()
+            },
+            Button {
+                action,
+                position,
+                shape: CollisionShape::Rect {
+                    size: Vec2::new(406., 159.) * scale,
+                },
+                enabled: true,
+                last_hover: false,
+                clicked: false,
+                audio_hover: hover_audio,
+                audio_click: click_audio,
+                audio_click_confirm: click_confirm_audio,
+            },
+            Transform2::from_translation(position)
+                .with_scale(scale)
+                .with_depth((DepthLayer::Front, 0.3)),
+            Label("Menu Button".to_owned()),
+        ))
+        .with_children(|parent| match content {
+            ButtonContent::Image(button_text) => {
+                parent.spawn((
+                    SpriteBundle {
+                        texture: button_text.normal.clone(),
+                        ..Default::default()
+                    },
+                    Transform2::new()
+                        .with_scale(BUTTON_TEXT_SCALE)
+                        .with_depth((DepthLayer::Front, 0.4)),
+                    button_text,
+                ));
+                parent.spawn((
+                    SpriteBundle {
+                        texture: asset_library.menu_sprite_skull.clone(),
+                        ..Default::default()
+                    },
+                    Transform2::from_xy(-210., 12.)
+                        .with_scale(Vec2::ONE * 1.2)
+                        .with_depth((DepthLayer::Front, 0.4)),
+                ));
+            }
+            ButtonContent::Label(label) => {
+                parent.spawn((
+                    Text2dBundle {
+                        text: Text::from_section(
+                            "",
+                            TextStyle {
+                                font: asset_library.font_bold.clone(),
+                                font_size: 56.0,
+                                color: Color::WHITE,
+                            },
+                        )
+                        .with_alignment(TextAlignment::Center),
+                        text_anchor: Anchor::Center,
+                        ..Default::default()
+                    },
+                    Transform2::from_xy(0., 8.).with_depth((DepthLayer::Front, 0.4)),
+                    ButtonLabel,
+                    label,
+                ));
+            }
+        });
+}
+
 fn menu_logo(mut query: Query<(&mut Logo, &mut Transform2, &mut Sprite)>, time: Res<Time>) {
     for (mut logo, mut transform, mut sprite) in query.iter_mut() {
         logo.x += time.delta_seconds() * 3.;
@@ -300,18 +449,52 @@ fn play_sound(entity: Entity, sfx_query: &mut Query<&mut AudioPlusSource>) {
     }
 }
 
+fn menu_button_visibility(
+    mut button_query: Query<(&mut Button, &mut Visibility)>,
+    menu_state: Res<MenuState>,
+    save_slots: Res<SaveSlots>,
+) {
+    for (mut button, mut visibility) in button_query.iter_mut() {
+        let main = !menu_state.show_slots && !menu_state.show_controls && !menu_state.show_options;
+        let (visible, enabled) = match button.action {
+            MenuAction::Play | MenuAction::Controls | MenuAction::Options => (main, true),
+            MenuAction::Continue | MenuAction::Load => (main && save_slots.any(), true),
+            MenuAction::Slot(slot) => (menu_state.show_slots, save_slots.summary(slot).is_some()),
+            MenuAction::Back => (
+                menu_state.show_slots || menu_state.show_controls || menu_state.show_options,
+                true,
+            ),
+        };
+        button.enabled = visible && enabled && menu_state.rebinding.is_none();
+        if !button.enabled {
+            button.clicked = false;
+        }
+        *visibility = if visible {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+    }
+}
+
 fn menu_button(
     mut screen_fade: ResMut<ScreenFade>,
     mut button_query: Query<(&mut Button, &GlobalTransform, &Children, &mut Transform2)>,
     mut text_query: Query<(&ButtonText, &mut Handle<Image>)>,
+    mut label_query: Query<&mut Text, With<ButtonLabel>>,
     mut sfx_query: Query<&mut AudioPlusSource>,
     sound_query: Query<Entity, With<Sound>>,
     mouse: Res<Mouse>,
     input: Res<Input<MouseButton>>,
     mut menu_state: ResMut<MenuState>,
+    save_slots: Res<SaveSlots>,
 ) {
+    let mut show_slots = menu_state.show_slots;
+    let mut show_controls = menu_state.show_controls;
+    let mut show_options = menu_state.show_options;
     for (mut button, transform, children, mut transform2) in button_query.iter_mut() {
         let hover = !menu_state.play
+            && button.enabled
             && button.shape.overlaps(
                 transform.translation().truncate(),
                 CollisionShape::Point,
@@ -329,18 +512,36 @@ fn menu_button(
         }
         if button.clicked && input.just_released(MouseButton::Left) {
             if hover {
-                for entity in sound_query.iter() {
-                    if let Ok(mut source) = sfx_query.get_mut(entity) {
-                        source.stop();
+                match button.action {
+                    MenuAction::Load => show_slots = true,
+                    MenuAction::Controls => show_controls = true,
+                    MenuAction::Options => show_options = true,
+                    MenuAction::Back => {
+                        show_slots = false;
+                        show_controls = false;
+                        show_options = false;
+                        menu_state.swapped = None;
+                    }
+                    action => {
+                        menu_state.load_slot = match action {
+                            MenuAction::Continue => save_slots.latest(),
+                            MenuAction::Slot(slot) => Some(slot),
+                            _ => None,
+                        };
+                        for entity in sound_query.iter() {
+                            if let Ok(mut source) = sfx_query.get_mut(entity) {
+                                source.stop();
+                            }
+                        }
+                        menu_state.play = true;
+                        play_sound(button.audio_click_confirm, &mut sfx_query);
+                        screen_fade.fade_out(1.8);
                     }
                 }
-                menu_state.play = true;
-                play_sound(button.audio_click_confirm, &mut sfx_query);
-                screen_fade.fade_out(1.8);
             }
             button.clicked = false;
         }
-        transform2.translation = BUTTON_POSITION;
+        transform2.translation = button.position;
         if button.clicked && hover {
             transform2.translation += Vec2::new(-2., -2.);
         }
@@ -354,19 +555,41 @@ fn menu_button(
                     *image = text.normal.clone();
                 }
             }
+            if let Ok(mut label) = label_query.get_mut(*child) {
+                label.sections[0].style.color = if !button.enabled {
+                    Color::rgba(1., 1., 1., 0.4)
+                } else if button.clicked && hover {
+                    Color::rgb(0.8, 0.7, 0.5)
+                } else if hover {
+                    Color::rgb(1., 0.9, 0.6)
+                } else {
+                    Color::WHITE
+                };
+            }
         }
     }
+    menu_state.show_slots = show_slots;
+    menu_state.show_controls = show_controls;
+    menu_state.show_options = show_options;
 }
 
 fn menu_fade(
     menu_state: Res<MenuState>,
     mut game_state: ResMut<GameState>,
+    mut save_slots: ResMut<SaveSlots>,
     mut app_state: ResMut<NextState<AppState>>,
     screen_fade: Res<ScreenFade>,
 ) {
     if menu_state.play && screen_fade.faded_out() {
-        *game_state = GameState::default();
-        app_state.set(AppState::IntroCutscene);
+        if let Some(loaded_game_state) = menu_state.load_slot.and_then(|slot| save_slots.load(slot))
+        {
+            *game_state = loaded_game_state;
+            app_state.set(AppState::Overworld);
+        } else {
+            *game_state = GameState::default();
+            save_slots.start_new_game();
+            app_state.set(AppState::IntroCutscene);
+        }
     }
 }
 
@@ -416,4 +639,6 @@ fn menu_fullscreen(
     }
 }
 
+pub mod controls;
+pub mod options;
 pub mod slider;
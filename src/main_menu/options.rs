@@ -0,0 +1,87 @@
+use super::MenuState;
+use crate::common::{
+    audio_settings::spawn_audio_settings_panel, label::Label, locale::spawn_language_toggle,
+    prelude::*,
+};
+use bevy::{prelude::*, sprite::Anchor};
+
+pub struct OptionsMenuPlugin;
+
+impl Plugin for OptionsMenuPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<OptionsMenuSpawnEvent>()
+            .add_system(options_menu_spawn)
+            .add_system(options_menu_visibility);
+    }
+}
+
+#[derive(Default, Clone, Copy)]
+pub struct OptionsMenuSpawnEvent;
+
+#[derive(Component)]
+struct OptionsMenu;
+
+fn options_menu_spawn(
+    mut ev_spawn: EventReader<OptionsMenuSpawnEvent>,
+    mut commands: Commands,
+    asset_library: Res<AssetLibrary>,
+) {
+    for _ in ev_spawn.iter() {
+        commands
+            .spawn((
+                VisibilityBundle {
+                    visibility: Visibility::Hidden,
+                    ..Default::default()
+                },
+                TransformBundle::default(),
+                Transform2::new().without_pixel_perfect(),
+                OptionsMenu,
+                Label("Options Menu".to_owned()),
+            ))
+            .with_children(|parent| {
+                parent.spawn((
+                    Text2dBundle {
+                        text: Text::from_section(
+                            "",
+                            TextStyle {
+                                font: asset_library.font_bold.clone(),
+                                font_size: 48.,
+                                color: Color::BLACK,
+                            },
+                        )
+                        .with_alignment(TextAlignment::Center),
+                        text_anchor: Anchor::Center,
+                        ..Default::default()
+                    },
+                    Transform2::from_xy(0., 280.).with_depth((DepthLayer::Front, 0.4)),
+                    LocalizedText::new("options.title"),
+                ));
+                spawn_audio_settings_panel(
+                    parent,
+                    &asset_library,
+                    Vec2::new(90., 200.),
+                    (DepthLayer::Front, 0.4),
+                );
+                // below the mute toggle
+                spawn_language_toggle(
+                    parent,
+                    &asset_library,
+                    Vec2::new(-150., -136.),
+                    (DepthLayer::Front, 0.401),
+                );
+            });
+    }
+}
+
+fn options_menu_visibility(
+    mut menu_query: Query<&mut Visibility, With<OptionsMenu>>,
+    menu_state: Res<MenuState>,
+) {
+    for mut visibility in menu_query.iter_mut() {
+        *visibility = if menu_state.show_options {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+    }
+}
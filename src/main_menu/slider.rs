@@ -22,8 +22,14 @@ fn volume_slider_spawn(
     mut ev_spawn: EventReader<VolumeSliderSpawnEvent>,
     mut commands: Commands,
     asset_library: Res<AssetLibrary>,
+    mapping: Res<PlayerInputMapping>,
 ) {
     for _ in ev_spawn.iter() {
+        let volume_keys = format!(
+            "change volume anytime with {}/{}",
+            mapping.label(InputAction::VolumeDown),
+            mapping.label(InputAction::VolumeUp)
+        );
         commands
             .spawn((
                 VisibilityBundle::default(),
@@ -71,7 +77,7 @@ fn volume_slider_spawn(
         commands.spawn((
             Text2dBundle {
                 text: Text::from_section(
-                    "change volume anytime with O/P",
+                    volume_keys,
                     TextStyle {
                         font: asset_library.font_bold.clone(),
                         font_size: 24.0,
@@ -0,0 +1,153 @@
+use bevy::prelude::*;
+use jam::common::prelude::*;
+
+const CELL_SIZE: f32 = 10.;
+
+fn query(broadphase: &Broadphase, min: Vec2, max: Vec2) -> Vec<usize> {
+    let mut results = vec![];
+    broadphase.query(min, max, &mut results);
+    results
+}
+
+fn entry(index: u32, position: Vec2) -> CollisionQueryEntry {
+    CollisionQueryEntry {
+        entity: Entity::from_raw(index),
+        position,
+        shape: CollisionShape::Rect {
+            size: Vec2::splat(4.),
+        },
+        flags: COLLISION_FLAG,
+    }
+}
+
+#[test]
+fn bounds_on_a_cell_boundary_are_in_both_cells() {
+    let mut broadphase = Broadphase::new(CELL_SIZE);
+    broadphase.insert(0, Vec2::new(8., 2.), Vec2::new(10., 4.));
+    assert_eq!(
+        query(&broadphase, Vec2::new(2., 2.), Vec2::new(4., 4.)),
+        [0]
+    );
+    assert_eq!(
+        query(&broadphase, Vec2::new(12., 2.), Vec2::new(14., 4.)),
+        [0]
+    );
+    assert!(query(&broadphase, Vec2::new(22., 2.), Vec2::new(24., 4.)).is_empty());
+}
+
+#[test]
+fn negative_cells_round_down() {
+    let mut broadphase = Broadphase::new(CELL_SIZE);
+    broadphase.insert(0, Vec2::new(-4., -4.), Vec2::new(-1., -1.));
+    assert!(query(&broadphase, Vec2::new(1., 1.), Vec2::new(4., 4.)).is_empty());
+    assert_eq!(
+        query(&broadphase, Vec2::new(-9., -9.), Vec2::new(-8., -8.)),
+        [0]
+    );
+}
+
+#[test]
+fn bounds_spanning_many_cells_are_found_once() {
+    let mut broadphase = Broadphase::new(CELL_SIZE);
+    broadphase.insert(0, Vec2::new(0., 0.), Vec2::new(35., 35.));
+    broadphase.insert(1, Vec2::new(5., 5.), Vec2::new(6., 6.));
+    assert_eq!(
+        query(&broadphase, Vec2::new(-5., -5.), Vec2::new(25., 25.)),
+        [0, 1]
+    );
+    assert_eq!(
+        broadphase
+            .iter(Vec2::new(15., 15.), Vec2::new(38., 38.))
+            .collect::<Vec<_>>(),
+        [0]
+    );
+}
+
+#[test]
+fn large_bounds_are_always_candidates() {
+    let mut broadphase = Broadphase::new(CELL_SIZE);
+    broadphase.insert(0, Vec2::new(-500., -500.), Vec2::new(500., 500.));
+    broadphase.insert(1, Vec2::new(5., 5.), Vec2::new(6., 6.));
+    assert_eq!(
+        query(
+            &broadphase,
+            Vec2::new(2000., 2000.),
+            Vec2::new(2001., 2001.)
+        ),
+        [0]
+    );
+    // a query too large for the grid gets everything
+    assert_eq!(
+        query(
+            &broadphase,
+            Vec2::new(-1000., -1000.),
+            Vec2::new(1000., 1000.)
+        ),
+        [0, 1]
+    );
+}
+
+#[test]
+fn non_finite_queries_find_nothing() {
+    let mut broadphase = Broadphase::new(CELL_SIZE);
+    broadphase.insert(0, Vec2::new(-500., -500.), Vec2::new(500., 500.));
+    broadphase.insert(1, Vec2::new(5., 5.), Vec2::new(6., 6.));
+    assert!(query(&broadphase, Vec2::new(f32::NAN, 0.), Vec2::new(1., 1.)).is_empty());
+}
+
+#[test]
+fn updated_bounds_leave_their_old_cells() {
+    let mut broadphase = Broadphase::new(CELL_SIZE);
+    broadphase.insert(0, Vec2::new(2., 2.), Vec2::new(4., 4.));
+    broadphase.update(
+        0,
+        (Vec2::new(2., 2.), Vec2::new(4., 4.)),
+        (Vec2::new(42., 2.), Vec2::new(44., 4.)),
+    );
+    assert!(query(&broadphase, Vec2::new(1., 1.), Vec2::new(5., 5.)).is_empty());
+    assert_eq!(
+        query(&broadphase, Vec2::new(41., 1.), Vec2::new(45., 5.)),
+        [0]
+    );
+    broadphase.update(
+        0,
+        (Vec2::new(42., 2.), Vec2::new(44., 4.)),
+        (Vec2::new(-500., -500.), Vec2::new(500., 500.)),
+    );
+    assert_eq!(
+        query(
+            &broadphase,
+            Vec2::new(2000., 2000.),
+            Vec2::new(2001., 2001.)
+        ),
+        [0]
+    );
+}
+
+#[test]
+fn translated_entries_collide_where_they_moved_to() {
+    let mut collision_query = CollisionQuery::default();
+    collision_query.insert(entry(0, Vec2::ZERO));
+    let shape = CollisionShape::Point;
+    assert!(collision_query.check(Vec2::ZERO, shape, None).is_some());
+    collision_query.translate(Entity::from_raw(0), Vec2::new(600., 0.));
+    assert!(collision_query.check(Vec2::ZERO, shape, None).is_none());
+    assert_eq!(
+        collision_query.check(Vec2::new(600., 0.), shape, None),
+        Some((Entity::from_raw(0), Vec2::ZERO))
+    );
+}
+
+#[test]
+fn hitbox_layer_is_kept_apart_from_collisions() {
+    let mut collision_query = CollisionQuery::default();
+    collision_query.insert(entry(0, Vec2::ZERO));
+    collision_query.insert_layer(CollisionLayer::Hitbox, entry(1, Vec2::new(1., 0.)));
+    let shape = CollisionShape::Point;
+    assert_eq!(collision_query.check(Vec2::new(2.5, 0.), shape, None), None);
+    let hitboxes = collision_query
+        .layer_candidates(CollisionLayer::Hitbox, Vec2::splat(-1.), Vec2::splat(1.))
+        .map(|entry| entry.entity)
+        .collect::<Vec<_>>();
+    assert_eq!(hitboxes, [Entity::from_raw(1)]);
+}
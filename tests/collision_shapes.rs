@@ -0,0 +1,236 @@
+use bevy::prelude::*;
+use jam::common::prelude::*;
+use proptest::prelude::*;
+use std::f32::consts::PI;
+
+// spacing of the brute force sample grid
+const SAMPLE_SPACING: f32 = 1.;
+
+// slack given to the sweep when comparing against sampled times
+const TIME_TOLERANCE: f32 = 1e-3;
+
+const TIME_SAMPLES: usize = 32;
+
+fn shape() -> impl Strategy<Value = CollisionShape> {
+    let size = (1f32..60., 1f32..60.).prop_map(|(x, y)| Vec2::new(x, y));
+    prop_oneof![
+        Just(CollisionShape::Point),
+        size.clone().prop_map(|size| CollisionShape::Rect { size }),
+        (1f32..30.).prop_map(|radius| CollisionShape::Circle { radius }),
+        (0f32..60., 1f32..20., -PI..PI).prop_map(|(length, radius, rotation)| {
+            CollisionShape::Capsule {
+                length,
+                radius,
+                rotation,
+            }
+        }),
+        (size, -PI..PI)
+            .prop_map(|(size, rotation)| CollisionShape::OrientedRect { size, rotation }),
+    ]
+}
+
+fn vec2(range: f32) -> impl Strategy<Value = Vec2> {
+    (-range..range, -range..range).prop_map(|(x, y)| Vec2::new(x, y))
+}
+
+fn segment_distance(point: Vec2, start: Vec2, end: Vec2) -> f32 {
+    let segment = end - start;
+    let t = if segment.length_squared() == 0. {
+        0.
+    } else {
+        ((point - start).dot(segment) / segment.length_squared()).clamp(0., 1.)
+    };
+    point.distance(start + segment * t)
+}
+
+// whether point is inside the shape after growing it by margin, which may be negative
+fn inside(shape: CollisionShape, position: Vec2, point: Vec2, margin: f32) -> bool {
+    let offset = point - position;
+    match shape {
+        CollisionShape::None => false,
+        CollisionShape::Point => offset.length() <= margin,
+        CollisionShape::Rect { size } => {
+            offset.x.abs() <= size.x * 0.5 + margin && offset.y.abs() <= size.y * 0.5 + margin
+        }
+        CollisionShape::Circle { radius } => offset.length() <= radius + margin,
+        CollisionShape::Capsule {
+            length,
+            radius,
+            rotation,
+        } => {
+            let axis = Vec2::from_angle(rotation) * length * 0.5;
+            segment_distance(offset, -axis, axis) <= radius + margin
+        }
+        CollisionShape::OrientedRect { size, rotation } => {
+            let local = Vec2::from_angle(-rotation).rotate(offset);
+            local.x.abs() <= size.x * 0.5 + margin && local.y.abs() <= size.y * 0.5 + margin
+        }
+    }
+}
+
+// furthest any point of the shape gets from its position
+fn reach(shape: CollisionShape) -> f32 {
+    match shape {
+        CollisionShape::None | CollisionShape::Point => 0.,
+        CollisionShape::Rect { size } | CollisionShape::OrientedRect { size, .. } => {
+            size.length() * 0.5
+        }
+        CollisionShape::Circle { radius } => radius,
+        CollisionShape::Capsule { length, radius, .. } => length * 0.5 + radius,
+    }
+}
+
+// moving points and rects keep the sweep they always had, which only handles rect pairs
+fn swept(a_shape: CollisionShape, b_shape: CollisionShape) -> bool {
+    use CollisionShape::*;
+    !matches!(
+        (a_shape, b_shape),
+        (Point, Point) | (Point, Rect { .. }) | (Rect { .. }, Point)
+    )
+}
+
+fn samples(
+    a_shape: CollisionShape,
+    a_position: Vec2,
+    b_shape: CollisionShape,
+    b_position: Vec2,
+) -> impl Iterator<Item = Vec2> {
+    let margin = SAMPLE_SPACING * 2.;
+    let min = (a_position - reach(a_shape)).max(b_position - reach(b_shape)) - margin;
+    let max = (a_position + reach(a_shape)).min(b_position + reach(b_shape)) + margin;
+    let steps = ((max - min) / SAMPLE_SPACING)
+        .ceil()
+        .max(Vec2::ZERO)
+        .as_uvec2();
+    (0..=steps.y).flat_map(move |y| {
+        (0..=steps.x).map(move |x| min + Vec2::new(x as f32, y as f32) * SAMPLE_SPACING)
+    })
+}
+
+fn sampled_overlap(
+    a_shape: CollisionShape,
+    a_position: Vec2,
+    b_shape: CollisionShape,
+    b_position: Vec2,
+    margin: f32,
+) -> bool {
+    samples(a_shape, a_position, b_shape, b_position).any(|point| {
+        inside(a_shape, a_position, point, margin) && inside(b_shape, b_position, point, margin)
+    })
+}
+
+proptest! {
+    #[test]
+    fn overlaps_when_samples_are_inside_both(
+        a_shape in shape(),
+        a_position in vec2(40.),
+        b_shape in shape(),
+        b_position in vec2(40.),
+    ) {
+        if sampled_overlap(a_shape, a_position, b_shape, b_position, -0.01) {
+            prop_assert!(a_shape.overlaps(a_position, b_shape, b_position));
+            prop_assert!(b_shape.overlaps(b_position, a_shape, a_position));
+        }
+    }
+
+    #[test]
+    fn overlaps_only_when_samples_are_nearby(
+        a_shape in shape(),
+        a_position in vec2(40.),
+        b_shape in shape(),
+        b_position in vec2(40.),
+    ) {
+        if a_shape.overlaps(a_position, b_shape, b_position) {
+            prop_assert!(sampled_overlap(
+                a_shape,
+                a_position,
+                b_shape,
+                b_position,
+                SAMPLE_SPACING
+            ));
+        }
+    }
+
+    #[test]
+    fn bounds_cover_the_shape(shape in shape(), position in vec2(40.)) {
+        let (min, max) = shape.bounds(position).unwrap();
+        for point in samples(shape, position, shape, position) {
+            if inside(shape, position, point, -0.01) {
+                prop_assert!(point.cmpge(min).all() && point.cmple(max).all());
+            }
+        }
+    }
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(64))]
+
+    #[test]
+    fn overlaps_moving_finds_the_first_contact(
+        a_shape in shape(),
+        a_position in vec2(120.),
+        a_velocity in vec2(160.),
+        b_shape in shape(),
+        b_position in vec2(120.),
+        b_velocity in vec2(160.),
+    ) {
+        prop_assume!(swept(a_shape, b_shape));
+        let hit = a_shape.overlaps_moving(a_position, a_velocity, b_shape, b_position, b_velocity);
+        let first_sampled = (0..=TIME_SAMPLES)
+            .map(|step| step as f32 / TIME_SAMPLES as f32)
+            .find(|t| {
+                a_shape.overlaps(a_position + a_velocity * *t, b_shape, b_position + b_velocity * *t)
+            });
+        if let Some(sampled) = first_sampled {
+            prop_assert!(matches!(hit, Some(t) if t <= sampled + TIME_TOLERANCE), "{:?} {}", hit, sampled);
+        }
+        if let Some(t) = hit {
+            prop_assert!((0. ..=1.).contains(&t));
+            prop_assert!(sampled_overlap(
+                a_shape,
+                a_position + a_velocity * t,
+                b_shape,
+                b_position + b_velocity * t,
+                SAMPLE_SPACING,
+            ));
+            let before = t - TIME_TOLERANCE;
+            if before > 0. {
+                prop_assert!(!a_shape.overlaps(
+                    a_position + a_velocity * before,
+                    b_shape,
+                    b_position + b_velocity * before,
+                ));
+            }
+        }
+    }
+}
+
+#[test]
+fn moving_points_are_not_swept() {
+    let point = CollisionShape::Point;
+    let rect = CollisionShape::Rect {
+        size: Vec2::splat(10.),
+    };
+    let circle = CollisionShape::Circle { radius: 5. };
+    let (from, velocity) = (Vec2::new(-20., 0.), Vec2::new(40., 0.));
+    assert_eq!(
+        point.overlaps_moving(from, velocity, rect, Vec2::ZERO, Vec2::ZERO),
+        None
+    );
+    assert_eq!(
+        rect.overlaps_moving(Vec2::ZERO, Vec2::ZERO, point, from, velocity),
+        None
+    );
+    assert_eq!(
+        point.overlaps_moving(from, velocity, point, Vec2::ZERO, Vec2::ZERO),
+        None
+    );
+    let hit = point
+        .overlaps_moving(from, velocity, circle, Vec2::ZERO, Vec2::ZERO)
+        .unwrap();
+    assert!((hit - 0.375).abs() < 1e-4, "{}", hit);
+    let hit = rect
+        .overlaps_moving(from, velocity, rect, Vec2::ZERO, Vec2::ZERO)
+        .unwrap();
+    assert!((hit - 0.25).abs() < 1e-4, "{}", hit);
+}
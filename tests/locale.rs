@@ -0,0 +1,86 @@
+use jam::common::{
+    assets::dialogue::DialogueScript,
+    dialogue::{dialogue_choice_key, dialogue_line_key},
+    prelude::*,
+};
+use std::collections::BTreeSet;
+
+const ENGLISH: &str = include_str!("../assets/locale/en.locale.ron");
+const SPANISH: &str = include_str!("../assets/locale/es.locale.ron");
+const STORY: &str = include_str!("../assets/dialogue/story.dialogue.ron");
+
+// english dialogue is the script text, only the other languages translate it
+const TRANSLATIONS: [&str; 1] = [SPANISH];
+
+fn keys(contents: &str) -> BTreeSet<String> {
+    ron::de::from_str::<LocaleAsset>(contents)
+        .unwrap()
+        .strings
+        .into_keys()
+        .collect()
+}
+
+fn dialogue_keys() -> BTreeSet<String> {
+    let scripts = ron::de::from_str::<Vec<DialogueScript>>(STORY).unwrap();
+    let mut keys = BTreeSet::new();
+    for script in scripts.iter() {
+        for line in script.lines.iter() {
+            let key = dialogue_line_key(&script.id, &line.id);
+            assert!(keys.insert(key.clone()), "duplicate line id {}", key);
+        }
+        for choice in script.choices.iter() {
+            let key = dialogue_choice_key(&script.id, &choice.id);
+            assert!(keys.insert(key.clone()), "duplicate choice id {}", key);
+        }
+    }
+    keys
+}
+
+#[test]
+fn every_language_has_the_same_keys() {
+    let dialogue = dialogue_keys();
+    let english = keys(ENGLISH);
+    for translation in TRANSLATIONS {
+        let translated = keys(translation)
+            .difference(&dialogue)
+            .cloned()
+            .collect::<BTreeSet<_>>();
+        let missing = english.difference(&translated).collect::<Vec<_>>();
+        let extra = translated.difference(&english).collect::<Vec<_>>();
+        assert!(
+            missing.is_empty() && extra.is_empty(),
+            "missing from translation: {:?}, only in translation: {:?}",
+            missing,
+            extra
+        );
+    }
+}
+
+#[test]
+fn english_has_no_dialogue_copies() {
+    let dialogue = dialogue_keys();
+    let copies = keys(ENGLISH)
+        .intersection(&dialogue)
+        .cloned()
+        .collect::<Vec<_>>();
+    assert!(copies.is_empty(), "script lines in en: {:?}", copies);
+}
+
+#[test]
+fn every_dialogue_line_is_translated() {
+    let dialogue = dialogue_keys();
+    for translation in TRANSLATIONS {
+        let keys = keys(translation);
+        let missing = dialogue.difference(&keys).collect::<Vec<_>>();
+        let stale = keys
+            .iter()
+            .filter(|key| key.starts_with("dialogue.") && !dialogue.contains(*key))
+            .collect::<Vec<_>>();
+        assert!(
+            missing.is_empty() && stale.is_empty(),
+            "missing: {:?}, no longer in a script: {:?}",
+            missing,
+            stale
+        );
+    }
+}
@@ -0,0 +1,45 @@
+use bevy::prelude::*;
+use jam::common::prelude::*;
+
+#[test]
+fn binding_a_used_key_swaps_it() {
+    let mut mapping = PlayerInputMapping::default();
+    let swapped = mapping.bind(InputAction::Map, InputBinding::Key(KeyCode::J));
+    assert_eq!(swapped, Some(InputAction::QuestLog));
+    assert_eq!(
+        mapping.binding(InputAction::Map),
+        Some(InputBinding::Key(KeyCode::J))
+    );
+    assert_eq!(
+        mapping.binding(InputAction::QuestLog),
+        Some(InputBinding::Key(KeyCode::M))
+    );
+}
+
+#[test]
+fn binding_a_free_key_swaps_nothing() {
+    let mut mapping = PlayerInputMapping::default();
+    let swapped = mapping.bind(InputAction::Map, InputBinding::Key(KeyCode::K));
+    assert_eq!(swapped, None);
+    assert_eq!(
+        mapping.binding(InputAction::QuestLog),
+        Some(InputBinding::Key(KeyCode::J))
+    );
+    assert_eq!(
+        mapping.bind(InputAction::Map, InputBinding::Key(KeyCode::K)),
+        None
+    );
+}
+
+#[test]
+fn binding_a_used_gamepad_button_takes_it_from_an_unbound_action() {
+    let mut mapping = PlayerInputMapping::default();
+    // move up has no gamepad button to hand over
+    let swapped = mapping.bind_gamepad(InputAction::MoveUp, GamepadButtonType::South);
+    assert_eq!(swapped, Some(InputAction::Dash));
+    assert_eq!(mapping.gamepad_button(InputAction::Dash), None);
+    assert_eq!(
+        mapping.gamepad_button(InputAction::MoveUp),
+        Some(GamepadButtonType::South)
+    );
+}
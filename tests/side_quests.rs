@@ -0,0 +1,44 @@
+use jam::game::prelude::*;
+
+fn unknown_side_quest(completed: bool) -> SideQuest {
+    SideQuest {
+        id: "removed_quest".to_owned(),
+        progress: 0,
+        completed,
+    }
+}
+
+#[test]
+fn unknown_side_quests_never_finish() {
+    let side_quest = unknown_side_quest(false);
+    assert_eq!(side_quest.goal(), None);
+    assert!(!side_quest.finished());
+}
+
+#[test]
+fn unknown_side_quests_are_left_out_of_the_log() {
+    let mut quests = Quests::default();
+    quests.accept_side_quest("octopus_hunt");
+    quests.side_quests.push(unknown_side_quest(false));
+    quests.side_quests.push(unknown_side_quest(true));
+    let active = quests
+        .active_side_quests()
+        .map(|side_quest| side_quest.id.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(active, ["octopus_hunt"]);
+    assert_eq!(quests.completed_side_quests().count(), 0);
+}
+
+#[test]
+fn unknown_side_quests_are_dropped() {
+    let mut quests = Quests::default();
+    quests.side_quests.push(unknown_side_quest(false));
+    quests.accept_side_quest("rum_delivery");
+    quests.drop_unknown_side_quests();
+    let ids = quests
+        .side_quests
+        .iter()
+        .map(|side_quest| side_quest.id.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(ids, ["rum_delivery"]);
+}